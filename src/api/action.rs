@@ -1,9 +1,19 @@
 use crate::client::Context;
 use derive_new::new;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::{Error, Value};
+use serde_json::Value;
+use std::collections::HashMap;
 
-use super::{traits::Service, HttpMethods, KeyValue, Limits, ACTION_ENDPOINT, NAMESPACE_ENDPOINT};
+use super::{
+    collection_url, deserialize_response, encode_entity_name, list_query, qualify_name,
+    response_activation_id, serialize_body, split_qualified_name,
+    traits::{AsyncService, Service},
+    typed_activation, typed_payload, web_action_url, with_query, Activation, ActivationId,
+    ActivationService, AsyncListIterator, Exec, HttpMethods, HttpResponse, KeyValue, Limits,
+    ListIterator, OpenWhiskError, PollOptions, RawRequest, TypedActivation, WebRequest,
+    WhiskRequest, ACTION_ENDPOINT,
+};
 
 /// Representation of Action Service
 #[derive(new, Debug, Default, Deserialize, Serialize, Clone)]
@@ -47,30 +57,22 @@ pub struct Action {
     pub parameters: Vec<KeyValue>,
 }
 
-/// Actions Execucatble properties
-#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq, Eq)]
-pub struct Exec {
-    /// Action's Kind
-    #[serde(default)]
-    pub kind: String,
-    /// Action's Code
-    #[serde(default)]
-    pub code: String,
-    /// Action's Image
-    #[serde(default)]
-    pub image: String,
-    /// Action's Init method
-    #[serde(default)]
-    pub init: String,
-    /// Action's Main method
-    #[serde(default)]
-    pub main: String,
-    /// Action's components
-    #[serde(default)]
-    pub components: Vec<String>,
-    /// Toogled to true Action will be of binary
-    #[serde(default)]
-    pub binary: bool,
+/// Representation of actions list options
+#[derive(new, Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ActionListOptions {
+    /// The limit for the required actions
+    pub limit: i64,
+    /// The counts to be skipped
+    pub skip: i64,
+    /// Toggle to get documents
+    pub docs: bool,
+}
+
+impl ActionListOptions {
+    /// Returns the list options as query parameters
+    pub fn query(&self) -> String {
+        list_query(self.limit, self.skip, self.docs)
+    }
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq, Eq)]
@@ -79,229 +81,712 @@ pub struct ActionList {
     pub namespace: String,
 }
 
+/// Representation of an action and, for sequences, the actions it chains
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct ActionTree {
+    /// Fully qualified name of the action, e.g. `/guest/utils/split`
+    pub name: String,
+    /// Definition of the action
+    pub action: Action,
+    /// Resolved components when the action is a sequence
+    pub components: Vec<ActionTree>,
+}
+
+/// Returns the components of a sequence, or nothing for other actions
+fn sequence_components(action: &Action) -> Vec<String> {
+    match &action.exec {
+        Exec::Sequence(exec) => exec.components.clone(),
+        _ => vec![],
+    }
+}
+
+/// Builds the definition of a sequence chaining the given actions
+///
+/// # Arguments
+/// * `namespace`     - Namespace used for components which are not fully qualified
+/// * `sequence_name` - Name of the sequence, optionally fully qualified
+/// * `components`    - Names of the chained actions
+fn sequence_action(namespace: &str, sequence_name: &str, components: &[&str]) -> Action {
+    let components = components
+        .iter()
+        .map(|component| qualify_name(namespace, component))
+        .collect();
+    let (sequence_namespace, _) = split_qualified_name(namespace, sequence_name);
+
+    Action {
+        namespace: sequence_namespace.to_string(),
+        name: sequence_name.to_string(),
+        exec: Exec::sequence(components),
+        ..Default::default()
+    }
+}
+
+/// Builds the tree of a sequence from the fetched actions, failing on cycles
+///
+/// # Arguments
+/// * `name`    - Fully qualified name of the root action
+/// * `actions` - Fetched actions keyed by fully qualified name
+/// * `path`    - Names of the sequences which lead to this action
+fn build_action_tree(
+    name: &str,
+    actions: &HashMap<String, Action>,
+    path: &mut Vec<String>,
+) -> Result<ActionTree, OpenWhiskError> {
+    if path.iter().any(|parent| parent == name) {
+        return Err(OpenWhiskError::InvalidInput(format!(
+            "Sequence {} contains itself",
+            name
+        )));
+    }
+
+    let action = match actions.get(name) {
+        Some(action) => action.clone(),
+        None => {
+            return Err(OpenWhiskError::InvalidInput(format!(
+                "Action {} is not resolved",
+                name
+            )))
+        }
+    };
+
+    path.push(name.to_string());
+
+    let components = sequence_components(&action)
+        .iter()
+        .map(|component| build_action_tree(component, actions, path))
+        .collect::<Result<Vec<ActionTree>, OpenWhiskError>>()?;
+
+    path.pop();
+
+    Ok(ActionTree {
+        name: name.to_string(),
+        action,
+        components,
+    })
+}
+
+/// Returns the names and namespaces of listed actions
+fn action_list(response: Value) -> Result<Vec<ActionList>, OpenWhiskError> {
+    let actions: Vec<Action> = deserialize_response(response, "actions")?;
+
+    Ok(actions
+        .into_iter()
+        .map(|action| ActionList {
+            name: action.name,
+            namespace: action.namespace,
+        })
+        .collect())
+}
+
+/// Returns the activation id of a non-blocking invoke
+fn invocation_activation_id(response: Value) -> Result<ActivationId, OpenWhiskError> {
+    match response_activation_id(response)? {
+        Some(activation_id) => Ok(activation_id),
+        None => Err(OpenWhiskError::Deserialize(
+            "Failed to deserialize activation id of invoke".to_string(),
+        )),
+    }
+}
+
+impl<T> ActionService<T> {
+    /// Returns the url of the actions in a namespace
+    fn actions_url(&self, namespace: &str) -> String {
+        collection_url(&self.context, namespace, ACTION_ENDPOINT)
+    }
+
+    /// Returns the url of an action, the namespace of fully qualified names overrides the context one
+    fn action_url(&self, action_name: &str) -> String {
+        let (namespace, action_name) = split_qualified_name(self.context.namespace(), action_name);
+
+        format!(
+            "{}/{}",
+            self.actions_url(namespace),
+            encode_entity_name(action_name)
+        )
+    }
+
+    fn list_request(&self, options: Option<&ActionListOptions>) -> WhiskRequest {
+        let url = self.actions_url(self.context.namespace());
+
+        match options {
+            Some(options) => {
+                WhiskRequest::new(HttpMethods::GET, format!("{}?{}", url, options.query()))
+            }
+            None => WhiskRequest::new(HttpMethods::GET, url),
+        }
+    }
+
+    fn get_request(&self, action_name: &str, fetch_code: bool) -> WhiskRequest {
+        let url = format!("{}?code={}", self.action_url(action_name), fetch_code);
+
+        WhiskRequest::new(HttpMethods::GET, url)
+    }
+
+    fn delete_request(&self, action_name: &str) -> WhiskRequest {
+        let url = format!("{}?code=false", self.action_url(action_name));
+
+        WhiskRequest::new(HttpMethods::DELETE, url)
+    }
+
+    fn insert_request(
+        &self,
+        action: &Action,
+        overwrite: bool,
+    ) -> Result<WhiskRequest, OpenWhiskError> {
+        action.exec.validate()?;
+
+        let url = format!("{}?overwrite={}", self.action_url(&action.name), overwrite);
+
+        Ok(WhiskRequest::new(HttpMethods::PUT, url).set_body(serialize_body(action)?))
+    }
+
+    fn invocation_request(
+        &self,
+        action_name: &str,
+        payload: Value,
+        blocking: bool,
+        result: bool,
+    ) -> WhiskRequest {
+        let url = format!(
+            "{}?blocking={}&result={}",
+            self.action_url(action_name),
+            blocking,
+            result
+        );
+
+        WhiskRequest::new(HttpMethods::POST, url)
+            .set_body(payload)
+            .set_blocking(blocking)
+    }
+
+    fn typed_invocation_request<P: Serialize>(
+        &self,
+        action_name: &str,
+        payload: &P,
+        result: bool,
+    ) -> Result<RawRequest, OpenWhiskError> {
+        let url = format!(
+            "{}?blocking=true&result={}",
+            self.action_url(action_name),
+            result
+        );
+
+        Ok(RawRequest::new(
+            HttpMethods::POST,
+            url,
+            true,
+            vec![("Content-Type".to_string(), "application/json".to_string())],
+            Some(typed_payload(payload)?),
+        ))
+    }
+
+    fn web_request(&self, action_name: &str, request: &WebRequest) -> RawRequest {
+        let url = with_query(
+            web_action_url(&self.context, action_name, request.extension.as_str()),
+            &request.query,
+        );
+
+        RawRequest::new(
+            request.method,
+            url,
+            request.basic_auth,
+            request.all_headers(),
+            request.body.clone(),
+        )
+    }
+}
+
 impl<T> ActionService<T>
 where
     T: Service,
 {
     /// Returns a list of Actions
-    pub fn list(&self) -> Result<Vec<ActionList>, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            ACTION_ENDPOINT
-        );
+    pub fn list(&self) -> Result<Vec<ActionList>, OpenWhiskError> {
+        action_list(self.list_request(None).send(&self.client, &self.context)?)
+    }
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::GET),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
-
-        match self.client.invoke_request(request) {
-            Ok(x) => {
-                let actions: Result<Vec<Action>, Error> = serde_json::from_value(x);
-                match actions {
-                    Ok(actions) => {
-                        let mut result = Vec::new();
-                        for action in actions.into_iter() {
-                            let actionlist = ActionList {
-                                name: action.name,
-                                namespace: action.namespace,
-                            };
-
-                            result.push(actionlist)
-                        }
-
-                        Ok(result)
-                    }
-                    Err(error) => Err(format!("Failed to deserailize actions {}", error)),
-                }
-            }
+    /// Returns a page of Actions selected by the list options
+    ///
+    /// # Arguments
+    /// * `options` - Limit, skip count and toggles for the list request
+    ///
+    pub fn list_with_options(
+        &self,
+        options: &ActionListOptions,
+    ) -> Result<Vec<ActionList>, OpenWhiskError> {
+        action_list(
+            self.list_request(Some(options))
+                .send(&self.client, &self.context)?,
+        )
+    }
 
-            Err(error) => Err(format!("Failed to fetch the list of actions {}", error)),
-        }
+    /// Returns an iterator which lazily walks every page of Actions
+    ///
+    /// # Arguments
+    /// * `options` - Page size (limit), skip count of the first page and toggles for the list request
+    ///
+    pub fn iter(&self, options: ActionListOptions) -> ListIterator<'_, ActionList> {
+        ListIterator::new(options.limit, options.skip, move |skip, limit| {
+            self.list_with_options(&ActionListOptions {
+                limit,
+                skip,
+                ..options.clone()
+            })
+        })
     }
 
     ///
     /// Returns Properties of action by using action name
     ///
     /// # Arguments
-    /// * `action_name` - String slice that holds action name
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
     /// * `fetch_code`  - Toggle to get code for the action
     ///
+    pub fn get(&self, action_name: &str, fetch_code: bool) -> Result<Action, OpenWhiskError> {
+        let response = self
+            .get_request(action_name, fetch_code)
+            .send(&self.client, &self.context)?;
 
-    pub fn get(&self, action_name: &str, fetch_code: bool) -> Result<Action, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}?code={}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            ACTION_ENDPOINT,
-            action_name,
-            fetch_code
-        );
+        deserialize_response(response, "actions")
+    }
+
+    ///
+    /// Delete Action and returns deleted Action by using action name
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    ///
+    pub fn delete(&self, action_name: &str) -> Result<Action, OpenWhiskError> {
+        let response = self
+            .delete_request(action_name)
+            .send(&self.client, &self.context)?;
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::GET),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
-
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(actions) => Ok(actions),
-                Err(error) => Err(format!("Failed to deserailize actions {}", error)),
-            },
-            Err(error) => Err(format!("Failed to get action properties {}", error)),
+        deserialize_response(response, "actions")
+    }
+
+    ///
+    /// Insert Action and returns new action created
+    ///
+    /// # Arguments
+    /// * `action`    - Action to insert, its name optionally qualified as `package/action` or `/namespace/package/action`
+    /// * `overwrite` - Bool toggle overwite of action if it present already
+    ///
+    pub fn insert(&self, action: &Action, overwrite: bool) -> Result<Action, OpenWhiskError> {
+        let response = self
+            .insert_request(action, overwrite)?
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "actions")
+    }
+
+    ///
+    /// Invoke Action and returns action result
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    /// * `payload`     - Params that action takes for exection
+    /// * `blocking`    - Toggle to block action execution until it returns result
+    /// * `result`      - Toggled only action result is returned
+    ///
+    pub fn invoke(
+        &self,
+        action_name: &str,
+        payload: Value,
+        blocking: bool,
+        result: bool,
+    ) -> Result<Value, OpenWhiskError> {
+        let response = self
+            .invocation_request(action_name, payload, blocking, result)
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "actions")
+    }
+
+    ///
+    /// Invokes an action without waiting for it to complete and returns the id of its activation
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    /// * `payload`     - Params that action takes for exection
+    ///
+    pub fn invoke_nonblocking(
+        &self,
+        action_name: &str,
+        payload: Value,
+    ) -> Result<ActivationId, OpenWhiskError> {
+        invocation_activation_id(self.invoke(action_name, payload, false, false)?)
+    }
+
+    ///
+    /// Invokes an action with a typed payload, blocking until it returns, and deserializes its result.
+    /// An application error of the action is returned as `Err` in the `result` of the typed activation
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    /// * `payload`     - Params that action takes for exection
+    /// * `result`      - Toggled only action result is returned, otherwise the activation is returned along with it
+    ///
+    pub fn invoke_typed<P: Serialize, R: DeserializeOwned>(
+        &self,
+        action_name: &str,
+        payload: &P,
+        result: bool,
+    ) -> Result<TypedActivation<R>, OpenWhiskError> {
+        let response = self
+            .typed_invocation_request(action_name, payload, result)?
+            .send(&self.client, &self.context)?;
+
+        typed_activation(response, result)
+    }
+
+    ///
+    /// Invokes a web action through the web endpoint and returns the raw response.
+    /// Responses are returned whatever their status code, use `HttpResponse::is_success` to check them
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action`)
+    /// * `request`     - Method, extension, query, headers and body of the request
+    ///
+    pub fn web_invoke(
+        &self,
+        action_name: &str,
+        request: &WebRequest,
+    ) -> Result<HttpResponse, OpenWhiskError> {
+        self.web_request(action_name, request)
+            .send(&self.client, &self.context)
+    }
+
+    ///
+    /// Creates a sequence which chains the given actions and returns the created Action
+    ///
+    /// # Arguments
+    /// * `sequence_name` - String slice that holds sequence name (optionally qualified as `package/sequence` or `/namespace/package/sequence`)
+    /// * `components`    - Names of the chained actions, qualified with the namespace when not starting with `/`
+    /// * `overwrite`     - Bool to toggle overwrite of an existing sequence
+    ///
+    pub fn create_sequence(
+        &self,
+        sequence_name: &str,
+        components: &[&str],
+        overwrite: bool,
+    ) -> Result<Action, OpenWhiskError> {
+        let action = sequence_action(self.context.namespace(), sequence_name, components);
+
+        self.insert(&action, overwrite)
+    }
+
+    ///
+    /// Returns an action along with the components of sequences, resolved recursively
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    ///
+    pub fn resolve_sequence(&self, action_name: &str) -> Result<ActionTree, OpenWhiskError> {
+        let root = qualify_name(self.context.namespace(), action_name);
+
+        let mut actions = HashMap::new();
+        let mut pending = vec![root.clone()];
+
+        while let Some(name) = pending.pop() {
+            if actions.contains_key(&name) {
+                continue;
+            }
+
+            let action = self.get(&name, false)?;
+            pending.extend(sequence_components(&action));
+            actions.insert(name, action);
         }
+
+        build_action_tree(&root, &actions, &mut vec![])
+    }
+}
+
+impl<T> ActionService<T>
+where
+    T: Service + Clone,
+{
+    ///
+    /// Invokes an action without blocking and polls its activation until it completes.
+    /// Returns the activation, including its logs and response status, whether the action succeeded or not
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action`)
+    /// * `payload`     - Params that action takes for exection
+    /// * `options`     - Backoff and deadline of polling
+    ///
+    pub fn invoke_and_wait(
+        &self,
+        action_name: &str,
+        payload: Value,
+        options: &PollOptions,
+    ) -> Result<Activation, OpenWhiskError> {
+        let activation_id = self.invoke_nonblocking(action_name, payload)?;
+
+        ActivationService::new(self.client.clone(), self.context.clone())
+            .wait(activation_id.as_str(), options)
+    }
+}
+
+impl<T> ActionService<T>
+where
+    T: AsyncService + Clone,
+{
+    ///
+    /// Invokes an action without blocking and polls its activation until it completes.
+    /// Returns the activation, including its logs and response status, whether the action succeeded or not
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action`)
+    /// * `payload`     - Params that action takes for exection
+    /// * `options`     - Backoff and deadline of polling
+    ///
+    pub async fn invoke_and_wait_async(
+        &self,
+        action_name: &str,
+        payload: Value,
+        options: &PollOptions,
+    ) -> Result<Activation, OpenWhiskError> {
+        let activation_id = self.invoke_nonblocking_async(action_name, payload).await?;
+
+        ActivationService::new(self.client.clone(), self.context.clone())
+            .wait_async(activation_id.as_str(), options)
+            .await
+    }
+}
+
+impl<T> ActionService<T>
+where
+    T: AsyncService + Sync,
+{
+    /// Returns an async iterator which lazily walks every page of Actions
+    ///
+    /// # Arguments
+    /// * `options` - Page size (limit), skip count of the first page and toggles for the list request
+    ///
+    pub fn iter_async(&self, options: ActionListOptions) -> AsyncListIterator<'_, ActionList> {
+        AsyncListIterator::new(options.limit, options.skip, move |skip, limit| {
+            let options = ActionListOptions {
+                limit,
+                skip,
+                ..options.clone()
+            };
+
+            async move { self.list_with_options_async(&options).await }
+        })
+    }
+}
+
+impl<T> ActionService<T>
+where
+    T: AsyncService,
+{
+    /// Returns a list of Actions
+    pub async fn list_async(&self) -> Result<Vec<ActionList>, OpenWhiskError> {
+        action_list(
+            self.list_request(None)
+                .send_async(&self.client, &self.context)
+                .await?,
+        )
+    }
+
+    /// Returns a page of Actions selected by the list options
+    ///
+    /// # Arguments
+    /// * `options` - Limit, skip count and toggles for the list request
+    ///
+    pub async fn list_with_options_async(
+        &self,
+        options: &ActionListOptions,
+    ) -> Result<Vec<ActionList>, OpenWhiskError> {
+        action_list(
+            self.list_request(Some(options))
+                .send_async(&self.client, &self.context)
+                .await?,
+        )
+    }
+
+    ///
+    /// Returns Properties of action by using action name
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    /// * `fetch_code`  - Toggle to get code for the action
+    ///
+    pub async fn get_async(
+        &self,
+        action_name: &str,
+        fetch_code: bool,
+    ) -> Result<Action, OpenWhiskError> {
+        let response = self
+            .get_request(action_name, fetch_code)
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "actions")
     }
 
     ///
     /// Delete Action and returns deleted Action by using action name
     ///
     /// # Arguments
-    /// * `action_name` - String slice that holds action name
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
     ///
-    pub fn delete(&self, action_name: &str) -> Result<Action, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}?code=false",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            ACTION_ENDPOINT,
-            action_name,
-        );
+    pub async fn delete_async(&self, action_name: &str) -> Result<Action, OpenWhiskError> {
+        let response = self
+            .delete_request(action_name)
+            .send_async(&self.client, &self.context)
+            .await?;
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::DELETE),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
-
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(actions) => Ok(actions),
-                Err(err) => Err(format!("Failed to deserailize actions {}", err)),
-            },
-            Err(x) => Err(format!("Failed to get action properties {}", x)),
-        }
+        deserialize_response(response, "actions")
     }
 
     ///
     /// Insert Action and returns new action created
     ///
     /// # Arguments
-    /// * `action`    - String slice that holds action name
+    /// * `action`    - Action to insert, its name optionally qualified as `package/action` or `/namespace/package/action`
     /// * `overwrite` - Bool toggle overwite of action if it present already
     ///
-    pub fn insert(&self, action: &Action, overwrite: bool) -> Result<Action, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}?overwrite={}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            ACTION_ENDPOINT,
-            action.name,
-            overwrite,
-        );
+    pub async fn insert_async(
+        &self,
+        action: &Action,
+        overwrite: bool,
+    ) -> Result<Action, OpenWhiskError> {
+        let response = self
+            .insert_request(action, overwrite)?
+            .send_async(&self.client, &self.context)
+            .await?;
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let body = serde_json::to_value(action).unwrap();
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::PUT),
-            url.as_str(),
-            Some((user, pass)),
-            Some(body),
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
-
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(actions) => Ok(actions),
-                Err(err) => Err(format!("Failed to deserailize actions {}", err)),
-            },
-            Err(x) => Err(format!("Failed to get action properties {}", x)),
-        }
+        deserialize_response(response, "actions")
     }
 
     ///
     /// Invoke Action and returns action result
     ///
     /// # Arguments
-    /// * `action_name` - String slice that holds action name
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
     /// * `payload`     - Params that action takes for exection
     /// * `blocking`    - Toggle to block action execution until it returns result
     /// * `result`      - Toggled only action result is returned
     ///
-    pub fn invoke(
+    pub async fn invoke_async(
         &self,
         action_name: &str,
         payload: Value,
         blocking: bool,
         result: bool,
-    ) -> Result<Value, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}?blocking={}&result={}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            ACTION_ENDPOINT,
-            action_name,
-            blocking,
-            result
-        );
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::POST),
-            url.as_str(),
-            Some((user, pass)),
-            Some(payload),
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
-
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(actions) => Ok(actions),
-                Err(err) => Err(format!("Failed to deserailize actions {}", err)),
-            },
-            Err(x) => Err(format!("Failed to invoke action {}", x)),
+    ) -> Result<Value, OpenWhiskError> {
+        let response = self
+            .invocation_request(action_name, payload, blocking, result)
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "actions")
+    }
+
+    ///
+    /// Invokes an action without waiting for it to complete and returns the id of its activation
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    /// * `payload`     - Params that action takes for exection
+    ///
+    pub async fn invoke_nonblocking_async(
+        &self,
+        action_name: &str,
+        payload: Value,
+    ) -> Result<ActivationId, OpenWhiskError> {
+        invocation_activation_id(
+            self.invoke_async(action_name, payload, false, false)
+                .await?,
+        )
+    }
+
+    ///
+    /// Invokes an action with a typed payload, blocking until it returns, and deserializes its result.
+    /// An application error of the action is returned as `Err` in the `result` of the typed activation
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    /// * `payload`     - Params that action takes for exection
+    /// * `result`      - Toggled only action result is returned, otherwise the activation is returned along with it
+    ///
+    pub async fn invoke_typed_async<P: Serialize, R: DeserializeOwned>(
+        &self,
+        action_name: &str,
+        payload: &P,
+        result: bool,
+    ) -> Result<TypedActivation<R>, OpenWhiskError> {
+        let response = self
+            .typed_invocation_request(action_name, payload, result)?
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        typed_activation(response, result)
+    }
+
+    ///
+    /// Invokes a web action through the web endpoint and returns the raw response.
+    /// Responses are returned whatever their status code, use `HttpResponse::is_success` to check them
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action`)
+    /// * `request`     - Method, extension, query, headers and body of the request
+    ///
+    pub async fn web_invoke_async(
+        &self,
+        action_name: &str,
+        request: &WebRequest,
+    ) -> Result<HttpResponse, OpenWhiskError> {
+        self.web_request(action_name, request)
+            .send_async(&self.client, &self.context)
+            .await
+    }
+
+    ///
+    /// Creates a sequence which chains the given actions and returns the created Action
+    ///
+    /// # Arguments
+    /// * `sequence_name` - String slice that holds sequence name (optionally qualified as `package/sequence` or `/namespace/package/sequence`)
+    /// * `components`    - Names of the chained actions, qualified with the namespace when not starting with `/`
+    /// * `overwrite`     - Bool to toggle overwrite of an existing sequence
+    ///
+    pub async fn create_sequence_async(
+        &self,
+        sequence_name: &str,
+        components: &[&str],
+        overwrite: bool,
+    ) -> Result<Action, OpenWhiskError> {
+        let action = sequence_action(self.context.namespace(), sequence_name, components);
+
+        self.insert_async(&action, overwrite).await
+    }
+
+    ///
+    /// Returns an action along with the components of sequences, resolved recursively
+    ///
+    /// # Arguments
+    /// * `action_name` - String slice that holds action name (optionally qualified as `package/action` or `/namespace/package/action`)
+    ///
+    pub async fn resolve_sequence_async(
+        &self,
+        action_name: &str,
+    ) -> Result<ActionTree, OpenWhiskError> {
+        let root = qualify_name(self.context.namespace(), action_name);
+
+        let mut actions = HashMap::new();
+        let mut pending = vec![root.clone()];
+
+        while let Some(name) = pending.pop() {
+            if actions.contains_key(&name) {
+                continue;
+            }
+
+            let action = self.get_async(&name, false).await?;
+            pending.extend(sequence_components(&action));
+            actions.insert(name, action);
         }
+
+        build_action_tree(&root, &actions, &mut vec![])
     }
 }
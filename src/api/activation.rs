@@ -0,0 +1,353 @@
+use derive_new::new;
+use futures_timer::Delay;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::thread;
+use std::time::Instant;
+
+use super::{
+    collection_url, deadline_exceeded, deserialize_response, AsyncService, HttpMethods, KeyValue,
+    OpenWhiskError, PollOptions, Service, WhiskRequest, ACTIVATIONS_ENDPOINT,
+};
+use crate::client::Context;
+
+/// Representation of Activation Service
+#[derive(new, Default, Debug, Clone)]
+pub struct ActivationService<T> {
+    /// A activation service must have a client to handle http request
+    client: T,
+    /// A activation service uses the context which sets openwhisk properties
+    context: Context,
+}
+
+/// Id of an activation, returned by fires and non-blocking invokes
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct ActivationId(String);
+
+impl ActivationId {
+    /// Returns the id as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ActivationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for ActivationId {
+    fn from(id: String) -> Self {
+        ActivationId(id)
+    }
+}
+
+impl From<&str> for ActivationId {
+    fn from(id: &str) -> Self {
+        ActivationId(id.to_string())
+    }
+}
+
+impl AsRef<str> for ActivationId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Representation of Activation
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct Activation {
+    /// The namespace where the activation ran
+    #[serde(default)]
+    pub namespace: String,
+    /// Name of the action or trigger which produced the activation
+    #[serde(default)]
+    pub name: String,
+    /// Version of the entity which produced the activation
+    #[serde(default)]
+    pub version: String,
+    /// The subject who made the activation
+    #[serde(default)]
+    pub subject: String,
+    /// Unique identifier of the activation
+    #[serde(rename = "activationId", default)]
+    pub activation_id: String,
+    /// Start time of the activation in milliseconds since epoch
+    #[serde(default)]
+    pub start: i64,
+    /// End time of the activation in milliseconds since epoch
+    #[serde(default)]
+    pub end: i64,
+    /// Duration of the activation in milliseconds
+    #[serde(default)]
+    pub duration: i64,
+    /// Status code of the activation (0 is success)
+    #[serde(rename = "statusCode", default)]
+    pub status_code: i64,
+    /// The response produced by the activation
+    #[serde(default)]
+    pub response: ActivationResponse,
+    /// Log lines written by the activation
+    #[serde(default)]
+    pub logs: Vec<String>,
+    /// Keyvalue pair for annotate activations
+    #[serde(default)]
+    pub annotations: Vec<KeyValue>,
+    /// Toggle to publish activation
+    #[serde(default)]
+    pub publish: bool,
+}
+
+/// Representation of Activation Response
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct ActivationResponse {
+    /// Status of the activation (success, application error, developer error, whisk internal error)
+    #[serde(default)]
+    pub status: String,
+    /// Status code of the activation response
+    #[serde(rename = "statusCode", default)]
+    pub status_code: i64,
+    /// Toggled to true when the activation succeeded
+    #[serde(default)]
+    pub success: bool,
+    /// Result returned by the action
+    #[serde(default)]
+    pub result: Value,
+    /// Size of the result in bytes
+    #[serde(default)]
+    pub size: Option<i64>,
+}
+
+/// Representation of Activation Logs
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct ActivationLogs {
+    /// Log lines written by the activation
+    #[serde(default)]
+    pub logs: Vec<String>,
+}
+
+impl<T> ActivationService<T> {
+    /// Returns the request getting an activation resource, e.g. `/{id}/logs`, or the activations for an empty path
+    fn activations_request(&self, path: &str) -> WhiskRequest {
+        let url = format!(
+            "{}{}",
+            collection_url(
+                &self.context,
+                self.context.namespace(),
+                ACTIVATIONS_ENDPOINT
+            ),
+            path
+        );
+
+        WhiskRequest::new(HttpMethods::GET, url)
+    }
+}
+
+impl<T> ActivationService<T>
+where
+    T: Service,
+{
+    /// Returns a list of Activations
+    pub fn list(&self) -> Result<Vec<Activation>, OpenWhiskError> {
+        let response = self
+            .activations_request("")
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "activations")
+    }
+
+    /// To get the properties of the activation
+    ///
+    /// # Arguments
+    /// * `activation_id` - String slice that holds activation id
+    ///
+    pub fn get(&self, activation_id: &str) -> Result<Activation, OpenWhiskError> {
+        let response = self
+            .activations_request(&format!("/{}", activation_id))
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "activation")
+    }
+
+    /// To get the logs of the activation
+    ///
+    /// # Arguments
+    /// * `activation_id` - String slice that holds activation id
+    ///
+    pub fn logs(&self, activation_id: &str) -> Result<ActivationLogs, OpenWhiskError> {
+        let response = self
+            .activations_request(&format!("/{}/logs", activation_id))
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "activation logs")
+    }
+
+    /// To get the result of the activation
+    ///
+    /// # Arguments
+    /// * `activation_id` - String slice that holds activation id
+    ///
+    pub fn result(&self, activation_id: &str) -> Result<ActivationResponse, OpenWhiskError> {
+        let response = self
+            .activations_request(&format!("/{}/result", activation_id))
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "activation result")
+    }
+
+    /// Polls an activation until it completes and returns it, including its logs and response.
+    /// Activations which are still running are not found, polling backs off between attempts
+    /// and returns `OpenWhiskError::Timeout` with the activation id once the deadline has passed
+    ///
+    /// # Arguments
+    /// * `activation_id` - String slice that holds activation id
+    /// * `options`       - Backoff and deadline of polling
+    ///
+    pub fn wait(
+        &self,
+        activation_id: &str,
+        options: &PollOptions,
+    ) -> Result<Activation, OpenWhiskError> {
+        let started = Instant::now();
+        let mut interval = options.interval;
+
+        loop {
+            thread::sleep(options.sleep_interval(interval, started.elapsed()));
+
+            if let Some(activation) = polled_activation(self.get(activation_id))? {
+                return Ok(activation);
+            }
+
+            if started.elapsed() >= options.deadline {
+                return Err(deadline_exceeded(activation_id, options));
+            }
+
+            interval = options.next_interval(interval);
+        }
+    }
+}
+
+impl<T> ActivationService<T>
+where
+    T: AsyncService,
+{
+    /// Returns a list of Activations
+    pub async fn list_async(&self) -> Result<Vec<Activation>, OpenWhiskError> {
+        let response = self
+            .activations_request("")
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "activations")
+    }
+
+    /// To get the properties of the activation
+    ///
+    /// # Arguments
+    /// * `activation_id` - String slice that holds activation id
+    ///
+    pub async fn get_async(&self, activation_id: &str) -> Result<Activation, OpenWhiskError> {
+        let response = self
+            .activations_request(&format!("/{}", activation_id))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "activation")
+    }
+
+    /// To get the logs of the activation
+    ///
+    /// # Arguments
+    /// * `activation_id` - String slice that holds activation id
+    ///
+    pub async fn logs_async(&self, activation_id: &str) -> Result<ActivationLogs, OpenWhiskError> {
+        let response = self
+            .activations_request(&format!("/{}/logs", activation_id))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "activation logs")
+    }
+
+    /// To get the result of the activation
+    ///
+    /// # Arguments
+    /// * `activation_id` - String slice that holds activation id
+    ///
+    pub async fn result_async(
+        &self,
+        activation_id: &str,
+    ) -> Result<ActivationResponse, OpenWhiskError> {
+        let response = self
+            .activations_request(&format!("/{}/result", activation_id))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "activation result")
+    }
+
+    /// Polls an activation until it completes and returns it, including its logs and response.
+    /// Activations which are still running are not found, polling backs off between attempts
+    /// and returns `OpenWhiskError::Timeout` with the activation id once the deadline has passed.
+    /// The wait between polls does not depend on an async runtime, it works with tokio,
+    /// async-std or any other executor
+    ///
+    /// # Arguments
+    /// * `activation_id` - String slice that holds activation id
+    /// * `options`       - Backoff and deadline of polling
+    ///
+    pub async fn wait_async(
+        &self,
+        activation_id: &str,
+        options: &PollOptions,
+    ) -> Result<Activation, OpenWhiskError> {
+        let started = Instant::now();
+        let mut interval = options.interval;
+
+        loop {
+            Delay::new(options.sleep_interval(interval, started.elapsed())).await;
+
+            if let Some(activation) = polled_activation(self.get_async(activation_id).await)? {
+                return Ok(activation);
+            }
+
+            if started.elapsed() >= options.deadline {
+                return Err(deadline_exceeded(activation_id, options));
+            }
+
+            interval = options.next_interval(interval);
+        }
+    }
+}
+
+/// Returns the activation of a poll, None while the activation is still running and not found
+fn polled_activation(
+    result: Result<Activation, OpenWhiskError>,
+) -> Result<Option<Activation>, OpenWhiskError> {
+    match result {
+        Ok(activation) => Ok(Some(activation)),
+        Err(OpenWhiskError::NotFound(_)) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Reads the activation id of a fire or non-blocking invoke response.
+/// Fires of triggers without active rules are answered with 204 and create no activation
+pub(crate) fn response_activation_id(
+    response: Value,
+) -> Result<Option<ActivationId>, OpenWhiskError> {
+    if response.is_null() {
+        return Ok(None);
+    }
+
+    match response.get("activationId").and_then(Value::as_str) {
+        Some(activation_id) => Ok(Some(ActivationId::from(activation_id))),
+        None => Err(OpenWhiskError::Deserialize(format!(
+            "Failed to deserialize activation id {}",
+            response
+        ))),
+    }
+}
@@ -1,10 +1,29 @@
-use serde::{Deserialize, Serialize};
+use http::{HeaderMap, StatusCode};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS, NON_ALPHANUMERIC};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 
-/// These Static variables represents action,triggers,rules and namespaces endpoints
+use super::{ActivationId, OpenWhiskError};
+use crate::client::whisk_errors;
+
+/// These Static variables represents action,triggers,rules,namespaces,activations and packages endpoints
 pub static ACTION_ENDPOINT: &str = "actions";
 pub static TRIGGERS_ENDPOINT: &str = "triggers";
 pub static RULES_ENDPOINT: &str = "rules";
 pub static NAMESPACE_ENDPOINT: &str = "namespaces";
+pub static ACTIVATIONS_ENDPOINT: &str = "activations";
+pub static PACKAGES_ENDPOINT: &str = "packages";
+
+/// These Static variables represents web action and API Gateway management endpoints
+pub static WEB_ENDPOINT: &str = "web";
+pub static APIGW_ENDPOINT: &str = "web/whisk.system/apimgmt";
+/// Header which holds the access token of API Gateway requests
+pub static APIGW_ACCESS_TOKEN_HEADER: &str = "apigw-access-token";
+
+/// Header which holds the id of the activation a response refers to
+pub static ACTIVATION_ID_HEADER: &str = "x-openwhisk-activation-id";
+/// Header which holds the id OpenWhisk assigned to the request
+pub static REQUEST_ID_HEADER: &str = "x-request-id";
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
 pub struct Limits {
@@ -23,9 +42,170 @@ pub struct Limits {
 }
 
 /// Enum of HTTP Methods
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum HttpMethods {
     GET,
     PUT,
     POST,
     DELETE,
+    PATCH,
+    HEAD,
+    OPTIONS,
+}
+
+impl HttpMethods {
+    /// Returns the name of the method as used in the request line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpMethods::GET => "GET",
+            HttpMethods::PUT => "PUT",
+            HttpMethods::POST => "POST",
+            HttpMethods::DELETE => "DELETE",
+            HttpMethods::PATCH => "PATCH",
+            HttpMethods::HEAD => "HEAD",
+            HttpMethods::OPTIONS => "OPTIONS",
+        }
+    }
+}
+
+/// Raw response of a http request
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    /// Status code of the response
+    pub status: u16,
+    /// Headers of the response
+    pub headers: HeaderMap,
+    /// Raw body of the response
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Returns true when the status code is in the 2xx range
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Returns the value of a header, if present and valid utf-8
+    ///
+    /// # Arguments
+    /// * `name` - Case insensitive name of the header
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// Returns the body as text, replacing invalid utf-8 sequences
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).to_string()
+    }
+
+    /// Deserializes the json body of the response
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, OpenWhiskError> {
+        match serde_json::from_slice(&self.body) {
+            Ok(value) => Ok(value),
+            Err(err) => Err(OpenWhiskError::Deserialize(format!(
+                "Failed to deserialize response body {}",
+                err
+            ))),
+        }
+    }
+
+    /// Returns the id of the activation the response refers to, sent in the `x-openwhisk-activation-id` header
+    pub fn activation_id(&self) -> Option<ActivationId> {
+        self.header(ACTIVATION_ID_HEADER).map(ActivationId::from)
+    }
+
+    /// Returns the id OpenWhisk assigned to the request, sent in the `x-request-id` header
+    pub fn request_id(&self) -> Option<&str> {
+        self.header(REQUEST_ID_HEADER)
+    }
+
+    /// Returns the error matching the status of an unsuccessful response, None for 2xx responses
+    pub fn error(&self) -> Option<OpenWhiskError> {
+        if self.is_success() {
+            return None;
+        }
+
+        match StatusCode::from_u16(self.status) {
+            Ok(code) => Some(whisk_errors(code, &self.body)),
+            Err(error) => Some(OpenWhiskError::Transport(format!("{}", error))),
+        }
+    }
+
+    /// Returns the json body of a successful response, null when the body is empty like for 204.
+    /// Unsuccessful responses are returned as the error matching their status
+    pub fn into_value(self) -> Result<Value, OpenWhiskError> {
+        if let Some(error) = self.error() {
+            return Err(error);
+        }
+
+        if self.body.iter().all(u8::is_ascii_whitespace) {
+            return Ok(Value::Null);
+        }
+
+        self.json()
+    }
+}
+
+/// Characters which must be escaped in a single segment of the entity path
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// Encodes an entity name for use in the url path.
+/// Package qualified names like `package/action` keep the separator
+/// while each segment is percent encoded
+///
+/// # Arguments
+/// * `name` - String slice that holds entity name
+pub(crate) fn encode_entity_name(name: &str) -> String {
+    name.trim_start_matches('/')
+        .split('/')
+        .map(|segment| utf8_percent_encode(segment, PATH_SEGMENT).to_string())
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+/// Returns the fully qualified name `/namespace/[package/]entity` of an entity.
+/// Names which start with `/` are already fully qualified
+///
+/// # Arguments
+/// * `namespace` - Namespace used for names which are not fully qualified
+/// * `name`      - String slice that holds entity name
+pub(crate) fn qualify_name(namespace: &str, name: &str) -> String {
+    if name.starts_with('/') {
+        name.to_string()
+    } else {
+        format!("/{}/{}", namespace, name)
+    }
+}
+
+/// Splits a name into namespace and `[package/]entity`.
+/// Names which are not fully qualified belong to the given namespace
+///
+/// # Arguments
+/// * `namespace` - Namespace used for names which are not fully qualified
+/// * `name`      - String slice that holds entity name
+pub(crate) fn split_qualified_name<'a>(namespace: &'a str, name: &'a str) -> (&'a str, &'a str) {
+    match name.strip_prefix('/').and_then(|name| name.split_once('/')) {
+        Some((namespace, name)) => (namespace, name),
+        None => (namespace, name.trim_start_matches('/')),
+    }
+}
+
+/// Encodes a value for use in the url query string
+///
+/// # Arguments
+/// * `value` - String slice that holds the query value
+pub(crate) fn encode_query_value(value: &str) -> String {
+    utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
 }
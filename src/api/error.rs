@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Details of an error response returned by the OpenWhisk API
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiError {
+    /// HTTP status code of the response
+    pub status: u16,
+    /// OpenWhisk error code id which identifies the failed request
+    pub code: String,
+    /// Error message sent by OpenWhisk
+    pub message: String,
+    /// Activation id, when the response refers to an activation
+    pub activation_id: Option<String>,
+}
+
+/// Representation of errors returned by the OpenWhisk client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpenWhiskError {
+    /// The request was malformed (400)
+    BadRequest(ApiError),
+    /// The credentials are missing or invalid (401)
+    Unauthorized(ApiError),
+    /// The subject is not allowed to access the entity (403)
+    Forbidden(ApiError),
+    /// The entity does not exist (404)
+    NotFound(ApiError),
+    /// The entity already exists or was concurrently modified (409)
+    Conflict(ApiError),
+    /// The request exceeds a namespace limit (429)
+    TooManyRequests(ApiError),
+    /// A blocking activation did not complete in time and continues asynchronously (202)
+    Timeout(ApiError),
+    /// Any other unsuccessful response, such as an internal or application error
+    Server(ApiError),
+    /// The request could not be created
+    Request(String),
+    /// The request could not be sent or the response could not be read
+    Transport(String),
+    /// The request body could not be serialized
+    Serialize(String),
+    /// The response body could not be deserialized
+    Deserialize(String),
+    /// An argument supplied by the caller is invalid
+    InvalidInput(String),
+    /// The client configuration could not be read
+    Configuration(String),
+}
+
+impl OpenWhiskError {
+    /// Creates the error matching the status code of an OpenWhisk response
+    ///
+    /// # Arguments
+    /// * `status`  - HTTP status code of the response
+    /// * `error`   - Error details sent by OpenWhisk
+    pub fn from_status(status: u16, error: ApiError) -> Self {
+        let error = ApiError { status, ..error };
+
+        match status {
+            202 => OpenWhiskError::Timeout(error),
+            400 => OpenWhiskError::BadRequest(error),
+            401 => OpenWhiskError::Unauthorized(error),
+            403 => OpenWhiskError::Forbidden(error),
+            404 => OpenWhiskError::NotFound(error),
+            409 => OpenWhiskError::Conflict(error),
+            429 => OpenWhiskError::TooManyRequests(error),
+            _ => OpenWhiskError::Server(error),
+        }
+    }
+
+    /// Returns the details of the response when the error was sent by OpenWhisk
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            OpenWhiskError::BadRequest(error)
+            | OpenWhiskError::Unauthorized(error)
+            | OpenWhiskError::Forbidden(error)
+            | OpenWhiskError::NotFound(error)
+            | OpenWhiskError::Conflict(error)
+            | OpenWhiskError::TooManyRequests(error)
+            | OpenWhiskError::Timeout(error)
+            | OpenWhiskError::Server(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns HTTP status code of the response
+    pub fn status(&self) -> Option<u16> {
+        self.api_error().map(|error| error.status)
+    }
+
+    /// Returns OpenWhisk error code id of the response
+    pub fn code(&self) -> Option<&str> {
+        self.api_error().map(|error| error.code.as_str())
+    }
+
+    /// Returns activation id of the response
+    pub fn activation_id(&self) -> Option<&str> {
+        self.api_error()
+            .and_then(|error| error.activation_id.as_deref())
+    }
+
+    /// Returns the error message
+    pub fn message(&self) -> &str {
+        match self {
+            OpenWhiskError::Request(message)
+            | OpenWhiskError::Transport(message)
+            | OpenWhiskError::Serialize(message)
+            | OpenWhiskError::Deserialize(message)
+            | OpenWhiskError::InvalidInput(message)
+            | OpenWhiskError::Configuration(message) => message,
+            _ => self
+                .api_error()
+                .map(|error| error.message.as_str())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for OpenWhiskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpenWhiskError::Request(message) => write!(f, "Failed to create request: {}", message),
+            OpenWhiskError::Transport(message) => write!(f, "Failed to send request: {}", message),
+            OpenWhiskError::Serialize(message) => write!(f, "Failed to serialize: {}", message),
+            OpenWhiskError::Deserialize(message) => {
+                write!(f, "Failed to deserialize: {}", message)
+            }
+            OpenWhiskError::InvalidInput(message) => write!(f, "Invalid input: {}", message),
+            OpenWhiskError::Configuration(message) => {
+                write!(f, "Invalid configuration: {}", message)
+            }
+            _ => match self.api_error() {
+                Some(error) => write!(
+                    f,
+                    "Error -> [ Status :{}, Code : {}, Message : {} ]",
+                    error.status, error.code, error.message
+                ),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+impl std::error::Error for OpenWhiskError {}
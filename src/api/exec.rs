@@ -0,0 +1,385 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use super::OpenWhiskError;
+
+/// Kind of the actions which run a docker image
+pub static BLACKBOX_KIND: &str = "blackbox";
+/// Kind of the actions which chain other actions
+pub static SEQUENCE_KIND: &str = "sequence";
+
+/// Runtime kinds supported by the OpenWhisk runtimes
+pub static KNOWN_RUNTIME_KINDS: &[&str] = &[
+    "nodejs:default",
+    "nodejs:14",
+    "nodejs:16",
+    "nodejs:18",
+    "nodejs:20",
+    "python:default",
+    "python:3",
+    "python:3.9",
+    "python:3.10",
+    "python:3.11",
+    "go:default",
+    "go:1.19",
+    "go:1.20",
+    "go:1.21",
+    "java:default",
+    "java:8",
+    "php:default",
+    "php:7.4",
+    "php:8.0",
+    "php:8.1",
+    "ruby:default",
+    "ruby:2.5",
+    "rust:default",
+    "rust:1.34",
+    "swift:default",
+    "swift:5.1",
+    "swift:5.3",
+    "swift:5.4",
+    "dotnet:default",
+    "dotnet:2.2",
+    "dotnet:3.1",
+    "ballerina:default",
+    "ballerina:0.990",
+];
+
+/// Runtime kind of a code action, e.g. `nodejs:20`
+///
+/// Runtimes are either one of the constants, or parsed from a string which is
+/// checked against `KNOWN_RUNTIME_KINDS`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Runtime(Cow<'static, str>);
+
+impl Runtime {
+    pub const NODEJS_DEFAULT: Runtime = Runtime(Cow::Borrowed("nodejs:default"));
+    pub const NODEJS_18: Runtime = Runtime(Cow::Borrowed("nodejs:18"));
+    pub const NODEJS_20: Runtime = Runtime(Cow::Borrowed("nodejs:20"));
+    pub const PYTHON_DEFAULT: Runtime = Runtime(Cow::Borrowed("python:default"));
+    pub const PYTHON_3_11: Runtime = Runtime(Cow::Borrowed("python:3.11"));
+    pub const GO_DEFAULT: Runtime = Runtime(Cow::Borrowed("go:default"));
+    pub const GO_1_21: Runtime = Runtime(Cow::Borrowed("go:1.21"));
+    pub const JAVA_DEFAULT: Runtime = Runtime(Cow::Borrowed("java:default"));
+    pub const PHP_DEFAULT: Runtime = Runtime(Cow::Borrowed("php:default"));
+    pub const RUBY_DEFAULT: Runtime = Runtime(Cow::Borrowed("ruby:default"));
+    pub const RUST_DEFAULT: Runtime = Runtime(Cow::Borrowed("rust:default"));
+    pub const RUST_1_34: Runtime = Runtime(Cow::Borrowed("rust:1.34"));
+    pub const SWIFT_DEFAULT: Runtime = Runtime(Cow::Borrowed("swift:default"));
+    pub const DOTNET_DEFAULT: Runtime = Runtime(Cow::Borrowed("dotnet:default"));
+
+    /// Creates a runtime from a kind in `KNOWN_RUNTIME_KINDS`
+    ///
+    /// # Arguments
+    /// * `kind` - Runtime kind, e.g. `python:3.11`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::Runtime;
+    ///
+    /// let runtime = Runtime::new("python:3.11").unwrap();
+    ///
+    /// ```
+    pub fn new(kind: &str) -> Result<Self, OpenWhiskError> {
+        match KNOWN_RUNTIME_KINDS.iter().find(|known| **known == kind) {
+            Some(known) => Ok(Runtime(Cow::Borrowed(known))),
+            None => Err(OpenWhiskError::InvalidInput(format!(
+                "Unknown runtime kind {}",
+                kind
+            ))),
+        }
+    }
+
+    /// Creates a runtime which is not checked against `KNOWN_RUNTIME_KINDS`,
+    /// for runtimes installed on a specific OpenWhisk deployment
+    ///
+    /// # Arguments
+    /// * `kind` - Runtime kind
+    pub fn custom(kind: &str) -> Self {
+        Runtime(Cow::Owned(kind.to_string()))
+    }
+
+    /// Returns the runtime kind
+    pub fn kind(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true when the kind is in `KNOWN_RUNTIME_KINDS`
+    pub fn is_known(&self) -> bool {
+        KNOWN_RUNTIME_KINDS.contains(&self.kind())
+    }
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Runtime::NODEJS_DEFAULT
+    }
+}
+
+impl FromStr for Runtime {
+    type Err = OpenWhiskError;
+
+    fn from_str(kind: &str) -> Result<Self, Self::Err> {
+        Runtime::new(kind)
+    }
+}
+
+impl fmt::Display for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Runtime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.kind())
+    }
+}
+
+/// Runtimes read from the server are not checked, as the deployment may install custom runtimes
+impl<'de> Deserialize<'de> for Runtime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let kind = String::deserialize(deserializer)?;
+
+        Ok(Runtime::custom(&kind))
+    }
+}
+
+/// Actions Execucatble properties
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(into = "ExecWire", from = "ExecWire")]
+pub enum Exec {
+    /// Action which runs code on a runtime
+    Code(CodeExec),
+    /// Action which runs a docker image
+    Blackbox(BlackboxExec),
+    /// Action which chains other actions
+    Sequence(SequenceExec),
+}
+
+/// Executable of an action which runs code on a runtime
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CodeExec {
+    /// Runtime kind of the action
+    pub kind: Runtime,
+    /// Source code, or base64 encoded zip when binary
+    pub code: String,
+    /// Name of the entry point, the runtime default is used when not set
+    pub main: Option<String>,
+    /// Toggled to true when the code is a base64 encoded zip
+    pub binary: bool,
+}
+
+/// Executable of an action which runs a docker image
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BlackboxExec {
+    /// Docker image of the action
+    pub image: String,
+    /// Optional code which is handed to the image on init
+    pub code: Option<String>,
+    /// Name of the entry point
+    pub main: Option<String>,
+    /// Toggled to true when the code is a base64 encoded zip
+    pub binary: bool,
+}
+
+/// Executable of an action which chains other actions
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SequenceExec {
+    /// Fully qualified names of the chained actions, e.g. `/guest/utils/split`
+    pub components: Vec<String>,
+}
+
+impl Default for Exec {
+    fn default() -> Self {
+        Exec::Code(CodeExec::default())
+    }
+}
+
+impl Exec {
+    /// Creates the executable of a code action
+    ///
+    /// # Arguments
+    /// * `kind` - Runtime kind of the action
+    /// * `code` - Source code of the action
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{Exec, Runtime};
+    ///
+    /// let exec = Exec::code(Runtime::NODEJS_20, "function main() { return {}; }");
+    ///
+    /// ```
+    pub fn code(kind: Runtime, code: &str) -> Self {
+        Exec::Code(CodeExec {
+            kind,
+            code: code.to_string(),
+            main: None,
+            binary: false,
+        })
+    }
+
+    /// Creates the executable of a code action from a base64 encoded zip
+    ///
+    /// # Arguments
+    /// * `kind` - Runtime kind of the action
+    /// * `code` - Base64 encoded zip archive
+    pub fn binary(kind: Runtime, code: &str) -> Self {
+        Exec::Code(CodeExec {
+            kind,
+            code: code.to_string(),
+            main: None,
+            binary: true,
+        })
+    }
+
+    /// Creates the executable of a docker action
+    ///
+    /// # Arguments
+    /// * `image` - Docker image of the action
+    pub fn blackbox(image: &str) -> Self {
+        Exec::Blackbox(BlackboxExec {
+            image: image.to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// Creates the executable of a sequence
+    ///
+    /// # Arguments
+    /// * `components` - Fully qualified names of the chained actions
+    pub fn sequence(components: Vec<String>) -> Self {
+        Exec::Sequence(SequenceExec { components })
+    }
+
+    /// To set the entry point of a code or docker action, ignored for sequences
+    ///
+    /// # Arguments
+    /// * `main` - Name of the entry point
+    pub fn set_main(mut self, main: &str) -> Self {
+        match &mut self {
+            Exec::Code(exec) => exec.main = Some(main.to_string()),
+            Exec::Blackbox(exec) => exec.main = Some(main.to_string()),
+            Exec::Sequence(_) => {}
+        }
+
+        self
+    }
+
+    /// Returns the kind as sent to OpenWhisk
+    pub fn kind(&self) -> &str {
+        match self {
+            Exec::Code(exec) => exec.kind.kind(),
+            Exec::Blackbox(_) => BLACKBOX_KIND,
+            Exec::Sequence(_) => SEQUENCE_KIND,
+        }
+    }
+
+    /// Returns the code of the action, None for sequences and docker actions without code
+    pub fn source(&self) -> Option<&str> {
+        match self {
+            Exec::Code(exec) => Some(&exec.code),
+            Exec::Blackbox(exec) => exec.code.as_deref(),
+            Exec::Sequence(_) => None,
+        }
+    }
+
+    /// Returns true when the code is a base64 encoded zip
+    pub fn is_binary(&self) -> bool {
+        match self {
+            Exec::Code(exec) => exec.binary,
+            Exec::Blackbox(exec) => exec.binary,
+            Exec::Sequence(_) => false,
+        }
+    }
+
+    /// Checks the executable for definitions OpenWhisk rejects
+    pub fn validate(&self) -> Result<(), OpenWhiskError> {
+        match self {
+            Exec::Code(exec) if exec.kind.kind().is_empty() => Err(OpenWhiskError::InvalidInput(
+                "Runtime kind of the action is empty".to_string(),
+            )),
+            Exec::Blackbox(exec) if exec.image.is_empty() => Err(OpenWhiskError::InvalidInput(
+                "Image of the blackbox action is empty".to_string(),
+            )),
+            Exec::Sequence(exec) if exec.components.is_empty() => Err(
+                OpenWhiskError::InvalidInput("Sequence has no components".to_string()),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Flat representation of the exec as sent and returned by OpenWhisk
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct ExecWire {
+    #[serde(default)]
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    main: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    binary: Option<bool>,
+}
+
+impl From<Exec> for ExecWire {
+    fn from(exec: Exec) -> Self {
+        match exec {
+            Exec::Code(exec) => ExecWire {
+                kind: exec.kind.kind().to_string(),
+                code: Some(exec.code),
+                main: exec.main,
+                binary: Some(exec.binary),
+                ..Default::default()
+            },
+            Exec::Blackbox(exec) => ExecWire {
+                kind: BLACKBOX_KIND.to_string(),
+                image: Some(exec.image),
+                code: exec.code,
+                main: exec.main,
+                binary: Some(exec.binary),
+                ..Default::default()
+            },
+            Exec::Sequence(exec) => ExecWire {
+                kind: SEQUENCE_KIND.to_string(),
+                components: Some(exec.components),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl From<ExecWire> for Exec {
+    fn from(wire: ExecWire) -> Self {
+        if wire.kind == SEQUENCE_KIND {
+            return Exec::Sequence(SequenceExec {
+                components: wire.components.unwrap_or_default(),
+            });
+        }
+
+        if wire.kind == BLACKBOX_KIND {
+            return Exec::Blackbox(BlackboxExec {
+                image: wire.image.unwrap_or_default(),
+                code: wire.code,
+                main: wire.main,
+                binary: wire.binary.unwrap_or_default(),
+            });
+        }
+
+        Exec::Code(CodeExec {
+            kind: Runtime::custom(&wire.kind),
+            code: wire.code.unwrap_or_default(),
+            main: wire.main,
+            binary: wire.binary.unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,126 @@
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{Activation, HttpResponse, OpenWhiskError};
+use crate::client::whisk_errors;
+
+/// Status of a blocking invoke whose action returned an application or developer error
+const APPLICATION_ERROR_STATUS: u16 = 502;
+
+/// Result of a typed invoke
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedActivation<R> {
+    /// Activation which produced the result, only returned when invoked with `result=false`
+    pub activation: Option<Activation>,
+    /// Result of the action, or the `error` it returned when the activation did not succeed
+    pub result: Result<R, Value>,
+}
+
+impl<R> TypedActivation<R> {
+    /// Returns true when the action succeeded
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Returns id of the activation, when invoked with `result=false`
+    pub fn activation_id(&self) -> Option<&str> {
+        self.activation
+            .as_ref()
+            .map(|activation| activation.activation_id.as_str())
+    }
+}
+
+/// Serializes the payload of a typed invoke
+pub(crate) fn typed_payload<P: Serialize>(payload: &P) -> Result<Vec<u8>, OpenWhiskError> {
+    match serde_json::to_vec(payload) {
+        Ok(body) => Ok(body),
+        Err(err) => Err(OpenWhiskError::Serialize(format!(
+            "Failed to serialize payload {}",
+            err
+        ))),
+    }
+}
+
+/// Reads the response of a blocking invoke into a typed activation.
+/// Application errors are answered with 502 and the activation, or its `error` when `result=true`
+///
+/// # Arguments
+/// * `response` - Response of the invoke
+/// * `result`   - Toggle the invoke was sent with, the body only holds the result when set
+pub(crate) fn typed_activation<R: DeserializeOwned>(
+    response: HttpResponse,
+    result: bool,
+) -> Result<TypedActivation<R>, OpenWhiskError> {
+    let application_error = response.status == APPLICATION_ERROR_STATUS;
+
+    // A blocking invoke which did not complete in time continues asynchronously,
+    // the activation id is returned with `OpenWhiskError::Timeout`
+    if response.status == StatusCode::ACCEPTED.as_u16()
+        || (!response.is_success() && !application_error)
+    {
+        return Err(response_error(&response));
+    }
+
+    let body = match response.json::<Value>() {
+        Ok(body) => body,
+        Err(_) if application_error => return Err(response_error(&response)),
+        Err(error) => return Err(error),
+    };
+
+    if result {
+        return Ok(TypedActivation {
+            activation: None,
+            result: typed_result(body, !application_error)?,
+        });
+    }
+
+    let activation: Activation = match serde_json::from_value(body) {
+        Ok(activation) => activation,
+        Err(_) if application_error => return Err(response_error(&response)),
+        Err(err) => {
+            return Err(OpenWhiskError::Deserialize(format!(
+                "Failed to deserialize activation {}",
+                err
+            )))
+        }
+    };
+
+    Ok(TypedActivation {
+        result: typed_result(
+            activation.response.result.clone(),
+            activation.response.success,
+        )?,
+        activation: Some(activation),
+    })
+}
+
+fn typed_result<R: DeserializeOwned>(
+    result: Value,
+    success: bool,
+) -> Result<Result<R, Value>, OpenWhiskError> {
+    if !success {
+        return Ok(Err(match result {
+            Value::Object(mut result) if result.contains_key("error") => {
+                result.remove("error").unwrap_or_default()
+            }
+            result => result,
+        }));
+    }
+
+    match serde_json::from_value(result) {
+        Ok(result) => Ok(Ok(result)),
+        Err(err) => Err(OpenWhiskError::Deserialize(format!(
+            "Failed to deserialize action result {}",
+            err
+        ))),
+    }
+}
+
+fn response_error(response: &HttpResponse) -> OpenWhiskError {
+    match StatusCode::from_u16(response.status) {
+        Ok(code) => whisk_errors(code, &response.body),
+        Err(err) => OpenWhiskError::Transport(format!("{}", err)),
+    }
+}
@@ -1,13 +1,35 @@
 mod action;
+mod activation;
 mod common;
+mod error;
+mod exec;
+mod invocation;
 mod namespace;
+mod package;
+mod packaging;
+mod pagination;
+mod polling;
+mod request;
+mod route;
 mod rule;
 mod traits;
 mod trigger;
+mod web;
 
 pub use action::*;
+pub use activation::*;
 pub use common::*;
+pub use error::*;
+pub use exec::*;
+pub use invocation::*;
 pub use namespace::*;
+pub use package::*;
+pub use packaging::*;
+pub use pagination::*;
+pub use polling::*;
+pub(crate) use request::*;
+pub use route::*;
 pub use rule::*;
 pub use traits::*;
 pub use trigger::*;
+pub use web::*;
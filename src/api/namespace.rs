@@ -1,6 +1,9 @@
 use derive_new::new;
 
-use super::{HttpMethods, Service, NAMESPACE_ENDPOINT};
+use super::{
+    deserialize_response, AsyncService, HttpMethods, OpenWhiskError, Service, WhiskRequest,
+    NAMESPACE_ENDPOINT,
+};
 use crate::client::Context;
 
 /// Representation of Namespace Service
@@ -12,37 +15,37 @@ pub struct NamespaceService<T> {
     context: Context,
 }
 
+impl<T> NamespaceService<T> {
+    fn list_request(&self) -> WhiskRequest {
+        let url = format!("{}/api/v1/{}/", self.context.host(), NAMESPACE_ENDPOINT);
+
+        WhiskRequest::new(HttpMethods::GET, url)
+    }
+}
+
 impl<T> NamespaceService<T>
 where
     T: Service,
 {
     /// The list function gets inputs from the struct and returns the list of namespaces available
-    pub fn list(&self) -> Result<Vec<String>, String> {
-        let url = format!("{}/api/v1/{}/", self.context.host(), NAMESPACE_ENDPOINT);
+    pub fn list(&self) -> Result<Vec<String>, OpenWhiskError> {
+        let response = self.list_request().send(&self.client, &self.context)?;
+
+        deserialize_response(response, "namespaces")
+    }
+}
+
+impl<T> NamespaceService<T>
+where
+    T: AsyncService,
+{
+    /// The list function gets inputs from the struct and returns the list of namespaces available
+    pub async fn list_async(&self) -> Result<Vec<String>, OpenWhiskError> {
+        let response = self
+            .list_request()
+            .send_async(&self.client, &self.context)
+            .await?;
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::GET),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
-
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(namespaces) => Ok(namespaces),
-                Err(err) => Err(format!("Failed to deserailize {}", err)),
-            },
-            Err(x) => Err(format!(
-                "Failed to fetch the list of available namespaces {}",
-                x
-            )),
-        }
+        deserialize_response(response, "namespaces")
     }
 }
@@ -0,0 +1,441 @@
+use super::{
+    collection_url, deserialize_response, encode_entity_name, list_query, serialize_body,
+    AsyncListIterator, AsyncService, HttpMethods, KeyValue, ListIterator, OpenWhiskError, Service,
+    WhiskRequest, PACKAGES_ENDPOINT,
+};
+use crate::client::Context;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Representation of Package Service
+#[derive(new, Default, Debug, Clone)]
+pub struct PackageService<T> {
+    /// A package service must have a client to handle http request
+    client: T,
+    /// A package service uses the context which sets openwhisk properties
+    context: Context,
+}
+
+/// Representation of Package
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct Package {
+    /// A package must have a namspace where it exists
+    #[serde(default)]
+    pub namespace: String,
+    /// A package must have a name to represent it
+    #[serde(default)]
+    pub name: String,
+    /// A package must have a versioning
+    #[serde(default)]
+    pub version: String,
+    /// Toggle to share the package with other namespaces
+    #[serde(default)]
+    pub publish: bool,
+    /// Keyvalue pair for annotate packages
+    #[serde(default)]
+    pub annotations: Vec<KeyValue>,
+    /// Keyvalue pair for package parameter
+    #[serde(default)]
+    pub parameters: Vec<KeyValue>,
+    /// The package this package is bound to, if it is a binding
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binding: Option<PackageBinding>,
+    /// Actions which are available in the package
+    #[serde(default, skip_serializing)]
+    pub actions: Vec<PackageAction>,
+    /// Feeds which are available in the package
+    #[serde(default, skip_serializing)]
+    pub feeds: Vec<PackageAction>,
+    /// Updated version count of package
+    #[serde(default)]
+    pub updated: i64,
+}
+
+/// Representation of the package a binding refers to
+#[derive(new, Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct PackageBinding {
+    /// Namespace of the bound package
+    #[serde(default)]
+    pub namespace: String,
+    /// Name of the bound package
+    #[serde(default)]
+    pub name: String,
+}
+
+/// Representation of an action or feed listed in a package
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct PackageAction {
+    /// Name of the action
+    #[serde(default)]
+    pub name: String,
+    /// Version of the action
+    #[serde(default)]
+    pub version: String,
+    /// Keyvalue pair for annotate the action
+    #[serde(default)]
+    pub annotations: Vec<KeyValue>,
+}
+
+/// Representation of packages list options
+#[derive(new, Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PackageListOptions {
+    /// The limit for the required packages
+    pub limit: i64,
+    /// The counts to be skipped
+    pub skip: i64,
+    /// Toggle to get documents
+    pub docs: bool,
+    /// Toggle to include packages shared by other namespaces
+    pub public: bool,
+}
+
+impl PackageListOptions {
+    /// Returns the list options as query parameters
+    pub fn query(&self) -> String {
+        format!(
+            "{}&public={}",
+            list_query(self.limit, self.skip, self.docs),
+            self.public
+        )
+    }
+}
+
+impl Package {
+    /// Returns true when the package is a binding to another package
+    pub fn is_binding(&self) -> bool {
+        match &self.binding {
+            Some(binding) => !binding.name.is_empty(),
+            None => false,
+        }
+    }
+}
+
+/// Returns the existing package with its publish toggle changed
+///
+/// # Arguments
+/// * `package_name` - String slice that holds package name
+/// * `package`      - The package as it exists
+/// * `publish`      - Toggle to share the package
+fn shared_package(package_name: &str, package: Package, publish: bool) -> Package {
+    // Packages which are not bindings are returned with an empty binding
+    let binding = if package.is_binding() {
+        package.binding.clone()
+    } else {
+        None
+    };
+
+    Package {
+        name: package_name.to_string(),
+        publish,
+        binding,
+        ..package
+    }
+}
+
+/// Returns the binding of a package
+///
+/// # Arguments
+/// * `binding_name` - String slice that holds the name of the binding
+/// * `package`      - The package to be bound
+/// * `parameters`   - Parameters which are bound to the package
+fn package_binding(
+    binding_name: &str,
+    package: &PackageBinding,
+    parameters: Vec<KeyValue>,
+) -> Package {
+    Package {
+        name: binding_name.to_string(),
+        binding: Some(package.clone()),
+        parameters,
+        ..Default::default()
+    }
+}
+
+impl<T> PackageService<T> {
+    /// Returns the url of the packages, or of a package for a non empty name
+    fn packages_url(&self, package_name: &str) -> String {
+        let url = collection_url(&self.context, self.context.namespace(), PACKAGES_ENDPOINT);
+
+        if package_name.is_empty() {
+            url
+        } else {
+            format!("{}/{}", url, encode_entity_name(package_name))
+        }
+    }
+
+    fn list_request(&self, options: Option<&PackageListOptions>) -> WhiskRequest {
+        let url = match options {
+            Some(options) => format!("{}?{}", self.packages_url(""), options.query()),
+            None => self.packages_url(""),
+        };
+
+        WhiskRequest::new(HttpMethods::GET, url)
+    }
+
+    fn insert_request(
+        &self,
+        package: &Package,
+        overwrite: bool,
+    ) -> Result<WhiskRequest, OpenWhiskError> {
+        let url = format!(
+            "{}?overwrite={}",
+            self.packages_url(&package.name),
+            overwrite
+        );
+
+        Ok(WhiskRequest::new(HttpMethods::PUT, url).set_body(serialize_body(package)?))
+    }
+}
+
+impl<T> PackageService<T>
+where
+    T: Service,
+{
+    /// Returns a list of Packages
+    pub fn list(&self) -> Result<Vec<Package>, OpenWhiskError> {
+        let response = self.list_request(None).send(&self.client, &self.context)?;
+
+        deserialize_response(response, "packages")
+    }
+
+    /// Returns a page of Packages selected by the list options
+    ///
+    /// # Arguments
+    /// * `options` - Limit, skip count and toggles for the list request
+    ///
+    pub fn list_with_options(
+        &self,
+        options: &PackageListOptions,
+    ) -> Result<Vec<Package>, OpenWhiskError> {
+        let response = self
+            .list_request(Some(options))
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "packages")
+    }
+
+    /// Returns an iterator which lazily walks every page of Packages
+    ///
+    /// # Arguments
+    /// * `options` - Page size (limit), skip count of the first page and toggles for the list request
+    ///
+    pub fn iter(&self, options: PackageListOptions) -> ListIterator<'_, Package> {
+        ListIterator::new(options.limit, options.skip, move |skip, limit| {
+            self.list_with_options(&PackageListOptions {
+                limit,
+                skip,
+                ..options.clone()
+            })
+        })
+    }
+
+    /// Inserts a package
+    ///
+    /// # Arguments
+    /// * `package` - The package to be inserted
+    /// * `overwrite`  - Toggle to get overwrtite an existing package
+    ///
+    pub fn insert(&self, package: &Package, overwrite: bool) -> Result<Package, OpenWhiskError> {
+        let response = self
+            .insert_request(package, overwrite)?
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "package")
+    }
+
+    /// To get the properties of the package
+    ///
+    /// # Arguments
+    /// * `package_name` - String slice that holds package name
+    ///
+    pub fn get(&self, package_name: &str) -> Result<Package, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::GET, self.packages_url(package_name))
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "package")
+    }
+
+    /// Deletes an already existing package
+    ///
+    /// # Arguments
+    /// * `package_name` - String slice that holds package name
+    ///
+    pub fn delete(&self, package_name: &str) -> Result<Package, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::DELETE, self.packages_url(package_name))
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "package")
+    }
+
+    /// Creates a binding to an existing package
+    ///
+    /// # Arguments
+    /// * `binding_name` - String slice that holds the name of the binding
+    /// * `package`      - The package to be bound
+    /// * `parameters`   - Parameters which are bound to the package
+    /// * `overwrite`    - Toggle to get overwrtite an existing binding
+    ///
+    pub fn bind(
+        &self,
+        binding_name: &str,
+        package: &PackageBinding,
+        parameters: Vec<KeyValue>,
+        overwrite: bool,
+    ) -> Result<Package, OpenWhiskError> {
+        self.insert(
+            &package_binding(binding_name, package, parameters),
+            overwrite,
+        )
+    }
+
+    /// Sets whether the package is shared with other namespaces.
+    /// The package is updated in place, a package which does not exist is `NotFound`
+    ///
+    /// # Arguments
+    /// * `package_name` - String slice that holds package name
+    /// * `publish`      - Toggle to share the package
+    ///
+    pub fn share(&self, package_name: &str, publish: bool) -> Result<Package, OpenWhiskError> {
+        let package = shared_package(package_name, self.get(package_name)?, publish);
+
+        self.insert(&package, true)
+    }
+}
+
+impl<T> PackageService<T>
+where
+    T: AsyncService + Sync,
+{
+    /// Returns an async iterator which lazily walks every page of Packages
+    ///
+    /// # Arguments
+    /// * `options` - Page size (limit), skip count of the first page and toggles for the list request
+    ///
+    pub fn iter_async(&self, options: PackageListOptions) -> AsyncListIterator<'_, Package> {
+        AsyncListIterator::new(options.limit, options.skip, move |skip, limit| {
+            let options = PackageListOptions {
+                limit,
+                skip,
+                ..options.clone()
+            };
+
+            async move { self.list_with_options_async(&options).await }
+        })
+    }
+}
+
+impl<T> PackageService<T>
+where
+    T: AsyncService,
+{
+    /// Returns a list of Packages
+    pub async fn list_async(&self) -> Result<Vec<Package>, OpenWhiskError> {
+        let response = self
+            .list_request(None)
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "packages")
+    }
+
+    /// Returns a page of Packages selected by the list options
+    ///
+    /// # Arguments
+    /// * `options` - Limit, skip count and toggles for the list request
+    ///
+    pub async fn list_with_options_async(
+        &self,
+        options: &PackageListOptions,
+    ) -> Result<Vec<Package>, OpenWhiskError> {
+        let response = self
+            .list_request(Some(options))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "packages")
+    }
+
+    /// Inserts a package
+    ///
+    /// # Arguments
+    /// * `package` - The package to be inserted
+    /// * `overwrite`  - Toggle to get overwrtite an existing package
+    ///
+    pub async fn insert_async(
+        &self,
+        package: &Package,
+        overwrite: bool,
+    ) -> Result<Package, OpenWhiskError> {
+        let response = self
+            .insert_request(package, overwrite)?
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "package")
+    }
+
+    /// To get the properties of the package
+    ///
+    /// # Arguments
+    /// * `package_name` - String slice that holds package name
+    ///
+    pub async fn get_async(&self, package_name: &str) -> Result<Package, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::GET, self.packages_url(package_name))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "package")
+    }
+
+    /// Deletes an already existing package
+    ///
+    /// # Arguments
+    /// * `package_name` - String slice that holds package name
+    ///
+    pub async fn delete_async(&self, package_name: &str) -> Result<Package, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::DELETE, self.packages_url(package_name))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "package")
+    }
+
+    /// Creates a binding to an existing package
+    ///
+    /// # Arguments
+    /// * `binding_name` - String slice that holds the name of the binding
+    /// * `package`      - The package to be bound
+    /// * `parameters`   - Parameters which are bound to the package
+    /// * `overwrite`    - Toggle to get overwrtite an existing binding
+    ///
+    pub async fn bind_async(
+        &self,
+        binding_name: &str,
+        package: &PackageBinding,
+        parameters: Vec<KeyValue>,
+        overwrite: bool,
+    ) -> Result<Package, OpenWhiskError> {
+        self.insert_async(
+            &package_binding(binding_name, package, parameters),
+            overwrite,
+        )
+        .await
+    }
+
+    /// Sets whether the package is shared with other namespaces.
+    /// The package is updated in place, a package which does not exist is `NotFound`
+    ///
+    /// # Arguments
+    /// * `package_name` - String slice that holds package name
+    /// * `publish`      - Toggle to share the package
+    ///
+    pub async fn share_async(
+        &self,
+        package_name: &str,
+        publish: bool,
+    ) -> Result<Package, OpenWhiskError> {
+        let package = shared_package(package_name, self.get_async(package_name).await?, publish);
+
+        self.insert_async(&package, true).await
+    }
+}
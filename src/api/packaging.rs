@@ -0,0 +1,244 @@
+use std::fs;
+use std::io::{self, Cursor, Write};
+use std::path::{Path, PathBuf};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, DateTime, ZipWriter};
+
+use super::{Exec, OpenWhiskError, Runtime};
+
+/// Returns the runtime of a source file or prebuilt artifact from its extension, as the wsk CLI does
+///
+/// # Arguments
+/// * `extension` - File extension without the leading dot
+pub fn runtime_for_extension(extension: &str) -> Option<Runtime> {
+    match extension {
+        "js" => Some(Runtime::NODEJS_DEFAULT),
+        "py" => Some(Runtime::PYTHON_DEFAULT),
+        "go" => Some(Runtime::GO_DEFAULT),
+        "jar" => Some(Runtime::JAVA_DEFAULT),
+        "php" => Some(Runtime::PHP_DEFAULT),
+        "rb" => Some(Runtime::RUBY_DEFAULT),
+        "rs" => Some(Runtime::RUST_DEFAULT),
+        "swift" => Some(Runtime::SWIFT_DEFAULT),
+        _ => None,
+    }
+}
+
+/// Returns the runtime of a source directory from the project files it holds
+///
+/// # Arguments
+/// * `dir` - Path to the source directory
+fn runtime_for_dir(dir: &Path) -> Option<Runtime> {
+    let markers = [
+        ("package.json", Runtime::NODEJS_DEFAULT),
+        ("__main__.py", Runtime::PYTHON_DEFAULT),
+        ("Cargo.toml", Runtime::RUST_DEFAULT),
+        ("go.mod", Runtime::GO_DEFAULT),
+        ("index.php", Runtime::PHP_DEFAULT),
+        ("main.rb", Runtime::RUBY_DEFAULT),
+        ("Package.swift", Runtime::SWIFT_DEFAULT),
+    ];
+
+    markers
+        .iter()
+        .find(|(marker, _)| dir.join(marker).is_file())
+        .map(|(_, runtime)| runtime.clone())
+}
+
+impl Exec {
+    /// Creates the executable of a code action from a source file, a directory or a zip archive,
+    /// like `wsk action create <name> <path> --kind <kind>` does
+    ///
+    /// Source files are sent as text, directories are zipped and zip or jar archives are sent as they are.
+    /// The kind is inferred from the file extension, or from the project files of a directory, when not given.
+    ///
+    /// # Arguments
+    /// * `path` - Path to a source file, directory, or zip or jar archive
+    /// * `kind` - Option of runtime kind, inferred when None
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{Exec, Runtime};
+    ///
+    /// let exec = Exec::from_path("./hello", Some(Runtime::NODEJS_20)).unwrap();
+    ///
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(
+        path: P,
+        kind: Option<Runtime>,
+    ) -> Result<Self, OpenWhiskError> {
+        let path = path.as_ref();
+
+        if path.is_dir() {
+            return Exec::from_dir(path, kind);
+        }
+
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let kind = match kind.or_else(|| runtime_for_extension(&extension)) {
+            Some(kind) => kind,
+            None => {
+                return Err(OpenWhiskError::InvalidInput(format!(
+                    "Kind of {} cannot be inferred, set it explicitly",
+                    path.display()
+                )))
+            }
+        };
+
+        match extension.as_str() {
+            "zip" | "jar" => Ok(Exec::binary(kind, &base64::encode(read(path)?))),
+            _ => match String::from_utf8(read(path)?) {
+                Ok(code) => Ok(Exec::code(kind, &code)),
+                Err(_) => Err(OpenWhiskError::InvalidInput(format!(
+                    "{} is neither utf-8 source code nor a zip archive",
+                    path.display()
+                ))),
+            },
+        }
+    }
+
+    /// Creates the executable of a code action from a directory, zipped in memory
+    ///
+    /// # Arguments
+    /// * `dir`  - Path to the source directory
+    /// * `kind` - Option of runtime kind, inferred from the project files when None
+    pub fn from_dir<P: AsRef<Path>>(dir: P, kind: Option<Runtime>) -> Result<Self, OpenWhiskError> {
+        let dir = dir.as_ref();
+
+        let kind = match kind.or_else(|| runtime_for_dir(dir)) {
+            Some(kind) => kind,
+            None => {
+                return Err(OpenWhiskError::InvalidInput(format!(
+                    "Kind of {} cannot be inferred, set it explicitly",
+                    dir.display()
+                )))
+            }
+        };
+
+        Ok(Exec::binary(kind, &base64::encode(zip_dir(dir)?)))
+    }
+}
+
+/// Zips the files of a directory in memory.
+/// Entries are sorted and timestamps are fixed, so the same files always give the same archive
+///
+/// # Arguments
+/// * `dir` - Path to the directory to be zipped
+pub fn zip_dir<P: AsRef<Path>>(dir: P) -> Result<Vec<u8>, OpenWhiskError> {
+    let dir = dir.as_ref();
+
+    let mut files = Vec::new();
+    collect_files(dir, &mut files)?;
+    files.sort();
+
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+
+    for file in files {
+        let name = match file.strip_prefix(dir) {
+            Ok(name) => name
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<String>>()
+                .join("/"),
+            Err(_) => continue,
+        };
+
+        let options = FileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .last_modified_time(DateTime::default())
+            .unix_permissions(permissions(&file));
+
+        let content = read(&file)?;
+
+        if let Err(error) = writer.start_file(name, options) {
+            return Err(OpenWhiskError::Serialize(format!(
+                "Failed to zip {} {}",
+                file.display(),
+                error
+            )));
+        }
+
+        if let Err(error) = writer.write_all(&content) {
+            return Err(OpenWhiskError::Serialize(format!(
+                "Failed to zip {} {}",
+                file.display(),
+                error
+            )));
+        }
+    }
+
+    match writer.finish() {
+        Ok(cursor) => Ok(cursor.into_inner()),
+        Err(error) => Err(OpenWhiskError::Serialize(format!(
+            "Failed to zip {} {}",
+            dir.display(),
+            error
+        ))),
+    }
+}
+
+/// Collects the files of a directory and its subdirectories.
+/// Symlinked files are zipped with the content they point to, symlinked directories are
+/// skipped, as they may point back to a parent and the archive would never end
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), OpenWhiskError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) => return Err(read_error(dir, error)),
+    };
+
+    for entry in entries {
+        let path = match entry {
+            Ok(entry) => entry.path(),
+            Err(error) => return Err(read_error(dir, error)),
+        };
+
+        let metadata = match fs::symlink_metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(error) => return Err(read_error(&path, error)),
+        };
+
+        if metadata.is_dir() {
+            collect_files(&path, files)?;
+        } else if metadata.file_type().is_symlink() && path.is_dir() {
+            continue;
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, OpenWhiskError> {
+    match fs::read(path) {
+        Ok(content) => Ok(content),
+        Err(error) => Err(read_error(path, error)),
+    }
+}
+
+/// Failures of reading the file system are reported as configuration errors, like the
+/// certificates of the properties, as the paths are valid but can not be read
+fn read_error(path: &Path, error: io::Error) -> OpenWhiskError {
+    OpenWhiskError::Configuration(format!("Failed to read {} {}", path.display(), error))
+}
+
+/// Executables keep their executable bit, as runtimes like the docker skeleton run them directly
+#[cfg(unix)]
+fn permissions(path: &Path) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.permissions().mode() & 0o111 != 0 => 0o755,
+        _ => 0o644,
+    }
+}
+
+#[cfg(not(unix))]
+fn permissions(_path: &Path) -> u32 {
+    0o644
+}
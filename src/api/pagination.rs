@@ -0,0 +1,189 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use super::OpenWhiskError;
+
+/// Maximum number of entities OpenWhisk returns for a single list request
+pub static MAX_LIST_LIMIT: i64 = 200;
+
+/// Builds the query string shared by list requests
+///
+/// # Arguments
+/// * `limit` - Number of entities to return, 0 returns the maximum
+/// * `skip`  - Number of entities to skip
+/// * `docs`  - Toggle to return full entity documents
+pub(crate) fn list_query(limit: i64, skip: i64, docs: bool) -> String {
+    format!("limit={}&skip={}&docs={}", limit, skip, docs)
+}
+
+/// Pages fetched from a list endpoint, shared by the blocking and async iterators
+struct Pages<E> {
+    /// Number of entities requested per page
+    page_size: i64,
+    /// Skip count of the next page
+    skip: i64,
+    /// Entities of the current page which are not yet consumed
+    page: std::vec::IntoIter<E>,
+    /// Toggled to true once the last page is fetched
+    finished: bool,
+}
+
+impl<E> Pages<E> {
+    /// Creates the pages of a list request
+    ///
+    /// # Arguments
+    /// * `limit` - Page size, 0 or values above `MAX_LIST_LIMIT` use the maximum
+    /// * `skip`  - Skip count of the first page
+    fn new(limit: i64, skip: i64) -> Self {
+        let page_size = if limit <= 0 || limit > MAX_LIST_LIMIT {
+            MAX_LIST_LIMIT
+        } else {
+            limit
+        };
+
+        Pages {
+            page_size,
+            skip,
+            page: Vec::new().into_iter(),
+            finished: false,
+        }
+    }
+
+    /// Returns the skip count of the page to fetch next,
+    /// None when the current page still holds entities or the last page is fetched
+    fn next_skip(&self) -> Option<i64> {
+        if !self.page.as_slice().is_empty() || self.finished {
+            None
+        } else {
+            Some(self.skip)
+        }
+    }
+
+    /// Stores a fetched page, the pages end after a short page or an error
+    fn push(&mut self, page: Result<Vec<E>, OpenWhiskError>) -> Option<Result<E, OpenWhiskError>> {
+        match page {
+            Ok(entities) => {
+                let count = entities.len() as i64;
+
+                self.finished = count < self.page_size;
+                self.skip += count;
+                self.page = entities.into_iter();
+
+                self.page.next().map(Ok)
+            }
+            Err(error) => {
+                self.finished = true;
+
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// Lazy iterator which walks every page of a list endpoint
+///
+/// A page is only requested once the entities of the previous page are consumed.
+/// The iterator ends after a page shorter than the page size, or after yielding an error.
+pub struct ListIterator<'a, E> {
+    /// Fetches the page starting at the given skip count
+    fetch: Box<dyn Fn(i64) -> Result<Vec<E>, OpenWhiskError> + 'a>,
+    /// Pages fetched so far
+    pages: Pages<E>,
+}
+
+impl<'a, E> ListIterator<'a, E> {
+    /// Creates a new ListIterator
+    ///
+    /// # Arguments
+    /// * `limit` - Page size, 0 or values above `MAX_LIST_LIMIT` use the maximum
+    /// * `skip`  - Skip count of the first page
+    /// * `fetch` - Closure which fetches a page for a skip count and page size
+    pub fn new<F>(limit: i64, skip: i64, fetch: F) -> Self
+    where
+        F: Fn(i64, i64) -> Result<Vec<E>, OpenWhiskError> + 'a,
+    {
+        let pages = Pages::new(limit, skip);
+        let page_size = pages.page_size;
+
+        ListIterator {
+            fetch: Box::new(move |skip| fetch(skip, page_size)),
+            pages,
+        }
+    }
+}
+
+impl<E> Iterator for ListIterator<'_, E> {
+    type Item = Result<E, OpenWhiskError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pages.next_skip() {
+            Some(skip) => {
+                let page = (self.fetch)(skip);
+
+                self.pages.push(page)
+            }
+            None => self.pages.page.next().map(Ok),
+        }
+    }
+}
+
+/// Future of a page fetched by an AsyncListIterator
+type PageFuture<'a, E> = Pin<Box<dyn Future<Output = Result<Vec<E>, OpenWhiskError>> + Send + 'a>>;
+
+/// Async counterpart of ListIterator, which lazily walks every page of a list endpoint
+///
+/// A page is only requested once the entities of the previous page are consumed.
+/// Entities are returned by `next_entity` until a page shorter than the page size,
+/// or an error, is returned.
+pub struct AsyncListIterator<'a, E> {
+    /// Fetches the page starting at the given skip count
+    fetch: Box<dyn Fn(i64) -> PageFuture<'a, E> + Send + Sync + 'a>,
+    /// Pages fetched so far
+    pages: Pages<E>,
+}
+
+impl<'a, E> AsyncListIterator<'a, E> {
+    /// Creates a new AsyncListIterator
+    ///
+    /// # Arguments
+    /// * `limit` - Page size, 0 or values above `MAX_LIST_LIMIT` use the maximum
+    /// * `skip`  - Skip count of the first page
+    /// * `fetch` - Closure which returns the future of a page for a skip count and page size
+    pub fn new<F, P>(limit: i64, skip: i64, fetch: F) -> Self
+    where
+        F: Fn(i64, i64) -> P + Send + Sync + 'a,
+        P: Future<Output = Result<Vec<E>, OpenWhiskError>> + Send + 'a,
+    {
+        let pages = Pages::new(limit, skip);
+        let page_size = pages.page_size;
+
+        AsyncListIterator {
+            fetch: Box::new(move |skip| Box::pin(fetch(skip, page_size))),
+            pages,
+        }
+    }
+
+    /// Returns the next entity, fetching the next page once the current one is consumed.
+    /// None is returned after the last entity or after an error
+    pub async fn next_entity(&mut self) -> Option<Result<E, OpenWhiskError>> {
+        match self.pages.next_skip() {
+            Some(skip) => {
+                let page = (self.fetch)(skip).await;
+
+                self.pages.push(page)
+            }
+            None => self.pages.page.next().map(Ok),
+        }
+    }
+
+    /// Fetches every remaining page and returns their entities
+    pub async fn collect_all(mut self) -> Result<Vec<E>, OpenWhiskError> {
+        let mut entities = Vec::new();
+
+        while let Some(entity) = self.next_entity().await {
+            entities.push(entity?);
+        }
+
+        Ok(entities)
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use super::{ApiError, OpenWhiskError};
+
+/// Options of polling an activation until it completes.
+/// The interval between polls grows by `multiplier` up to `max_interval`,
+/// polling stops with `OpenWhiskError::Timeout` once `deadline` has passed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollOptions {
+    /// Interval before the first poll
+    pub interval: Duration,
+    /// Upper bound of the interval between polls
+    pub max_interval: Duration,
+    /// Factor applied to the interval after each poll
+    pub multiplier: u32,
+    /// Overall time to wait for the activation
+    pub deadline: Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        PollOptions {
+            interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(10),
+            multiplier: 2,
+            deadline: Duration::from_secs(600),
+        }
+    }
+}
+
+impl PollOptions {
+    /// Creates poll options with the default backoff and deadline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the interval before the first poll
+    ///
+    /// # Arguments
+    /// * `interval` - Interval before the first poll
+    ///
+    pub fn set_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the upper bound of the interval between polls
+    ///
+    /// # Arguments
+    /// * `max_interval` - Upper bound of the interval between polls
+    ///
+    pub fn set_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Sets the factor applied to the interval after each poll, 1 polls at a fixed interval
+    ///
+    /// # Arguments
+    /// * `multiplier` - Factor applied to the interval
+    ///
+    pub fn set_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets the overall time to wait for the activation
+    ///
+    /// # Arguments
+    /// * `deadline` - Overall time to wait for the activation
+    ///
+    pub fn set_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Returns the interval following the given one
+    pub(crate) fn next_interval(&self, interval: Duration) -> Duration {
+        interval
+            .saturating_mul(self.multiplier.max(1))
+            .min(self.max_interval)
+    }
+
+    /// Returns the interval to sleep, bounded by the time left until the deadline
+    pub(crate) fn sleep_interval(&self, interval: Duration, elapsed: Duration) -> Duration {
+        interval.min(self.deadline.saturating_sub(elapsed))
+    }
+}
+
+/// Returns the error of an activation which did not complete before the deadline
+pub(crate) fn deadline_exceeded(activation_id: &str, options: &PollOptions) -> OpenWhiskError {
+    OpenWhiskError::Timeout(ApiError {
+        status: 202,
+        message: format!(
+            "Activation {} did not complete within {:?}",
+            activation_id, options.deadline
+        ),
+        activation_id: Some(activation_id.to_string()),
+        ..Default::default()
+    })
+}
@@ -0,0 +1,236 @@
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{AsyncService, HttpMethods, HttpResponse, OpenWhiskError, Service, NAMESPACE_ENDPOINT};
+use crate::client::{whisk_errors, Context};
+
+/// Json request to the OpenWhisk API.
+/// Services build the request once and send it from their blocking or async methods
+#[derive(Debug, Clone)]
+pub(crate) struct WhiskRequest {
+    /// Http method of the request
+    method: HttpMethods,
+    /// Url of the request
+    url: String,
+    /// Json body of the request
+    body: Option<Value>,
+    /// Toggle set for blocking invokes, whose 202 response is a timeout
+    blocking: bool,
+}
+
+impl WhiskRequest {
+    /// Creates a request without a body
+    ///
+    /// # Arguments
+    /// * `method` - Http method of the request
+    /// * `url`    - Url of the request
+    pub(crate) fn new(method: HttpMethods, url: String) -> Self {
+        WhiskRequest {
+            method,
+            url,
+            body: None,
+            blocking: false,
+        }
+    }
+
+    /// Sets the json body of the request
+    ///
+    /// # Arguments
+    /// * `body` - Json body of the request
+    pub(crate) fn set_body(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Marks the request as a blocking invoke. OpenWhisk answers 202 with the activation id
+    /// when the action does not complete in time, which is returned as `OpenWhiskError::Timeout`
+    ///
+    /// # Arguments
+    /// * `blocking` - Toggle the invoke was sent with
+    pub(crate) fn set_blocking(mut self, blocking: bool) -> Self {
+        self.blocking = blocking;
+        self
+    }
+
+    /// Sends the request with a blocking client, authenticated by the context,
+    /// and returns the json body of the response
+    pub(crate) fn send<T: Service>(
+        self,
+        client: &T,
+        context: &Context,
+    ) -> Result<Value, OpenWhiskError> {
+        let request = client.new_request(
+            Some(self.method),
+            self.url.as_str(),
+            Some(context.auth()),
+            self.body,
+        )?;
+
+        match self.blocking {
+            true => blocking_response(client.invoke_raw_request(request)?),
+            false => client.invoke_request(request),
+        }
+    }
+
+    /// Sends the request with an async client, authenticated by the context,
+    /// and returns the json body of the response
+    pub(crate) async fn send_async<T: AsyncService>(
+        self,
+        client: &T,
+        context: &Context,
+    ) -> Result<Value, OpenWhiskError> {
+        let request = client.new_request(
+            Some(self.method),
+            self.url.as_str(),
+            Some(context.auth()),
+            self.body,
+        )?;
+
+        match self.blocking {
+            true => blocking_response(client.invoke_raw_request(request).await?),
+            false => client.invoke_request(request).await,
+        }
+    }
+}
+
+/// Request with custom headers and a raw body, whose raw response is returned
+#[derive(Debug, Clone)]
+pub(crate) struct RawRequest {
+    /// Http method of the request
+    method: HttpMethods,
+    /// Url of the request
+    url: String,
+    /// Toggle to authenticate the request with the context
+    authenticated: bool,
+    /// Headers sent along with the request
+    headers: Vec<(String, String)>,
+    /// Raw body of the request
+    body: Option<Vec<u8>>,
+}
+
+impl RawRequest {
+    /// Creates a request
+    ///
+    /// # Arguments
+    /// * `method`        - Http method of the request
+    /// * `url`           - Url of the request
+    /// * `authenticated` - Toggle to authenticate the request with the context
+    /// * `headers`       - Headers sent along with the request
+    /// * `body`          - Raw body of the request
+    pub(crate) fn new(
+        method: HttpMethods,
+        url: String,
+        authenticated: bool,
+        headers: Vec<(String, String)>,
+        body: Option<Vec<u8>>,
+    ) -> Self {
+        RawRequest {
+            method,
+            url,
+            authenticated,
+            headers,
+            body,
+        }
+    }
+
+    /// Sends the request with a blocking client and returns the raw response
+    pub(crate) fn send<T: Service>(
+        self,
+        client: &T,
+        context: &Context,
+    ) -> Result<HttpResponse, OpenWhiskError> {
+        let user_auth = self.authenticated.then(|| context.auth());
+
+        let request = client.new_raw_request(
+            self.method,
+            self.url.as_str(),
+            user_auth,
+            &self.headers,
+            self.body,
+        )?;
+
+        client.invoke_raw_request(request)
+    }
+
+    /// Sends the request with an async client and returns the raw response
+    pub(crate) async fn send_async<T: AsyncService>(
+        self,
+        client: &T,
+        context: &Context,
+    ) -> Result<HttpResponse, OpenWhiskError> {
+        let user_auth = self.authenticated.then(|| context.auth());
+
+        let request = client.new_raw_request(
+            self.method,
+            self.url.as_str(),
+            user_auth,
+            &self.headers,
+            self.body,
+        )?;
+
+        client.invoke_raw_request(request).await
+    }
+}
+
+/// Returns the json body of the response of a blocking invoke. A 202 response holds the id
+/// of an activation which did not complete in time and continues asynchronously
+///
+/// # Arguments
+/// * `response` - Response of the blocking invoke
+fn blocking_response(response: HttpResponse) -> Result<Value, OpenWhiskError> {
+    if response.status == StatusCode::ACCEPTED.as_u16() {
+        return Err(whisk_errors(StatusCode::ACCEPTED, &response.body));
+    }
+
+    response.into_value()
+}
+
+/// Returns the url of a collection of entities in a namespace, e.g. `/api/v1/namespaces/guest/actions`
+///
+/// # Arguments
+/// * `context`   - Context holding the host
+/// * `namespace` - Namespace of the entities
+/// * `endpoint`  - Endpoint of the entity collection
+pub(crate) fn collection_url(context: &Context, namespace: &str, endpoint: &str) -> String {
+    format!(
+        "{}/api/v1/{}/{}/{}",
+        context.host(),
+        NAMESPACE_ENDPOINT,
+        namespace,
+        endpoint
+    )
+}
+
+/// Serializes the json body of a request
+///
+/// # Arguments
+/// * `body` - Entity sent in the request
+pub(crate) fn serialize_body<B: Serialize>(body: &B) -> Result<Value, OpenWhiskError> {
+    match serde_json::to_value(body) {
+        Ok(body) => Ok(body),
+        Err(error) => Err(OpenWhiskError::Serialize(format!(
+            "Failed to serialize body {}",
+            error
+        ))),
+    }
+}
+
+/// Deserializes the json body of a response
+///
+/// # Arguments
+/// * `response` - Json body of the response
+/// * `entity`   - Name of the entity, used in the error message
+pub(crate) fn deserialize_response<R: DeserializeOwned>(
+    response: Value,
+    entity: &str,
+) -> Result<R, OpenWhiskError> {
+    match serde_json::from_value(response) {
+        Ok(result) => Ok(result),
+        Err(error) => Err(OpenWhiskError::Deserialize(format!(
+            "Failed to deserialize {} {}",
+            entity, error
+        ))),
+    }
+}
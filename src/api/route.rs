@@ -0,0 +1,245 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{
+    deserialize_response, encode_query_value, split_qualified_name, web_action_url, HttpMethods,
+    OpenWhiskError, RawRequest, Service, APIGW_ACCESS_TOKEN_HEADER, APIGW_ENDPOINT,
+};
+use crate::client::Context;
+
+/// Representation of Route Service
+#[derive(new, Default, Debug, Clone)]
+pub struct RouteService<T> {
+    /// A route service must have a client to handle http request
+    client: T,
+    /// A route service uses the context which sets openwhisk properties
+    context: Context,
+}
+
+/// Representation of an API Gateway route
+#[derive(new, Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct Route {
+    /// Base path of the API, e.g. `/hello`
+    pub base_path: String,
+    /// Path of the route relative to the base path, e.g. `/world`
+    pub relative_path: String,
+    /// HTTP verb of the route, e.g. `GET`
+    pub verb: String,
+    /// Name of the web action the route invokes, optionally qualified as `package/action`
+    pub action: String,
+    /// Response type of the web action (json, http, text or svg)
+    pub response_type: String,
+    /// Name of the API, defaults to the base path
+    #[serde(default)]
+    pub api_name: Option<String>,
+}
+
+/// Representation of the list of APIs
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct ApiList {
+    /// APIs which are configured in the namespace
+    #[serde(default)]
+    pub apis: Vec<ApiItem>,
+}
+
+/// Representation of an API in the list of APIs
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct ApiItem {
+    /// Id of the API
+    #[serde(default)]
+    pub id: String,
+    /// Key which identifies the API
+    #[serde(default)]
+    pub key: String,
+    /// Configuration of the API
+    #[serde(default)]
+    pub value: Api,
+}
+
+/// Representation of an API
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct Api {
+    /// Namespace of the API
+    #[serde(default)]
+    pub namespace: String,
+    /// Url where the API is served by the gateway
+    #[serde(rename = "gwApiUrl", default)]
+    pub gw_api_url: String,
+    /// Toggled to true when the API is activated on the gateway
+    #[serde(rename = "gwApiActivated", default)]
+    pub gw_api_activated: bool,
+    /// OpenAPI (Swagger) definition of the API
+    #[serde(rename = "apidoc", default)]
+    pub swagger: Value,
+}
+
+impl<T> RouteService<T>
+where
+    T: Service,
+{
+    /// Returns a list of APIs
+    ///
+    /// # Arguments
+    /// * `base_path` - Option of base path or API name to filter the APIs
+    ///
+    pub fn list(&self, base_path: Option<&str>) -> Result<ApiList, OpenWhiskError> {
+        let mut params = vec![];
+        if let Some(base_path) = base_path {
+            params.push(("basepath", base_path));
+        }
+
+        let response = self.send(HttpMethods::GET, "getApi", &params, None)?;
+
+        deserialize_response(response, "apis")
+    }
+
+    /// Creates a route which invokes a web action
+    ///
+    /// # Arguments
+    /// * `route` - The route to be created
+    ///
+    pub fn insert(&self, route: &Route) -> Result<Api, OpenWhiskError> {
+        let user_auth = self.context.auth();
+
+        let backend_url = web_action_url(&self.context, &route.action, &route.response_type);
+        let (action_namespace, action_name) =
+            split_qualified_name(self.context.namespace(), &route.action);
+
+        let api_name = match &route.api_name {
+            Some(api_name) => api_name.clone(),
+            None => route.base_path.clone(),
+        };
+
+        let body = json!({
+            "apidoc": {
+                "namespace": self.context.namespace(),
+                "gatewayBasePath": route.base_path,
+                "gatewayPath": route.relative_path,
+                "gatewayMethod": route.verb.to_uppercase(),
+                "id": format!("API:{}:{}", self.context.namespace(), route.base_path),
+                "apiName": api_name,
+                "action": {
+                    "name": action_name,
+                    "namespace": action_namespace,
+                    "backendMethod": route.verb.to_uppercase(),
+                    "backendUrl": backend_url,
+                    "authkey": format!("{}:{}", user_auth.0, user_auth.1),
+                },
+            }
+        });
+
+        self.create(&[("responsetype", route.response_type.as_str())], body)
+    }
+
+    /// Creates the routes described by an OpenAPI (Swagger) definition
+    ///
+    /// # Arguments
+    /// * `swagger` - OpenAPI definition of the API
+    ///
+    pub fn import(&self, swagger: &Value) -> Result<Api, OpenWhiskError> {
+        let body = json!({
+            "apidoc": {
+                "namespace": self.context.namespace(),
+                "swagger": swagger.to_string(),
+            }
+        });
+
+        self.create(&[], body)
+    }
+
+    /// Returns the OpenAPI (Swagger) definition of an API
+    ///
+    /// # Arguments
+    /// * `base_path` - Base path or name of the API
+    ///
+    pub fn export(&self, base_path: &str) -> Result<Value, OpenWhiskError> {
+        let apis = self.list(Some(base_path))?;
+
+        match apis.apis.into_iter().next() {
+            Some(api) => Ok(api.value.swagger),
+            None => Err(OpenWhiskError::InvalidInput(format!(
+                "Api {} does not exist",
+                base_path
+            ))),
+        }
+    }
+
+    /// Deletes an API, or the routes of an API matching the relative path and verb
+    ///
+    /// # Arguments
+    /// * `base_path`     - Base path or name of the API
+    /// * `relative_path` - Option of relative path of the routes to be deleted
+    /// * `verb`          - Option of HTTP verb of the route to be deleted
+    ///
+    pub fn delete(
+        &self,
+        base_path: &str,
+        relative_path: Option<&str>,
+        verb: Option<&str>,
+    ) -> Result<(), OpenWhiskError> {
+        let mut params = vec![("basepath", base_path)];
+        if let Some(relative_path) = relative_path {
+            params.push(("relpath", relative_path));
+        }
+        if let Some(verb) = verb {
+            params.push(("operation", verb));
+        }
+
+        self.send(HttpMethods::DELETE, "deleteApi", &params, None)?;
+
+        Ok(())
+    }
+
+    fn create(&self, params: &[(&str, &str)], body: Value) -> Result<Api, OpenWhiskError> {
+        let response = self.send(HttpMethods::POST, "createApi", params, Some(body))?;
+
+        deserialize_response(response, "api")
+    }
+
+    /// Sends a request to an API management operation and returns its json response.
+    /// The API Gateway access token is sent in the `apigw-access-token` header, so it does
+    /// not end up in the logs of proxies and servers like the query parameters do
+    fn send(
+        &self,
+        method: HttpMethods,
+        operation: &str,
+        params: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Value, OpenWhiskError> {
+        let mut headers = vec![];
+        if let Some(token) = self.context.apigw_access_token() {
+            headers.push((APIGW_ACCESS_TOKEN_HEADER.to_string(), token.to_string()));
+        }
+
+        let body = match body {
+            Some(body) => {
+                headers.push(("Content-Type".to_string(), "application/json".to_string()));
+                Some(body.to_string().into_bytes())
+            }
+            None => None,
+        };
+
+        RawRequest::new(method, self.url(operation, params), true, headers, body)
+            .send(&self.client, &self.context)?
+            .into_value()
+    }
+
+    /// Builds the url of an API management operation.
+    /// The subject of the auth key identifies the caller to the gateway
+    fn url(&self, operation: &str, params: &[(&str, &str)]) -> String {
+        let mut query = vec![format!("spaceguid={}", self.context.auth().0)];
+
+        for (key, value) in params {
+            query.push(format!("{}={}", key, encode_query_value(value)));
+        }
+
+        format!(
+            "{}/api/v1/{}/{}.http?{}",
+            self.context.host(),
+            APIGW_ENDPOINT,
+            operation,
+            query.join("&")
+        )
+    }
+}
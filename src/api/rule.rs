@@ -2,7 +2,11 @@ use derive_new::new;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::{HttpMethods, KeyValue, Service, NAMESPACE_ENDPOINT, RULES_ENDPOINT};
+use super::{
+    collection_url, deserialize_response, list_query, serialize_body, AsyncListIterator,
+    AsyncService, HttpMethods, KeyValue, ListIterator, OpenWhiskError, Service, WhiskRequest,
+    RULES_ENDPOINT,
+};
 use crate::client::Context;
 
 /// Representation of rule Service
@@ -22,12 +26,12 @@ pub struct Rule {
 }
 
 impl Rule {
-    fn body(namespace: String, rule: &Rule) -> Result<Value, serde_json::Error> {
+    fn body(namespace: String, rule: &Rule) -> Result<Value, OpenWhiskError> {
         let trigger = format!("/{}/{}/", namespace, rule.trigger);
 
         let action = format!("/{}/{}/", namespace, rule.action);
 
-        serde_json::to_value(Rule {
+        serialize_body(&Rule {
             name: rule.name.clone(),
             trigger,
             action,
@@ -79,6 +83,13 @@ pub struct RuleListOptions {
     pub docs: bool,
 }
 
+impl RuleListOptions {
+    /// Returns the list options as query parameters
+    pub fn query(&self) -> String {
+        list_query(self.limit, self.skip, self.docs)
+    }
+}
+
 impl RuleResponse {
     fn set_status(state: String) -> Self {
         Self {
@@ -88,41 +99,93 @@ impl RuleResponse {
     }
 }
 
+impl<T> RuleService<T> {
+    /// Returns the url of the rules, or of a rule for a non empty name
+    fn rules_url(&self, rule_name: &str) -> String {
+        let url = collection_url(&self.context, self.context.namespace(), RULES_ENDPOINT);
+
+        if rule_name.is_empty() {
+            url
+        } else {
+            format!("{}/{}", url, rule_name)
+        }
+    }
+
+    fn list_request(&self, options: Option<&RuleListOptions>) -> WhiskRequest {
+        let url = match options {
+            Some(options) => format!("{}?{}", self.rules_url(""), options.query()),
+            None => self.rules_url(""),
+        };
+
+        WhiskRequest::new(HttpMethods::GET, url)
+    }
+
+    fn insert_request(&self, rule: &Rule, overwrite: bool) -> Result<WhiskRequest, OpenWhiskError> {
+        let url = format!("{}?overwrite={}", self.rules_url(&rule.name), overwrite);
+        let body = Rule::body(self.context.namespace().to_string(), rule)?;
+
+        Ok(WhiskRequest::new(HttpMethods::PUT, url).set_body(body))
+    }
+
+    fn set_state_request(
+        &self,
+        rule_name: &str,
+        state: &str,
+    ) -> Result<WhiskRequest, OpenWhiskError> {
+        let state = state.to_lowercase();
+
+        if state != "active" && state != "inactive" {
+            return Err(OpenWhiskError::InvalidInput(
+                "Invalid setstate options".to_string(),
+            ));
+        }
+
+        let body = serialize_body(&RuleResponse::set_status(state))?;
+
+        Ok(WhiskRequest::new(HttpMethods::POST, self.rules_url(rule_name)).set_body(body))
+    }
+}
+
 impl<T> RuleService<T>
 where
     T: Service,
 {
     /// Returns a list of Rules
-    pub fn list(&self) -> Result<Vec<RuleResponse>, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            RULES_ENDPOINT,
-        );
-
-        let auth = self.context.auth();
-        let user = auth.0;
-        let pass = auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::GET),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
+    pub fn list(&self) -> Result<Vec<RuleResponse>, OpenWhiskError> {
+        let response = self.list_request(None).send(&self.client, &self.context)?;
 
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(rules) => Ok(rules),
-                Err(err) => Err(format!("Failed to deserailize Rules {}", err)),
-            },
-            Err(x) => Err(format!("Failed to fetch the list of Rules {}", x)),
-        }
+        deserialize_response(response, "Rules")
+    }
+
+    /// Returns a page of Rules selected by the list options
+    ///
+    /// # Arguments
+    /// * `options` - Limit, skip count and toggles for the list request
+    ///
+    pub fn list_with_options(
+        &self,
+        options: &RuleListOptions,
+    ) -> Result<Vec<RuleResponse>, OpenWhiskError> {
+        let response = self
+            .list_request(Some(options))
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "Rules")
+    }
+
+    /// Returns an iterator which lazily walks every page of Rules
+    ///
+    /// # Arguments
+    /// * `options` - Page size (limit), skip count of the first page and toggles for the list request
+    ///
+    pub fn iter(&self, options: RuleListOptions) -> ListIterator<'_, RuleResponse> {
+        ListIterator::new(options.limit, options.skip, move |skip, limit| {
+            self.list_with_options(&RuleListOptions {
+                limit,
+                skip,
+                ..options.clone()
+            })
+        })
     }
 
     /// Inserts a rule
@@ -131,43 +194,12 @@ where
     /// * `rule` - The rule ro be inserted
     /// * `overwrite`  - Toggle to get overwrtite an existing rule
     ///  
-    pub fn insert(&self, rule: &Rule, overwrite: bool) -> Result<RuleResponse, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}?overwrite={}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            RULES_ENDPOINT,
-            rule.name,
-            overwrite
-        );
-
-        let auth = self.context.auth();
-        let user = auth.0;
-        let pass = auth.1;
-
-        let body = match Rule::body(self.context.namespace().to_string(), rule) {
-            Ok(body) => body,
-            Err(error) => return Err(format!("Failed deserailize body {}", error)),
-        };
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::PUT),
-            url.as_str(),
-            Some((user, pass)),
-            Some(body),
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
+    pub fn insert(&self, rule: &Rule, overwrite: bool) -> Result<RuleResponse, OpenWhiskError> {
+        let response = self
+            .insert_request(rule, overwrite)?
+            .send(&self.client, &self.context)?;
 
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(actions) => Ok(actions),
-                Err(err) => Err(format!("Failed to deserailize rule {}", err)),
-            },
-            Err(x) => Err(format!("Failed to create rule {}", x)),
-        }
+        deserialize_response(response, "rule")
     }
 
     /// To get the properties of the rule
@@ -175,37 +207,11 @@ where
     /// # Arguments
     /// * `rule_name` - String slice that holds rule name
     ///
-    pub fn get(&self, rule_name: &str) -> Result<RuleResponse, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            RULES_ENDPOINT,
-            rule_name
-        );
-
-        let auth = self.context.auth();
-        let user = auth.0;
-        let pass = auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::GET),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
+    pub fn get(&self, rule_name: &str) -> Result<RuleResponse, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::GET, self.rules_url(rule_name))
+            .send(&self.client, &self.context)?;
 
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(actions) => Ok(actions),
-                Err(err) => Err(format!("Failed to deserailize rule {}", err)),
-            },
-            Err(x) => Err(format!("Failed to get rule properties{}", x)),
-        }
+        deserialize_response(response, "rule")
     }
 
     /// Deletes an already existing rule
@@ -213,37 +219,11 @@ where
     /// # Arguments
     /// * `rule_name` - String slice that holds rule name
     ///
-    pub fn delete(&self, rule_name: &str) -> Result<RuleResponse, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            RULES_ENDPOINT,
-            rule_name
-        );
-
-        let auth = self.context.auth();
-        let user = auth.0;
-        let pass = auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::DELETE),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
+    pub fn delete(&self, rule_name: &str) -> Result<RuleResponse, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::DELETE, self.rules_url(rule_name))
+            .send(&self.client, &self.context)?;
 
-        match self.client.invoke_request(request) {
-            Ok(x) => match serde_json::from_value(x) {
-                Ok(actions) => Ok(actions),
-                Err(err) => Err(format!("Failed to deserailize rule {}", err)),
-            },
-            Err(x) => Err(format!("Failed to get rule properties{}", x)),
-        }
+        deserialize_response(response, "rule")
     }
 
     /// Sets the state of the rule
@@ -252,46 +232,127 @@ where
     /// * `rule_name` - String slice that holds rule name
     /// * 'state' - Execution state of the rule    
     ///
-    pub fn set_state(&self, rule_name: &str, state: &str) -> Result<String, String> {
-        let state = state.to_lowercase();
+    pub fn set_state(&self, rule_name: &str, state: &str) -> Result<String, OpenWhiskError> {
+        self.set_state_request(rule_name, state)?
+            .send(&self.client, &self.context)?;
 
-        if state != "active" && state != "inactive" {
-            Err("Invalid setstate options".to_string())
-        } else {
-            let url = format!(
-                "{}/api/v1/{}/{}/{}/{}",
-                self.context.host(),
-                NAMESPACE_ENDPOINT,
-                self.context.namespace(),
-                RULES_ENDPOINT,
-                rule_name
-            );
-
-            let auth = self.context.auth();
-            let user = auth.0;
-            let pass = auth.1;
-
-            let setstate = RuleResponse::set_status(state);
-
-            let body = match serde_json::to_value(setstate) {
-                Ok(body) => body,
-                Err(error) => return Err(format!("Failed deserailize body {}", error)),
-            };
+        Ok("The rule is updated".to_string())
+    }
+}
 
-            let request = match self.client.new_request(
-                Some(HttpMethods::POST),
-                url.as_str(),
-                Some((user, pass)),
-                Some(body),
-            ) {
-                Ok(request) => request,
-                Err(error) => return Err(error),
+impl<T> RuleService<T>
+where
+    T: AsyncService + Sync,
+{
+    /// Returns an async iterator which lazily walks every page of Rules
+    ///
+    /// # Arguments
+    /// * `options` - Page size (limit), skip count of the first page and toggles for the list request
+    ///
+    pub fn iter_async(&self, options: RuleListOptions) -> AsyncListIterator<'_, RuleResponse> {
+        AsyncListIterator::new(options.limit, options.skip, move |skip, limit| {
+            let options = RuleListOptions {
+                limit,
+                skip,
+                ..options.clone()
             };
 
-            match self.client.invoke_request(request) {
-                Ok(_x) => Ok("The rule is updated".to_string()),
-                Err(x) => Err(format!("Failed to SetState for Rule {}", x)),
-            }
-        }
+            async move { self.list_with_options_async(&options).await }
+        })
+    }
+}
+
+impl<T> RuleService<T>
+where
+    T: AsyncService,
+{
+    /// Returns a list of Rules
+    pub async fn list_async(&self) -> Result<Vec<RuleResponse>, OpenWhiskError> {
+        let response = self
+            .list_request(None)
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "Rules")
+    }
+
+    /// Returns a page of Rules selected by the list options
+    ///
+    /// # Arguments
+    /// * `options` - Limit, skip count and toggles for the list request
+    ///
+    pub async fn list_with_options_async(
+        &self,
+        options: &RuleListOptions,
+    ) -> Result<Vec<RuleResponse>, OpenWhiskError> {
+        let response = self
+            .list_request(Some(options))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "Rules")
+    }
+
+    /// Inserts a rule
+    ///
+    /// # Arguments
+    /// * `rule` - The rule ro be inserted
+    /// * `overwrite`  - Toggle to get overwrtite an existing rule
+    ///  
+    pub async fn insert_async(
+        &self,
+        rule: &Rule,
+        overwrite: bool,
+    ) -> Result<RuleResponse, OpenWhiskError> {
+        let response = self
+            .insert_request(rule, overwrite)?
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "rule")
+    }
+
+    /// To get the properties of the rule
+    ///
+    /// # Arguments
+    /// * `rule_name` - String slice that holds rule name
+    ///
+    pub async fn get_async(&self, rule_name: &str) -> Result<RuleResponse, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::GET, self.rules_url(rule_name))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "rule")
+    }
+
+    /// Deletes an already existing rule
+    ///
+    /// # Arguments
+    /// * `rule_name` - String slice that holds rule name
+    ///
+    pub async fn delete_async(&self, rule_name: &str) -> Result<RuleResponse, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::DELETE, self.rules_url(rule_name))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "rule")
+    }
+
+    /// Sets the state of the rule
+    ///
+    /// # Arguments
+    /// * `rule_name` - String slice that holds rule name
+    /// * 'state' - Execution state of the rule    
+    ///
+    pub async fn set_state_async(
+        &self,
+        rule_name: &str,
+        state: &str,
+    ) -> Result<String, OpenWhiskError> {
+        self.set_state_request(rule_name, state)?
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        Ok("The rule is updated".to_string())
     }
 }
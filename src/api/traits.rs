@@ -1,7 +1,10 @@
 use serde_json::Value;
+use std::future::Future;
 
-use super::HttpMethods;
+use super::{HttpMethods, HttpResponse, OpenWhiskError};
 
+/// Blocking http client used by the services.
+/// Implementors provide `invoke_raw_request`, `invoke_request` defaults to its json body
 pub trait Service {
     type Output;
     fn new_request(
@@ -10,6 +13,79 @@ pub trait Service {
         url: &str,
         user_auth: Option<(&str, &str)>,
         body: Option<Value>,
-    ) -> Result<Self::Output, String>;
-    fn invoke_request(&self, request: Self::Output) -> Result<Value, String>;
+    ) -> Result<Self::Output, OpenWhiskError>;
+    /// Invokes a request and returns the json body of a 2xx response,
+    /// other statuses are returned as the matching error
+    fn invoke_request(&self, request: Self::Output) -> Result<Value, OpenWhiskError> {
+        self.invoke_raw_request(request)?.into_value()
+    }
+    /// Creates a request with custom headers and a raw body.
+    /// By default the request is created by `new_request`, without the custom headers,
+    /// and bodies which are not json are rejected
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        _headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        self.new_request(Some(method), url, user_auth, json_body(body)?)
+    }
+    /// Invokes a request and returns the raw response, whatever its status code
+    fn invoke_raw_request(&self, request: Self::Output) -> Result<HttpResponse, OpenWhiskError>;
+}
+
+/// Asynchronous counterpart of the Service trait, used by non blocking http clients.
+/// Implementors provide `invoke_raw_request`, `invoke_request` defaults to its json body
+pub trait AsyncService {
+    type Output;
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError>;
+    /// Invokes a request and returns the json body of a 2xx response,
+    /// other statuses are returned as the matching error
+    fn invoke_request(
+        &self,
+        request: Self::Output,
+    ) -> impl Future<Output = Result<Value, OpenWhiskError>> + Send {
+        let response = self.invoke_raw_request(request);
+
+        async move { response.await?.into_value() }
+    }
+    /// Creates a request with custom headers and a raw body.
+    /// By default the request is created by `new_request`, without the custom headers,
+    /// and bodies which are not json are rejected
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        _headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        self.new_request(Some(method), url, user_auth, json_body(body)?)
+    }
+    /// Invokes a request and returns the raw response, whatever its status code
+    fn invoke_raw_request(
+        &self,
+        request: Self::Output,
+    ) -> impl Future<Output = Result<HttpResponse, OpenWhiskError>> + Send;
+}
+
+/// Returns the json value of a raw body, for clients which only send json requests
+fn json_body(body: Option<Vec<u8>>) -> Result<Option<Value>, OpenWhiskError> {
+    match body {
+        None => Ok(None),
+        Some(body) => match serde_json::from_slice(&body) {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => Err(OpenWhiskError::Request(
+                "The client only supports json request bodies".to_string(),
+            )),
+        },
+    }
 }
@@ -1,9 +1,19 @@
-use super::NAMESPACE_ENDPOINT;
-use super::{HttpMethods, Limits, Service, TRIGGERS_ENDPOINT};
+use super::{
+    collection_url, deserialize_response, encode_entity_name, list_query, qualify_name,
+    response_activation_id, serialize_body, split_qualified_name, ActivationId, AsyncListIterator,
+    AsyncService, HttpMethods, Limits, ListIterator, OpenWhiskError, Service, WhiskRequest,
+    ACTION_ENDPOINT, TRIGGERS_ENDPOINT,
+};
 use crate::client::Context;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
+
+/// Annotation holding the feed action of a trigger
+static FEED_ANNOTATION: &str = "feed";
+
+/// Parameters of the feed protocol, set by the client in every lifecycle event
+static FEED_RESERVED_PARAMETERS: [&str; 3] = ["lifecycleEvent", "triggerName", "authKey"];
 
 /// Representation of Trigger Service
 #[derive(new, Default, Debug, Clone)]
@@ -47,7 +57,8 @@ pub struct KeyValue {
     pub key: String,
     pub value: Value,
 }
-#[derive(Debug, Deserialize, Serialize, Clone)]
+/// Representation of triggers list options
+#[derive(new, Debug, Deserialize, Serialize, Clone, Default)]
 pub struct TriggerListOptions {
     /// The limit for the trigger
     pub limit: i64,
@@ -57,42 +68,84 @@ pub struct TriggerListOptions {
     pub docs: bool,
 }
 
+impl TriggerListOptions {
+    /// Returns the list options as query parameters
+    pub fn query(&self) -> String {
+        list_query(self.limit, self.skip, self.docs)
+    }
+}
+
+/// Lifecycle events of the feed protocol, sent to the feed action of a trigger
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedLifecycleEvent {
+    /// The trigger was created, the feed starts firing it
+    Create,
+    /// Returns the configuration of the trigger in the feed
+    Read,
+    /// Changes the parameters of the trigger in the feed
+    Update,
+    /// The trigger is deleted, the feed stops firing it
+    Delete,
+    /// The feed stops firing the trigger until it is unpaused
+    Pause,
+    /// The feed fires the trigger again
+    Unpause,
+}
+
+impl FeedLifecycleEvent {
+    /// Returns the name of the event as sent in `lifecycleEvent`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeedLifecycleEvent::Create => "CREATE",
+            FeedLifecycleEvent::Read => "READ",
+            FeedLifecycleEvent::Update => "UPDATE",
+            FeedLifecycleEvent::Delete => "DELETE",
+            FeedLifecycleEvent::Pause => "PAUSE",
+            FeedLifecycleEvent::Unpause => "UNPAUSE",
+        }
+    }
+}
+
 impl<T> TriggerService<T>
 where
     T: Service,
 {
     /// Returns a list of Triggers
-    pub fn list(&self) -> Result<Vec<Trigger>, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            TRIGGERS_ENDPOINT
-        );
+    pub fn list(&self) -> Result<Vec<Trigger>, OpenWhiskError> {
+        let response = self.list_request(None).send(&self.client, &self.context)?;
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::GET),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(error) => return Err(error),
-        };
+        deserialize_response(response, "triggers")
+    }
 
-        match self.client.invoke_request(request) {
-            Ok(value) => match serde_json::from_value(value) {
-                Ok(result) => Ok(result),
-                Err(error) => Err(format!("Failed deserailize triggers {}", error)),
-            },
+    /// Returns a page of Triggers selected by the list options
+    ///
+    /// # Arguments
+    /// * `options` - Limit, skip count and toggles for the list request
+    ///
+    pub fn list_with_options(
+        &self,
+        options: &TriggerListOptions,
+    ) -> Result<Vec<Trigger>, OpenWhiskError> {
+        let response = self
+            .list_request(Some(options))
+            .send(&self.client, &self.context)?;
 
-            Err(error) => Err(format!("Failed to fetch the list of triggers {}", error)),
-        }
+        deserialize_response(response, "triggers")
+    }
+
+    /// Returns an iterator which lazily walks every page of Triggers
+    ///
+    /// # Arguments
+    /// * `options` - Page size (limit), skip count of the first page and toggles for the list request
+    ///
+    pub fn iter(&self, options: TriggerListOptions) -> ListIterator<'_, Trigger> {
+        ListIterator::new(options.limit, options.skip, move |skip, limit| {
+            self.list_with_options(&TriggerListOptions {
+                limit,
+                skip,
+                ..options.clone()
+            })
+        })
     }
 
     /// Inserts a trigger
@@ -101,163 +154,622 @@ where
     /// * `trigger` - The trigger ro be inserted
     /// * `overwrite`  - Toggle to get overwrtite an existing trigger
     ///
-    pub fn insert(&self, trigger: &Trigger, overwrite: bool) -> Result<Trigger, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}?overwrite={}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            TRIGGERS_ENDPOINT,
-            trigger.name,
-            overwrite,
-        );
+    pub fn insert(&self, trigger: &Trigger, overwrite: bool) -> Result<Trigger, OpenWhiskError> {
+        let response = self
+            .insert_request(trigger, overwrite)?
+            .send(&self.client, &self.context)?;
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
+        deserialize_response(response, "trigger")
+    }
 
-        let body = match serde_json::to_value(trigger) {
-            Ok(value) => value,
-            Err(err) => return Err(format!("failed to serialize body {}", err)),
-        };
+    /// To get the properties of the trigger
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub fn get(&self, trigger_name: &str) -> Result<Trigger, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::GET, self.triggers_url(trigger_name))
+            .send(&self.client, &self.context)?;
 
-        let request = match self.client.new_request(
-            Some(HttpMethods::PUT),
-            url.as_str(),
-            Some((user, pass)),
-            Some(body),
-        ) {
-            Ok(request) => request,
-            Err(err) => return Err(format!("failed to create request {}", err)),
-        };
+        deserialize_response(response, "trigger")
+    }
 
-        let trigger: Trigger = match self.client.invoke_request(request) {
-            Ok(response) => match serde_json::from_value(response) {
-                Ok(trigger) => trigger,
-                Err(err) => return Err(format!("falied to deserilaize {}", err)),
-            },
-            Err(err) => return Err(format!("falied to insert trigger {}", err)),
-        };
+    /// Deletes an already existing trigger
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub fn delete(&self, trigger_name: &str) -> Result<Trigger, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::DELETE, self.triggers_url(trigger_name))
+            .send(&self.client, &self.context)?;
+
+        deserialize_response(response, "trigger")
+    }
+
+    /// Fires a trigger to an action and returns the id of the trigger activation,
+    /// None when the trigger has no active rule and no activation was created
+    ///
+    ///  # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    /// * `payload` - payload is the result of the action
+    ///
+    pub fn fire(
+        &self,
+        trigger_name: &str,
+        payload: Value,
+    ) -> Result<Option<ActivationId>, OpenWhiskError> {
+        let response = self
+            .fire_request(trigger_name, payload)
+            .send(&self.client, &self.context)?;
+
+        response_activation_id(response)
+    }
+
+    /// Creates a trigger fired by a feed, like `/whisk.system/alarms/alarm`.
+    /// The trigger is inserted with a `feed` annotation, then the feed action is invoked with
+    /// the `CREATE` lifecycle event. A trigger created by this call is deleted again when the
+    /// feed fails. A trigger which is already registered with the feed is overwritten and
+    /// its feed receives the `UPDATE` event instead. When the feed fails, an overwritten
+    /// trigger is restored to its previous body. A trigger registered with another feed is
+    /// unregistered from that feed with the `DELETE` event once the new feed succeeded, so
+    /// the previous feed keeps firing it when the new feed fails
+    ///
+    /// # Arguments
+    /// * `trigger`    - The trigger to be inserted
+    /// * `feed_name`  - Name of the feed action (optionally qualified as `/namespace/package/action`)
+    /// * `parameters` - Parameters handed to the feed, like the `cron` of an alarm
+    /// * `overwrite`  - Toggle to get overwrtite an existing trigger
+    ///
+    pub fn create_with_feed(
+        &self,
+        trigger: &Trigger,
+        feed_name: &str,
+        parameters: &[KeyValue],
+        overwrite: bool,
+    ) -> Result<Trigger, OpenWhiskError> {
+        let existing = existing_trigger(self.get(&trigger.name))?;
+        let (event, previous_feed) = self.feed_registration(existing.as_ref(), feed_name);
+        let payload = self.feed_payload(&trigger.name, event, parameters)?;
+
+        let trigger = self.insert(&self.feed_trigger(trigger, feed_name), overwrite)?;
+
+        if let Err(error) = self
+            .feed_request(feed_name, payload)
+            .send(&self.client, &self.context)
+        {
+            // The feed error is more useful than a failure of the rollback
+            let _ = match &existing {
+                Some(existing) => self.insert(existing, true),
+                None => self.delete(&trigger.name),
+            };
+
+            return Err(error);
+        }
+
+        if let Some(previous_feed) = previous_feed {
+            self.feed_lifecycle(
+                &previous_feed,
+                &trigger.name,
+                FeedLifecycleEvent::Delete,
+                &[],
+            )?;
+        }
 
         Ok(trigger)
     }
 
-    /// To get the properties of the trigger
+    /// Unregisters a trigger from the feed of its `feed` annotation, and deletes it
     ///
     /// # Arguments
     /// * `trigger_name` - String slice that holds trigger name
     ///
-    pub fn get(&self, trigger_name: &str) -> Result<Trigger, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            TRIGGERS_ENDPOINT,
-            trigger_name
-        );
+    pub fn delete_with_feed(&self, trigger_name: &str) -> Result<Trigger, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get(trigger_name)?)?;
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::GET),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(err) => return Err(err),
-        };
+        self.feed_lifecycle(&feed_name, trigger_name, FeedLifecycleEvent::Delete, &[])?;
 
-        let trigger: Trigger = match self.client.invoke_request(request) {
-            Ok(response) => match serde_json::from_value(response) {
-                Ok(trigger) => trigger,
-                Err(err) => return Err(format!("falied to deserilaize {}", err)),
-            },
-            Err(err) => return Err(format!("falied to insert trigger {}", err)),
-        };
+        self.delete(trigger_name)
+    }
 
-        Ok(trigger)
+    /// Returns the configuration of a trigger in the feed of its `feed` annotation
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub fn read_feed(&self, trigger_name: &str) -> Result<Value, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get(trigger_name)?)?;
+
+        self.feed_lifecycle(&feed_name, trigger_name, FeedLifecycleEvent::Read, &[])
     }
 
-    /// Deletes an already existing trigger
+    /// Changes the parameters of a trigger in the feed of its `feed` annotation
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    /// * `parameters`   - Parameters to be changed, like the `cron` of an alarm
+    ///
+    pub fn update_feed(
+        &self,
+        trigger_name: &str,
+        parameters: &[KeyValue],
+    ) -> Result<Value, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get(trigger_name)?)?;
+
+        self.feed_lifecycle(
+            &feed_name,
+            trigger_name,
+            FeedLifecycleEvent::Update,
+            parameters,
+        )
+    }
+
+    /// Stops the feed of a trigger from firing it, until it is unpaused
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub fn pause_feed(&self, trigger_name: &str) -> Result<Value, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get(trigger_name)?)?;
+
+        self.feed_lifecycle(&feed_name, trigger_name, FeedLifecycleEvent::Pause, &[])
+    }
+
+    /// Lets the feed of a paused trigger fire it again
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub fn unpause_feed(&self, trigger_name: &str) -> Result<Value, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get(trigger_name)?)?;
+
+        self.feed_lifecycle(&feed_name, trigger_name, FeedLifecycleEvent::Unpause, &[])
+    }
+
+    /// Sends a lifecycle event of a trigger to a feed action and returns the result of the feed
+    ///
     /// # Arguments
+    /// * `feed_name`    - Name of the feed action (optionally qualified as `/namespace/package/action`)
     /// * `trigger_name` - String slice that holds trigger name
+    /// * `event`        - Lifecycle event sent to the feed
+    /// * `parameters`   - Parameters handed to the feed along with the event
     ///
-    pub fn delete(&self, trigger_name: &str) -> Result<Trigger, String> {
+    pub fn feed_lifecycle(
+        &self,
+        feed_name: &str,
+        trigger_name: &str,
+        event: FeedLifecycleEvent,
+        parameters: &[KeyValue],
+    ) -> Result<Value, OpenWhiskError> {
+        let payload = self.feed_payload(trigger_name, event, parameters)?;
+
+        self.feed_request(feed_name, payload)
+            .send(&self.client, &self.context)
+    }
+}
+
+impl<T> TriggerService<T> {
+    /// Returns the url of the triggers, or of a trigger for a non empty name
+    fn triggers_url(&self, trigger_name: &str) -> String {
+        let url = collection_url(&self.context, self.context.namespace(), TRIGGERS_ENDPOINT);
+
+        if trigger_name.is_empty() {
+            url
+        } else {
+            format!("{}/{}", url, trigger_name)
+        }
+    }
+
+    fn list_request(&self, options: Option<&TriggerListOptions>) -> WhiskRequest {
+        let url = match options {
+            Some(options) => format!("{}?{}", self.triggers_url(""), options.query()),
+            None => self.triggers_url(""),
+        };
+
+        WhiskRequest::new(HttpMethods::GET, url)
+    }
+
+    fn insert_request(
+        &self,
+        trigger: &Trigger,
+        overwrite: bool,
+    ) -> Result<WhiskRequest, OpenWhiskError> {
         let url = format!(
-            "{}/api/v1/{}/{}/{}/{}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            TRIGGERS_ENDPOINT,
-            trigger_name
+            "{}?overwrite={}",
+            self.triggers_url(&trigger.name),
+            overwrite
         );
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::DELETE),
-            url.as_str(),
-            Some((user, pass)),
-            None,
-        ) {
-            Ok(request) => request,
-            Err(err) => return Err(err),
-        };
 
-        let trigger: Trigger = match self.client.invoke_request(request) {
-            Ok(response) => match serde_json::from_value(response) {
-                Ok(trigger) => trigger,
-                Err(err) => return Err(format!("falied to deserilaize {}", err)),
-            },
-            Err(err) => return Err(format!("falied to insert trigger {}", err)),
-        };
+        Ok(WhiskRequest::new(HttpMethods::PUT, url).set_body(serialize_body(trigger)?))
+    }
 
-        Ok(trigger)
+    fn fire_request(&self, trigger_name: &str, payload: Value) -> WhiskRequest {
+        WhiskRequest::new(HttpMethods::POST, self.triggers_url(trigger_name)).set_body(payload)
     }
 
-    /// Fires a trigger to an action
+    /// Returns the request invoking a feed action, blocking until it returns its result
+    fn feed_request(&self, feed_name: &str, payload: Value) -> WhiskRequest {
+        let (namespace, feed_name) = split_qualified_name(self.context.namespace(), feed_name);
+
+        let url = format!(
+            "{}/{}?blocking=true&result=true",
+            collection_url(&self.context, namespace, ACTION_ENDPOINT),
+            encode_entity_name(feed_name),
+        );
+
+        // A feed which is still running has not registered the trigger yet
+        WhiskRequest::new(HttpMethods::POST, url)
+            .set_body(payload)
+            .set_blocking(true)
+    }
+
+    /// Returns the parameters of a lifecycle event: the feed parameters, `lifecycleEvent`,
+    /// the fully qualified `triggerName` and the `authKey` the feed fires the trigger with.
+    /// Feeds only accept the username and password of the auth token as `authKey`, so
+    /// clients authenticated by an auth provider can not register triggers with feeds
+    fn feed_payload(
+        &self,
+        trigger_name: &str,
+        event: FeedLifecycleEvent,
+        parameters: &[KeyValue],
+    ) -> Result<Value, OpenWhiskError> {
+        if self.context.has_auth_provider() {
+            return Err(OpenWhiskError::InvalidInput(format!(
+                "Feed of trigger {} requires the auth key of the auth token, \
+                 which is not used by clients authenticated by an auth provider",
+                trigger_name
+            )));
+        }
+
+        let mut payload = Map::new();
+        for parameter in parameters {
+            if FEED_RESERVED_PARAMETERS.contains(&parameter.key.as_str()) {
+                return Err(OpenWhiskError::InvalidInput(format!(
+                    "Feed parameter {} is reserved for the feed protocol",
+                    parameter.key
+                )));
+            }
+
+            payload.insert(parameter.key.clone(), parameter.value.clone());
+        }
+
+        let (user, pass) = self.context.auth();
+        payload.insert("lifecycleEvent".to_string(), json!(event.as_str()));
+        payload.insert(
+            "triggerName".to_string(),
+            json!(qualify_name(self.context.namespace(), trigger_name)),
+        );
+        payload.insert("authKey".to_string(), json!(format!("{}:{}", user, pass)));
+
+        Ok(Value::Object(payload))
+    }
+
+    /// Returns the lifecycle event registering a trigger with a feed, `UPDATE` when the
+    /// existing trigger is already registered with the same feed and `CREATE` otherwise,
+    /// along with the other feed the existing trigger is registered with
+    fn feed_registration(
+        &self,
+        existing: Option<&Trigger>,
+        feed_name: &str,
+    ) -> (FeedLifecycleEvent, Option<String>) {
+        let feed_name = qualify_name(self.context.namespace(), feed_name);
+
+        match existing.map(trigger_feed) {
+            Some(Ok(feed)) if feed == feed_name => (FeedLifecycleEvent::Update, None),
+            Some(Ok(feed)) => (FeedLifecycleEvent::Create, Some(feed)),
+            _ => (FeedLifecycleEvent::Create, None),
+        }
+    }
+
+    /// Returns the trigger with its `feed` annotation set to the feed action
+    fn feed_trigger(&self, trigger: &Trigger, feed_name: &str) -> Trigger {
+        let mut trigger = trigger.clone();
+        trigger
+            .annotations
+            .retain(|annotation| annotation.key != FEED_ANNOTATION);
+        trigger.annotations.push(KeyValue {
+            key: FEED_ANNOTATION.to_string(),
+            value: json!(qualify_name(self.context.namespace(), feed_name)),
+        });
+
+        trigger
+    }
+}
+
+/// Returns the trigger of a get request, None when it does not exist
+fn existing_trigger(
+    result: Result<Trigger, OpenWhiskError>,
+) -> Result<Option<Trigger>, OpenWhiskError> {
+    match result {
+        Ok(trigger) => Ok(Some(trigger)),
+        Err(OpenWhiskError::NotFound(_)) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the feed action of a trigger, from its `feed` annotation
+fn trigger_feed(trigger: &Trigger) -> Result<String, OpenWhiskError> {
+    let feed = trigger
+        .annotations
+        .iter()
+        .find(|annotation| annotation.key == FEED_ANNOTATION)
+        .and_then(|annotation| annotation.value.as_str());
+
+    match feed {
+        Some(feed) => Ok(feed.to_string()),
+        None => Err(OpenWhiskError::InvalidInput(format!(
+            "Trigger {} has no feed",
+            trigger.name
+        ))),
+    }
+}
+
+impl<T> TriggerService<T>
+where
+    T: AsyncService + Sync,
+{
+    /// Returns an async iterator which lazily walks every page of Triggers
+    ///
+    /// # Arguments
+    /// * `options` - Page size (limit), skip count of the first page and toggles for the list request
+    ///
+    pub fn iter_async(&self, options: TriggerListOptions) -> AsyncListIterator<'_, Trigger> {
+        AsyncListIterator::new(options.limit, options.skip, move |skip, limit| {
+            let options = TriggerListOptions {
+                limit,
+                skip,
+                ..options.clone()
+            };
+
+            async move { self.list_with_options_async(&options).await }
+        })
+    }
+}
+
+impl<T> TriggerService<T>
+where
+    T: AsyncService,
+{
+    /// Returns a list of Triggers
+    pub async fn list_async(&self) -> Result<Vec<Trigger>, OpenWhiskError> {
+        let response = self
+            .list_request(None)
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "triggers")
+    }
+
+    /// Returns a page of Triggers selected by the list options
+    ///
+    /// # Arguments
+    /// * `options` - Limit, skip count and toggles for the list request
+    ///
+    pub async fn list_with_options_async(
+        &self,
+        options: &TriggerListOptions,
+    ) -> Result<Vec<Trigger>, OpenWhiskError> {
+        let response = self
+            .list_request(Some(options))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "triggers")
+    }
+
+    /// Inserts a trigger
+    ///
+    /// # Arguments
+    /// * `trigger` - The trigger ro be inserted
+    /// * `overwrite`  - Toggle to get overwrtite an existing trigger
+    ///
+    pub async fn insert_async(
+        &self,
+        trigger: &Trigger,
+        overwrite: bool,
+    ) -> Result<Trigger, OpenWhiskError> {
+        let response = self
+            .insert_request(trigger, overwrite)?
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "trigger")
+    }
+
+    /// To get the properties of the trigger
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub async fn get_async(&self, trigger_name: &str) -> Result<Trigger, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::GET, self.triggers_url(trigger_name))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "trigger")
+    }
+
+    /// Deletes an already existing trigger
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub async fn delete_async(&self, trigger_name: &str) -> Result<Trigger, OpenWhiskError> {
+        let response = WhiskRequest::new(HttpMethods::DELETE, self.triggers_url(trigger_name))
+            .send_async(&self.client, &self.context)
+            .await?;
+
+        deserialize_response(response, "trigger")
+    }
+
+    /// Fires a trigger to an action and returns the id of the trigger activation,
+    /// None when the trigger has no active rule and no activation was created
     ///
     ///  # Arguments
     /// * `trigger_name` - String slice that holds trigger name
     /// * `payload` - payload is the result of the action
     ///
-    pub fn fire(&self, trigger_name: &str, payload: Value) -> Result<Trigger, String> {
-        let url = format!(
-            "{}/api/v1/{}/{}/{}/{}",
-            self.context.host(),
-            NAMESPACE_ENDPOINT,
-            self.context.namespace(),
-            TRIGGERS_ENDPOINT,
-            trigger_name
-        );
+    pub async fn fire_async(
+        &self,
+        trigger_name: &str,
+        payload: Value,
+    ) -> Result<Option<ActivationId>, OpenWhiskError> {
+        let response = self
+            .fire_request(trigger_name, payload)
+            .send_async(&self.client, &self.context)
+            .await?;
 
-        let user_auth = self.context.auth();
-        let user = user_auth.0;
-        let pass = user_auth.1;
-
-        let request = match self.client.new_request(
-            Some(HttpMethods::POST),
-            url.as_str(),
-            Some((user, pass)),
-            Some(payload),
-        ) {
-            Ok(request) => request,
-            Err(err) => return Err(err),
-        };
+        response_activation_id(response)
+    }
 
-        let trigger: Trigger = match self.client.invoke_request(request) {
-            Ok(response) => match serde_json::from_value(response) {
-                Ok(trigger) => trigger,
-                Err(err) => return Err(format!("falied to deserilaize {}", err)),
-            },
-            Err(err) => return Err(format!("falied to insert trigger {}", err)),
-        };
+    /// Creates a trigger fired by a feed, like `/whisk.system/alarms/alarm`.
+    /// The trigger is inserted with a `feed` annotation, then the feed action is invoked with
+    /// the `CREATE` lifecycle event. A trigger created by this call is deleted again when the
+    /// feed fails. A trigger which is already registered with the feed is overwritten and
+    /// its feed receives the `UPDATE` event instead. When the feed fails, an overwritten
+    /// trigger is restored to its previous body. A trigger registered with another feed is
+    /// unregistered from that feed with the `DELETE` event once the new feed succeeded, so
+    /// the previous feed keeps firing it when the new feed fails
+    ///
+    /// # Arguments
+    /// * `trigger`    - The trigger to be inserted
+    /// * `feed_name`  - Name of the feed action (optionally qualified as `/namespace/package/action`)
+    /// * `parameters` - Parameters handed to the feed, like the `cron` of an alarm
+    /// * `overwrite`  - Toggle to get overwrtite an existing trigger
+    ///
+    pub async fn create_with_feed_async(
+        &self,
+        trigger: &Trigger,
+        feed_name: &str,
+        parameters: &[KeyValue],
+        overwrite: bool,
+    ) -> Result<Trigger, OpenWhiskError> {
+        let existing = existing_trigger(self.get_async(&trigger.name).await)?;
+        let (event, previous_feed) = self.feed_registration(existing.as_ref(), feed_name);
+        let payload = self.feed_payload(&trigger.name, event, parameters)?;
+
+        let trigger = self
+            .insert_async(&self.feed_trigger(trigger, feed_name), overwrite)
+            .await?;
+
+        if let Err(error) = self
+            .feed_request(feed_name, payload)
+            .send_async(&self.client, &self.context)
+            .await
+        {
+            // The feed error is more useful than a failure of the rollback
+            let _ = match &existing {
+                Some(existing) => self.insert_async(existing, true).await,
+                None => self.delete_async(&trigger.name).await,
+            };
+
+            return Err(error);
+        }
+
+        if let Some(previous_feed) = previous_feed {
+            self.feed_lifecycle_async(
+                &previous_feed,
+                &trigger.name,
+                FeedLifecycleEvent::Delete,
+                &[],
+            )
+            .await?;
+        }
 
         Ok(trigger)
     }
+
+    /// Unregisters a trigger from the feed of its `feed` annotation, and deletes it
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub async fn delete_with_feed_async(
+        &self,
+        trigger_name: &str,
+    ) -> Result<Trigger, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get_async(trigger_name).await?)?;
+
+        self.feed_lifecycle_async(&feed_name, trigger_name, FeedLifecycleEvent::Delete, &[])
+            .await?;
+
+        self.delete_async(trigger_name).await
+    }
+
+    /// Returns the configuration of a trigger in the feed of its `feed` annotation
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub async fn read_feed_async(&self, trigger_name: &str) -> Result<Value, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get_async(trigger_name).await?)?;
+
+        self.feed_lifecycle_async(&feed_name, trigger_name, FeedLifecycleEvent::Read, &[])
+            .await
+    }
+
+    /// Changes the parameters of a trigger in the feed of its `feed` annotation
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    /// * `parameters`   - Parameters to be changed, like the `cron` of an alarm
+    ///
+    pub async fn update_feed_async(
+        &self,
+        trigger_name: &str,
+        parameters: &[KeyValue],
+    ) -> Result<Value, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get_async(trigger_name).await?)?;
+
+        self.feed_lifecycle_async(
+            &feed_name,
+            trigger_name,
+            FeedLifecycleEvent::Update,
+            parameters,
+        )
+        .await
+    }
+
+    /// Stops the feed of a trigger from firing it, until it is unpaused
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub async fn pause_feed_async(&self, trigger_name: &str) -> Result<Value, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get_async(trigger_name).await?)?;
+
+        self.feed_lifecycle_async(&feed_name, trigger_name, FeedLifecycleEvent::Pause, &[])
+            .await
+    }
+
+    /// Lets the feed of a paused trigger fire it again
+    ///
+    /// # Arguments
+    /// * `trigger_name` - String slice that holds trigger name
+    ///
+    pub async fn unpause_feed_async(&self, trigger_name: &str) -> Result<Value, OpenWhiskError> {
+        let feed_name = trigger_feed(&self.get_async(trigger_name).await?)?;
+
+        self.feed_lifecycle_async(&feed_name, trigger_name, FeedLifecycleEvent::Unpause, &[])
+            .await
+    }
+
+    /// Sends a lifecycle event of a trigger to a feed action and returns the result of the feed
+    ///
+    /// # Arguments
+    /// * `feed_name`    - Name of the feed action (optionally qualified as `/namespace/package/action`)
+    /// * `trigger_name` - String slice that holds trigger name
+    /// * `event`        - Lifecycle event sent to the feed
+    /// * `parameters`   - Parameters handed to the feed along with the event
+    ///
+    pub async fn feed_lifecycle_async(
+        &self,
+        feed_name: &str,
+        trigger_name: &str,
+        event: FeedLifecycleEvent,
+        parameters: &[KeyValue],
+    ) -> Result<Value, OpenWhiskError> {
+        let payload = self.feed_payload(trigger_name, event, parameters)?;
+
+        self.feed_request(feed_name, payload)
+            .send_async(&self.client, &self.context)
+            .await
+    }
 }
@@ -0,0 +1,226 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    encode_entity_name, encode_query_value, split_qualified_name, HttpMethods, WEB_ENDPOINT,
+};
+use crate::client::Context;
+
+/// Header which carries the secret of web actions annotated with `require-whisk-auth`
+pub static REQUIRE_WHISK_AUTH_HEADER: &str = "X-Require-Whisk-Auth";
+
+/// Content extension of a web action url, which decides how the action result is served
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WebExtension {
+    /// Serves the result as a json object
+    #[default]
+    Json,
+    /// Serves the result as a http response described by `statusCode`, `headers` and `body`
+    Http,
+    /// Serves the `text` property of the result
+    Text,
+    /// Serves the `body` property of the result as an svg image
+    Svg,
+}
+
+impl WebExtension {
+    /// Returns the extension as used in the web action url
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebExtension::Json => "json",
+            WebExtension::Http => "http",
+            WebExtension::Text => "text",
+            WebExtension::Svg => "svg",
+        }
+    }
+}
+
+/// Representation of a request to a web action
+#[derive(Debug, Clone)]
+pub struct WebRequest {
+    /// HTTP method of the request
+    pub method: HttpMethods,
+    /// Content extension of the web action url
+    pub extension: WebExtension,
+    /// Query parameters appended to the url
+    pub query: Vec<(String, String)>,
+    /// Headers sent with the request
+    pub headers: Vec<(String, String)>,
+    /// Raw body of the request
+    pub body: Option<Vec<u8>>,
+    /// Secret of an action annotated with `require-whisk-auth`
+    pub whisk_auth: Option<String>,
+    /// Toggle to send the namespace credentials as basic auth
+    pub basic_auth: bool,
+}
+
+impl Default for WebRequest {
+    fn default() -> Self {
+        WebRequest::new(HttpMethods::GET)
+    }
+}
+
+impl WebRequest {
+    /// Creates a request to a web action
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method of the request
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{HttpMethods, WebExtension, WebRequest};
+    ///
+    /// let request = WebRequest::new(HttpMethods::POST)
+    ///     .set_extension(WebExtension::Http)
+    ///     .add_header("Content-Type", "text/plain")
+    ///     .set_body(b"hello".to_vec());
+    ///
+    /// ```
+    pub fn new(method: HttpMethods) -> Self {
+        WebRequest {
+            method,
+            extension: WebExtension::default(),
+            query: Vec::new(),
+            headers: Vec::new(),
+            body: None,
+            whisk_auth: None,
+            basic_auth: false,
+        }
+    }
+
+    /// To set the content extension
+    ///
+    /// # Arguments
+    /// * `extension` - Content extension of the web action url
+    ///
+    pub fn set_extension(mut self, extension: WebExtension) -> Self {
+        self.extension = extension;
+
+        self
+    }
+
+    /// To add a query parameter
+    ///
+    /// # Arguments
+    /// * `key`   - Name of the query parameter
+    /// * `value` - Value of the query parameter
+    ///
+    pub fn add_query(mut self, key: &str, value: &str) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+
+        self
+    }
+
+    /// To add a header
+    ///
+    /// # Arguments
+    /// * `key`   - Name of the header
+    /// * `value` - Value of the header
+    ///
+    pub fn add_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+
+        self
+    }
+
+    /// To set a raw body
+    ///
+    /// # Arguments
+    /// * `body` - Raw body of the request
+    ///
+    pub fn set_body(mut self, body: Vec<u8>) -> Self {
+        self.body = Some(body);
+
+        self
+    }
+
+    /// To set a json body, along with its `Content-Type` header
+    ///
+    /// # Arguments
+    /// * `body` - Json body of the request
+    ///
+    pub fn set_json_body(self, body: &serde_json::Value) -> Self {
+        self.add_header("Content-Type", "application/json")
+            .set_body(body.to_string().into_bytes())
+    }
+
+    /// To set the secret of an action annotated with `require-whisk-auth`
+    ///
+    /// # Arguments
+    /// * `secret` - Value of the `require-whisk-auth` annotation
+    ///
+    pub fn set_whisk_auth(mut self, secret: &str) -> Self {
+        self.whisk_auth = Some(secret.to_string());
+
+        self
+    }
+
+    /// To send the namespace credentials, required when `require-whisk-auth` is `true`
+    ///
+    /// # Arguments
+    /// * `basic_auth` - Bool to toggle basic auth
+    ///
+    pub fn set_basic_auth(mut self, basic_auth: bool) -> Self {
+        self.basic_auth = basic_auth;
+
+        self
+    }
+
+    /// Returns the headers of the request, including the `require-whisk-auth` secret
+    pub(crate) fn all_headers(&self) -> Vec<(String, String)> {
+        let mut headers = self.headers.clone();
+
+        if let Some(secret) = &self.whisk_auth {
+            headers.push((REQUIRE_WHISK_AUTH_HEADER.to_string(), secret.clone()));
+        }
+
+        headers
+    }
+}
+
+/// Builds the url of a web action.
+/// Actions which are not qualified with a package live in the `default` package,
+/// fully qualified actions are served from their own namespace
+///
+/// # Arguments
+/// * `context`   - Context which holds the host and namespace
+/// * `action`    - Name of the action, optionally qualified as `[/namespace/][package/]action`
+/// * `extension` - Content extension of the url
+pub(crate) fn web_action_url(context: &Context, action: &str, extension: &str) -> String {
+    let (namespace, action) = split_qualified_name(context.namespace(), action);
+
+    let (package, action) = match action.rsplit_once('/') {
+        Some((package, action)) => (package, action),
+        None => ("default", action),
+    };
+
+    format!(
+        "{}/api/v1/{}/{}/{}/{}.{}",
+        context.host(),
+        WEB_ENDPOINT,
+        namespace,
+        encode_entity_name(package),
+        encode_entity_name(action),
+        extension
+    )
+}
+
+/// Appends query parameters to a url
+///
+/// # Arguments
+/// * `url`   - Url without query string
+/// * `query` - Query parameters to be appended
+pub(crate) fn with_query(url: String, query: &[(String, String)]) -> String {
+    if query.is_empty() {
+        return url;
+    }
+
+    let query = query
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode_query_value(key), encode_query_value(value)))
+        .collect::<Vec<String>>()
+        .join("&");
+
+    format!("{}?{}", url, query)
+}
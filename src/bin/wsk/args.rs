@@ -0,0 +1,305 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// OpenWhisk command-line interface, compatible with the subcommands of the wsk CLI
+#[derive(Debug, Parser)]
+#[command(name = "wsk", version)]
+pub struct Cli {
+    #[command(flatten)]
+    pub global: GlobalArgs,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Flags which override the properties read from `.wskprops` and the environment,
+/// given before the subcommand like `wsk -i action list`
+#[derive(Debug, Args)]
+pub struct GlobalArgs {
+    /// Whisk API host
+    #[arg(long)]
+    pub apihost: Option<String>,
+
+    /// Authorization key
+    #[arg(short = 'u', long)]
+    pub auth: Option<String>,
+
+    /// Whisk API version
+    #[arg(long)]
+    pub apiversion: Option<String>,
+
+    /// Client certificate file
+    #[arg(long)]
+    pub cert: Option<String>,
+
+    /// Client key file
+    #[arg(long)]
+    pub key: Option<String>,
+
+    /// Bypass certificate checking
+    #[arg(short = 'i', long)]
+    pub insecure: bool,
+
+    /// Format of the output
+    #[arg(short = 'o', long, value_enum, default_value_t = Output::Table)]
+    pub output: Output,
+}
+
+/// Format of the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Output {
+    /// Tables for lists and messages for other commands
+    Table,
+    /// Raw JSON responses
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Work with actions
+    #[command(subcommand)]
+    Action(ActionCommand),
+    /// Work with triggers
+    #[command(subcommand)]
+    Trigger(TriggerCommand),
+    /// Work with rules
+    #[command(subcommand)]
+    Rule(RuleCommand),
+    /// Work with namespaces
+    #[command(subcommand)]
+    Namespace(NamespaceCommand),
+    /// Work with whisk properties
+    #[command(subcommand)]
+    Property(PropertyCommand),
+}
+
+/// Page of a list command
+#[derive(Debug, Args)]
+pub struct ListArgs {
+    /// Only return LIMIT number of entities from the collection
+    #[arg(short, long, default_value_t = 30)]
+    pub limit: i64,
+
+    /// Exclude the first SKIP number of entities from the result
+    #[arg(short, long, default_value_t = 0)]
+    pub skip: i64,
+}
+
+/// Parameters and annotations of an entity, values are parsed as JSON or kept as strings
+#[derive(Debug, Args)]
+pub struct ParamArgs {
+    /// Parameter values in KEY VALUE format
+    #[arg(short = 'p', long = "param", num_args = 2, value_names = ["KEY", "VALUE"])]
+    pub params: Vec<String>,
+
+    /// FILE containing parameter values in JSON format
+    #[arg(short = 'P', long = "param-file")]
+    pub param_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct AnnotationArgs {
+    /// Annotation values in KEY VALUE format
+    #[arg(short = 'a', long = "annotation", num_args = 2, value_names = ["KEY", "VALUE"])]
+    pub annotations: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ActionArgs {
+    /// Name of the action
+    pub name: String,
+
+    /// Source file, directory or zip archive of the action
+    pub artifact: PathBuf,
+
+    /// The KIND of the action runtime, inferred from the artifact when not set
+    #[arg(long)]
+    pub kind: Option<String>,
+
+    /// The name of the action entry point
+    #[arg(short, long)]
+    pub main: Option<String>,
+
+    #[command(flatten)]
+    pub params: ParamArgs,
+
+    #[command(flatten)]
+    pub annotations: AnnotationArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ActionCommand {
+    /// List all actions
+    List(ListArgs),
+    /// Get action
+    Get {
+        /// Name of the action
+        name: String,
+    },
+    /// Create a new action
+    Create(ActionArgs),
+    /// Update an existing action, or create an action if it does not exist
+    Update(ActionArgs),
+    /// Delete action
+    Delete {
+        /// Name of the action
+        name: String,
+    },
+    /// Invoke action
+    Invoke {
+        /// Name of the action
+        name: String,
+
+        #[command(flatten)]
+        params: ParamArgs,
+
+        /// Blocking invoke
+        #[arg(short, long)]
+        blocking: bool,
+
+        /// Blocking invoke, show only activation result
+        #[arg(short, long)]
+        result: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct TriggerArgs {
+    /// Name of the trigger
+    pub name: String,
+
+    #[command(flatten)]
+    pub params: ParamArgs,
+
+    #[command(flatten)]
+    pub annotations: AnnotationArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TriggerCommand {
+    /// List all triggers
+    List(ListArgs),
+    /// Get trigger
+    Get {
+        /// Name of the trigger
+        name: String,
+    },
+    /// Create new trigger
+    Create(TriggerArgs),
+    /// Update an existing trigger, or create a trigger if it does not exist
+    Update(TriggerArgs),
+    /// Delete trigger
+    Delete {
+        /// Name of the trigger
+        name: String,
+    },
+    /// Fire trigger event
+    Fire {
+        /// Name of the trigger
+        name: String,
+
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct RuleArgs {
+    /// Name of the rule
+    pub name: String,
+    /// Name of the trigger
+    pub trigger: String,
+    /// Name of the action
+    pub action: String,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RuleCommand {
+    /// List all rules
+    List(ListArgs),
+    /// Get rule
+    Get {
+        /// Name of the rule
+        name: String,
+    },
+    /// Create new rule
+    Create(RuleArgs),
+    /// Update an existing rule, or create a rule if it does not exist
+    Update(RuleArgs),
+    /// Delete rule
+    Delete {
+        /// Name of the rule
+        name: String,
+    },
+    /// Enable rule
+    Enable {
+        /// Name of the rule
+        name: String,
+    },
+    /// Disable rule
+    Disable {
+        /// Name of the rule
+        name: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum NamespaceCommand {
+    /// List available namespaces
+    List,
+}
+
+/// Properties read or written by the property subcommands
+#[derive(Debug, Args)]
+pub struct PropertyArgs {
+    /// Whisk API host
+    #[arg(long)]
+    pub apihost: bool,
+    /// Authorization key
+    #[arg(long)]
+    pub auth: bool,
+    /// Whisk namespace
+    #[arg(long)]
+    pub namespace: bool,
+    /// Whisk API version
+    #[arg(long)]
+    pub apiversion: bool,
+    /// Client certificate file
+    #[arg(long)]
+    pub cert: bool,
+    /// Client key file
+    #[arg(long)]
+    pub key: bool,
+    /// All properties
+    #[arg(long)]
+    pub all: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PropertyCommand {
+    /// Get property
+    Get(PropertyArgs),
+    /// Set property
+    Set {
+        /// Whisk API host
+        #[arg(long)]
+        apihost: Option<String>,
+        /// Authorization key
+        #[arg(short = 'u', long)]
+        auth: Option<String>,
+        /// Whisk namespace
+        #[arg(long)]
+        namespace: Option<String>,
+        /// Whisk API version
+        #[arg(long)]
+        apiversion: Option<String>,
+        /// Client certificate file
+        #[arg(long)]
+        cert: Option<String>,
+        /// Client key file
+        #[arg(long)]
+        key: Option<String>,
+    },
+    /// Unset property
+    Unset(PropertyArgs),
+}
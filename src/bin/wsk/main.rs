@@ -0,0 +1,515 @@
+//! wsk compatible command-line interface, built with the `cli` feature
+
+mod args;
+mod output;
+
+use args::{
+    ActionArgs, ActionCommand, Cli, Command, GlobalArgs, NamespaceCommand, ParamArgs, PropertyArgs,
+    PropertyCommand, RuleArgs, RuleCommand, TriggerArgs, TriggerCommand,
+};
+use clap::Parser;
+use openwhisk_client_rust::{
+    Action, ActionListOptions, Exec, KeyValue, NativeClient, OpenWhiskError, OpenwhiskClient, Rule,
+    RuleListOptions, Runtime, Trigger, TriggerListOptions, WskProperties,
+};
+use output::Printer;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::process;
+
+type Client = OpenwhiskClient<NativeClient>;
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(error) = run(cli) {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<(), OpenWhiskError> {
+    let printer = Printer::new(cli.global.output);
+
+    match cli.command {
+        Command::Action(command) => action(&client(&cli.global)?, command, &printer),
+        Command::Trigger(command) => trigger(&client(&cli.global)?, command, &printer),
+        Command::Rule(command) => rule(&client(&cli.global)?, command, &printer),
+        Command::Namespace(NamespaceCommand::List) => {
+            let namespaces = client(&cli.global)?.namespaces().list()?;
+
+            printer.list("namespaces", &["name"], &namespaces, |namespace| {
+                vec![namespace.clone()]
+            });
+
+            Ok(())
+        }
+        Command::Property(command) => property(command, &cli.global, &printer),
+    }
+}
+
+/// Reads the properties the way the wsk CLI does, overridden by the global flags
+fn properties(global: &GlobalArgs) -> Result<WskProperties, OpenWhiskError> {
+    let mut properties = WskProperties::from_default_locations()?;
+
+    if let Some(apihost) = &global.apihost {
+        properties = properties.set_api_host(apihost);
+    }
+
+    if let Some(auth) = &global.auth {
+        properties.auth_token = auth.clone();
+    }
+
+    if let Some(apiversion) = &global.apiversion {
+        properties.version = apiversion.clone();
+    }
+
+    if let (Some(cert), Some(key)) = (&global.cert, &global.key) {
+        properties = properties.set_client_certificate(cert.clone(), key.clone());
+    }
+
+    if global.insecure {
+        properties = properties.set_bypass_cerificate_check(true);
+    }
+
+    Ok(properties)
+}
+
+fn client(global: &GlobalArgs) -> Result<Client, OpenWhiskError> {
+    let properties = properties(global)?;
+
+    if properties.host.is_empty() {
+        return Err(OpenWhiskError::Configuration(
+            "The API host is not valid, set it with `wsk property set --apihost`".to_string(),
+        ));
+    }
+
+    Client::try_new(Some(&properties))
+}
+
+/// Reads the parameters of the param file and the `KEY VALUE` pairs, which take precedence.
+/// Values are parsed as JSON and kept as strings when they are not valid JSON
+fn params(args: &ParamArgs) -> Result<Map<String, Value>, OpenWhiskError> {
+    let mut params = match &args.param_file {
+        Some(path) => {
+            let content = match fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(error) => {
+                    return Err(OpenWhiskError::InvalidInput(format!(
+                        "Failed to read {} {}",
+                        path.display(),
+                        error
+                    )))
+                }
+            };
+
+            match serde_json::from_str(&content) {
+                Ok(Value::Object(params)) => params,
+                _ => {
+                    return Err(OpenWhiskError::InvalidInput(format!(
+                        "{} does not hold a JSON object",
+                        path.display()
+                    )))
+                }
+            }
+        }
+        None => Map::new(),
+    };
+
+    params.extend(key_values(&args.params));
+
+    Ok(params)
+}
+
+fn key_values(pairs: &[String]) -> Map<String, Value> {
+    pairs
+        .chunks(2)
+        .filter_map(|pair| match pair {
+            [key, value] => Some((
+                key.clone(),
+                serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.clone())),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn to_key_values(values: Map<String, Value>) -> Vec<KeyValue> {
+    values
+        .into_iter()
+        .map(|(key, value)| KeyValue { key, value })
+        .collect()
+}
+
+/// Returns the name qualified with the namespace, names which start with `/` already are
+fn qualified_name(namespace: &str, name: &str) -> String {
+    match name.starts_with('/') {
+        true => name.to_string(),
+        false => format!("/{}/{}", namespace, name),
+    }
+}
+
+fn action(
+    client: &Client,
+    command: ActionCommand,
+    printer: &Printer,
+) -> Result<(), OpenWhiskError> {
+    match command {
+        ActionCommand::List(args) => {
+            let actions = client
+                .actions()
+                .list_with_options(&ActionListOptions::new(args.limit, args.skip, false))?;
+
+            printer.list("actions", &["name"], &actions, |action| {
+                vec![qualified_name(&action.namespace, &action.name)]
+            });
+        }
+        ActionCommand::Get { name } => {
+            let action = client.actions().get(&name, true)?;
+
+            printer.entity(&format!("ok: got action {}", name), &action);
+        }
+        ActionCommand::Create(args) => {
+            let action = client.actions().insert(&action_definition(args)?, false)?;
+
+            printer.message(&format!("ok: created action {}", action.name), &action);
+        }
+        ActionCommand::Update(args) => {
+            let action = client.actions().insert(&action_definition(args)?, true)?;
+
+            printer.message(&format!("ok: updated action {}", action.name), &action);
+        }
+        ActionCommand::Delete { name } => {
+            let action = client.actions().delete(&name)?;
+
+            printer.message(&format!("ok: deleted action {}", name), &action);
+        }
+        ActionCommand::Invoke {
+            name,
+            params: param_args,
+            blocking,
+            result,
+        } => {
+            let payload = Value::Object(params(&param_args)?);
+            let response = match client
+                .actions()
+                .invoke(&name, payload, blocking || result, result)
+            {
+                Ok(response) => response,
+                // The action outlasted the blocking wait and continues asynchronously
+                Err(OpenWhiskError::Timeout(error)) => {
+                    let activation_id = error.activation_id.unwrap_or_default();
+                    let message = format!(
+                        "ok: invoked {}, but the request has not yet finished, with id {}",
+                        qualified_name(client.context.namespace(), &name),
+                        activation_id
+                    );
+                    printer.message(&message, &json!({ "activationId": activation_id }));
+
+                    return Ok(());
+                }
+                Err(error) => return Err(error),
+            };
+
+            if result {
+                printer.value(&response);
+            } else {
+                let message = format!(
+                    "ok: invoked {} with id {}",
+                    qualified_name(client.context.namespace(), &name),
+                    response["activationId"].as_str().unwrap_or_default()
+                );
+
+                match blocking {
+                    true => printer.entity(&message, &response),
+                    false => printer.message(&message, &response),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn action_definition(args: ActionArgs) -> Result<Action, OpenWhiskError> {
+    let kind = match &args.kind {
+        Some(kind) => Some(kind.parse::<Runtime>()?),
+        None => None,
+    };
+
+    let mut exec = Exec::from_path(&args.artifact, kind)?;
+
+    if let Some(main) = &args.main {
+        exec = exec.set_main(main);
+    }
+
+    Ok(Action {
+        name: args.name,
+        exec,
+        parameters: to_key_values(params(&args.params)?),
+        annotations: to_key_values(key_values(&args.annotations.annotations)),
+        ..Default::default()
+    })
+}
+
+fn trigger(
+    client: &Client,
+    command: TriggerCommand,
+    printer: &Printer,
+) -> Result<(), OpenWhiskError> {
+    match command {
+        TriggerCommand::List(args) => {
+            let triggers = client
+                .triggers()
+                .list_with_options(&TriggerListOptions::new(args.limit, args.skip, false))?;
+
+            printer.list("triggers", &["name"], &triggers, |trigger| {
+                vec![qualified_name(&trigger.namespace, &trigger.name)]
+            });
+        }
+        TriggerCommand::Get { name } => {
+            let trigger = client.triggers().get(&name)?;
+
+            printer.entity(&format!("ok: got trigger {}", name), &trigger);
+        }
+        TriggerCommand::Create(args) => {
+            let trigger = client
+                .triggers()
+                .insert(&trigger_definition(args)?, false)?;
+
+            printer.message(&format!("ok: created trigger {}", trigger.name), &trigger);
+        }
+        TriggerCommand::Update(args) => {
+            let trigger = client.triggers().insert(&trigger_definition(args)?, true)?;
+
+            printer.message(&format!("ok: updated trigger {}", trigger.name), &trigger);
+        }
+        TriggerCommand::Delete { name } => {
+            let trigger = client.triggers().delete(&name)?;
+
+            printer.message(&format!("ok: deleted trigger {}", name), &trigger);
+        }
+        TriggerCommand::Fire { name, params: args } => {
+            let activation_id = client
+                .triggers()
+                .fire(&name, Value::Object(params(&args)?))?;
+
+            let message = match &activation_id {
+                Some(activation_id) => {
+                    format!(
+                        "ok: triggered {} with id {}",
+                        qualified_name(client.context.namespace(), &name),
+                        activation_id
+                    )
+                }
+                None => format!(
+                    "ok: triggered {}, no rule is active",
+                    qualified_name(client.context.namespace(), &name)
+                ),
+            };
+
+            printer.message(&message, &json!({ "activationId": activation_id }));
+        }
+    }
+
+    Ok(())
+}
+
+fn trigger_definition(args: TriggerArgs) -> Result<Trigger, OpenWhiskError> {
+    Ok(Trigger {
+        name: args.name,
+        parameters: to_key_values(params(&args.params)?),
+        annotations: to_key_values(key_values(&args.annotations.annotations)),
+        ..Default::default()
+    })
+}
+
+fn rule(client: &Client, command: RuleCommand, printer: &Printer) -> Result<(), OpenWhiskError> {
+    match command {
+        RuleCommand::List(args) => {
+            let rules = client
+                .rules()
+                .list_with_options(&RuleListOptions::new(args.limit, args.skip, false))?;
+
+            printer.list("rules", &["name", "status"], &rules, |rule| {
+                vec![
+                    qualified_name(&rule.namespace, &rule.name),
+                    rule.status.clone(),
+                ]
+            });
+        }
+        RuleCommand::Get { name } => {
+            let rule = client.rules().get(&name)?;
+
+            printer.entity(&format!("ok: got rule {}", name), &rule);
+        }
+        RuleCommand::Create(args) => {
+            let rule = client.rules().insert(&rule_definition(args), false)?;
+
+            printer.message(&format!("ok: created rule {}", rule.name), &rule);
+        }
+        RuleCommand::Update(args) => {
+            let rule = client.rules().insert(&rule_definition(args), true)?;
+
+            printer.message(&format!("ok: updated rule {}", rule.name), &rule);
+        }
+        RuleCommand::Delete { name } => {
+            let rule = client.rules().delete(&name)?;
+
+            printer.message(&format!("ok: deleted rule {}", name), &rule);
+        }
+        RuleCommand::Enable { name } => {
+            let status = client.rules().set_state(&name, "active")?;
+
+            printer.message(
+                &format!("ok: enabled rule {}", name),
+                &json!({ "status": status }),
+            );
+        }
+        RuleCommand::Disable { name } => {
+            let status = client.rules().set_state(&name, "inactive")?;
+
+            printer.message(
+                &format!("ok: disabled rule {}", name),
+                &json!({ "status": status }),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn rule_definition(args: RuleArgs) -> Rule {
+    Rule {
+        name: args.name,
+        trigger: args.trigger,
+        action: args.action,
+        ..Default::default()
+    }
+}
+
+/// Keys of the properties file, with the label the wsk CLI prints them with
+const PROPERTIES: &[(&str, &str)] = &[
+    ("AUTH", "whisk auth"),
+    ("APIHOST", "whisk API host"),
+    ("NAMESPACE", "whisk namespace"),
+    ("APIVERSION", "whisk API version"),
+    ("CERT", "client cert"),
+    ("KEY", "client key"),
+];
+
+/// Returns the keys selected by the flags of `property get` and `property unset`,
+/// every key with `--all` or, when `default_all` is toggled, when no flag is given
+fn property_keys(args: &PropertyArgs, default_all: bool) -> Vec<&'static str> {
+    let selected = [
+        args.auth,
+        args.apihost,
+        args.namespace,
+        args.apiversion,
+        args.cert,
+        args.key,
+    ];
+    let all = args.all || (default_all && !selected.iter().any(|selected| *selected));
+
+    PROPERTIES
+        .iter()
+        .zip(selected.iter())
+        .filter(|(_, selected)| all || **selected)
+        .map(|((key, _), _)| *key)
+        .collect()
+}
+
+fn property(
+    command: PropertyCommand,
+    global: &GlobalArgs,
+    printer: &Printer,
+) -> Result<(), OpenWhiskError> {
+    match command {
+        PropertyCommand::Get(args) => {
+            let properties = properties(global)?;
+            let mut values = Map::new();
+            let mut rows = Vec::new();
+
+            for key in property_keys(&args, true) {
+                let value = match key {
+                    "AUTH" => properties.auth_token.clone(),
+                    "APIHOST" => properties.host.clone(),
+                    "NAMESPACE" => properties.namespace.clone(),
+                    "APIVERSION" => properties.version.clone(),
+                    "CERT" => properties.cert.clone().unwrap_or_default(),
+                    _ => properties.key.clone().unwrap_or_default(),
+                };
+
+                rows.push(vec![label(key).to_string(), value.clone()]);
+                values.insert(key.to_string(), Value::String(value));
+            }
+
+            printer.rows(&["property", "value"], rows, &values);
+        }
+        PropertyCommand::Set {
+            apihost,
+            auth,
+            namespace,
+            apiversion,
+            cert,
+            key,
+        } => {
+            let values = [auth, apihost, namespace, apiversion, cert, key];
+            let updates: Vec<(&str, Option<&str>)> = PROPERTIES
+                .iter()
+                .zip(values.iter())
+                .filter_map(|((key, _), value)| value.as_deref().map(|value| (*key, Some(value))))
+                .collect();
+
+            if updates.is_empty() {
+                return Err(OpenWhiskError::InvalidInput(
+                    "No property to set was given".to_string(),
+                ));
+            }
+
+            WskProperties::update_wskprops_file(wskprops_path()?, &updates)?;
+
+            for (key, value) in updates {
+                printer.message(
+                    &format!("ok: {} set to {}", label(key), value.unwrap_or_default()),
+                    &json!({ key: value }),
+                );
+            }
+        }
+        PropertyCommand::Unset(args) => {
+            let keys = property_keys(&args, false);
+
+            if keys.is_empty() {
+                return Err(OpenWhiskError::InvalidInput(
+                    "No property to unset was given, use --all to unset every property".to_string(),
+                ));
+            }
+            let updates: Vec<(&str, Option<&str>)> = keys.iter().map(|key| (*key, None)).collect();
+
+            WskProperties::update_wskprops_file(wskprops_path()?, &updates)?;
+
+            for key in keys {
+                printer.message(&format!("ok: {} unset", label(key)), &json!({ key: null }));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn label(key: &str) -> &'static str {
+    PROPERTIES
+        .iter()
+        .find(|(property, _)| *property == key)
+        .map(|(_, label)| *label)
+        .unwrap_or_default()
+}
+
+fn wskprops_path() -> Result<std::path::PathBuf, OpenWhiskError> {
+    match WskProperties::wskprops_path() {
+        Some(path) => Ok(path),
+        None => Err(OpenWhiskError::Configuration(
+            "The home directory is not known, set WSK_CONFIG_FILE".to_string(),
+        )),
+    }
+}
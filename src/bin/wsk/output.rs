@@ -0,0 +1,107 @@
+use serde::Serialize;
+
+use crate::args::Output;
+
+/// Prints the results of commands as tables and messages, or as raw JSON
+pub struct Printer {
+    output: Output,
+}
+
+impl Printer {
+    pub fn new(output: Output) -> Self {
+        Printer { output }
+    }
+
+    /// Prints a list as a table with the given columns, or as a JSON array
+    ///
+    /// # Arguments
+    /// * `title`   - Title printed above the table, like `actions`
+    /// * `columns` - Header of each column
+    /// * `items`   - Entities of the list
+    /// * `row`     - Cells of the row of an entity
+    pub fn list<T, F>(&self, title: &str, columns: &[&str], items: &[T], row: F)
+    where
+        T: Serialize,
+        F: Fn(&T) -> Vec<String>,
+    {
+        match self.output {
+            Output::Json => self.value(&items),
+            Output::Table => {
+                println!("{}", title);
+                print!("{}", table(columns, items.iter().map(row).collect()));
+            }
+        }
+    }
+
+    /// Prints rows as a table, or the JSON value standing for them
+    ///
+    /// # Arguments
+    /// * `columns` - Header of each column
+    /// * `rows`    - Cells of each row
+    /// * `value`   - Value of the JSON output
+    pub fn rows<T: Serialize>(&self, columns: &[&str], rows: Vec<Vec<String>>, value: &T) {
+        match self.output {
+            Output::Json => self.value(value),
+            Output::Table => print!("{}", table(columns, rows)),
+        }
+    }
+
+    /// Prints an entity after a message, or the entity alone as JSON
+    ///
+    /// # Arguments
+    /// * `message` - Message of the table output, like `ok: got action cars`
+    /// * `entity`  - Entity returned by OpenWhisk
+    pub fn entity<T: Serialize>(&self, message: &str, entity: &T) {
+        if self.output == Output::Table {
+            println!("{}", message);
+        }
+
+        self.value(entity)
+    }
+
+    /// Prints a message, or the JSON value standing for it
+    ///
+    /// # Arguments
+    /// * `message` - Message of the table output
+    /// * `value`   - Value of the JSON output
+    pub fn message<T: Serialize>(&self, message: &str, value: &T) {
+        match self.output {
+            Output::Table => println!("{}", message),
+            Output::Json => self.value(value),
+        }
+    }
+
+    /// Prints a value as JSON, whatever the output
+    pub fn value<T: Serialize + ?Sized>(&self, value: &T) {
+        match serde_json::to_string_pretty(value) {
+            Ok(json) => println!("{}", json),
+            Err(error) => eprintln!("error: Failed to serialize output {}", error),
+        }
+    }
+}
+
+/// Formats rows as left aligned columns, sized to their widest cell
+fn table(columns: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = columns.iter().map(|column| column.len()).collect();
+
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header = columns.iter().map(|column| column.to_string()).collect();
+
+    std::iter::once(header)
+        .chain(rows)
+        .map(|row: Vec<String>| {
+            let line: Vec<String> = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+
+            format!("{}\n", line.join("  ").trim_end())
+        })
+        .collect()
+}
@@ -0,0 +1,276 @@
+use super::common::{OpenWhisk, WskProperties, DEFAULT_TIMEOUT};
+use super::tls::TlsSettings;
+use super::{auth_headers, AuthProvider, RetryPolicy};
+use crate::api::{AsyncService, HttpMethods, HttpResponse, OpenWhiskError};
+use futures_timer::Delay;
+use reqwest::{Client, RequestBuilder, Response};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A non blocking Client to make Requests with.
+/// Requests are sent by reqwest, which must be polled from within a tokio runtime.
+/// The backoff between retries does not depend on the runtime
+#[derive(Debug, Default, Clone)]
+pub struct AsyncNativeClient {
+    client: Client,
+    retry_policy: RetryPolicy,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
+    /// Timeout of each request, overriding the timeout of the client
+    timeout: Option<Duration>,
+}
+
+impl OpenWhisk for AsyncNativeClient {
+    /// AsyncNativeClient - Http Client (Here client is async Reqwest Client)
+    type Output = AsyncNativeClient;
+    /// Creates New WhiskClient
+    ///
+    /// # Arguments
+    /// * `insecure` - Option of Bool to specify connection type
+    fn new_whisk_client(insecure: Option<bool>) -> Self::Output {
+        AsyncNativeClient::with_client(
+            reqwest::Client::builder()
+                .danger_accept_invalid_certs(insecure.unwrap_or_default())
+                .timeout(DEFAULT_TIMEOUT)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    /// Creates New WhiskClient with the connection type, TLS settings, timeouts, proxies,
+    /// retry policy and authentication of the properties
+    ///
+    /// # Arguments
+    /// * `properties` - OpenWhisk properties
+    fn from_properties(properties: &WskProperties) -> Result<Self::Output, OpenWhiskError> {
+        let tls = TlsSettings::from_properties(properties)?;
+        let mut builder = reqwest::Client::builder()
+            .danger_accept_invalid_certs(properties.insecure)
+            .timeout(properties.timeout.unwrap_or(DEFAULT_TIMEOUT));
+
+        if let Some(connect_timeout) = properties.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(proxy) = &properties.proxy {
+            for proxy in proxy.proxies()? {
+                builder = builder.proxy(proxy);
+            }
+        }
+
+        if let Some(identity) = tls.identity {
+            builder = builder.identity(identity);
+        }
+
+        for certificate in tls.root_certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+
+        if let Some(version) = tls.min_version {
+            builder = builder.min_tls_version(version);
+        }
+
+        let client = match builder.build() {
+            Ok(client) => client,
+            Err(error) => {
+                return Err(OpenWhiskError::Configuration(format!(
+                    "Failed to create http client {}",
+                    error
+                )))
+            }
+        };
+
+        Ok(AsyncNativeClient {
+            retry_policy: properties.retry_policy.clone(),
+            auth_provider: properties.auth_provider.clone(),
+            ..AsyncNativeClient::with_client(client)
+        })
+    }
+
+    /// Returns a copy of the client whose requests time out after the given duration
+    ///
+    /// # Arguments
+    /// * `timeout` - Timeout of each request
+    fn with_timeout(&self, timeout: Duration) -> Self::Output {
+        AsyncNativeClient {
+            timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+}
+
+impl AsyncNativeClient {
+    /// Creates a client with the default retry policy and basic authentication
+    fn with_client(client: Client) -> Self {
+        AsyncNativeClient {
+            client,
+            retry_policy: RetryPolicy::default(),
+            auth_provider: None,
+            timeout: None,
+        }
+    }
+
+    /// Adds the authentication headers and the request timeout override to a request
+    fn prepare(
+        &self,
+        mut request: RequestBuilder,
+        use_auth: Option<(&str, &str)>,
+    ) -> Result<RequestBuilder, OpenWhiskError> {
+        for (key, value) in auth_headers(self.auth_provider.as_ref(), use_auth)? {
+            request = request.header(key, value);
+        }
+
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        Ok(request)
+    }
+
+    /// Sends a request, retrying transient failures as set by the retry policy
+    async fn send(&self, request: RequestBuilder) -> Result<Response, OpenWhiskError> {
+        let request = match request.build() {
+            Ok(request) => request,
+            Err(error) => return Err(OpenWhiskError::Request(format!("{}", error))),
+        };
+        let method = request.method().as_str().to_string();
+        let mut attempt = 1;
+
+        loop {
+            // Requests with streamed bodies can not be cloned and are sent once
+            let attempt_request = match request.try_clone() {
+                Some(attempt_request) => attempt_request,
+                None => {
+                    return self
+                        .client
+                        .execute(request)
+                        .await
+                        .map_err(|error| OpenWhiskError::Transport(format!("{}", error)))
+                }
+            };
+
+            let delay = match self.client.execute(attempt_request).await {
+                Ok(response)
+                    if self
+                        .retry_policy
+                        .is_retry_status(response.status().as_u16())
+                        && self.retry_policy.can_retry(&method, attempt) =>
+                {
+                    self.retry_policy.delay(attempt, Some(response.headers()))
+                }
+                Ok(response) => return Ok(response),
+                Err(_) if self.retry_policy.can_retry(&method, attempt) => {
+                    self.retry_policy.delay(attempt, None)
+                }
+                Err(error) => return Err(OpenWhiskError::Transport(format!("{}", error))),
+            };
+
+            Delay::new(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+impl AsyncService for AsyncNativeClient {
+    type Output = reqwest::RequestBuilder;
+
+    ///
+    /// Creates New Request and Returns  `reqwest::RequestBuilder`
+    ///
+    /// # Arguments
+    /// * `method`   - Option of HTTPMethods
+    /// * `url`      - API Host url
+    /// * `use_auth` - Option of tuple conatining Username and Password
+    /// * `body`     - Option of value which can have parameters necessary for the body of request
+    ///
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        use_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let body = body.unwrap_or_else(|| serde_json::json!({}));
+
+        let request = match method {
+            Some(HttpMethods::GET) => self.client.get(url),
+            Some(HttpMethods::POST) => self.client.post(url).json(&body),
+            Some(HttpMethods::PUT) => self.client.put(url).json(&body),
+            Some(HttpMethods::DELETE) => self.client.delete(url).json(&body),
+            Some(HttpMethods::PATCH) => self.client.patch(url).json(&body),
+            Some(HttpMethods::HEAD) => self.client.head(url),
+            Some(HttpMethods::OPTIONS) => self.client.request(reqwest::Method::OPTIONS, url),
+            None => {
+                return Err(OpenWhiskError::Request(
+                    "Falied to create request".to_string(),
+                ))
+            }
+        };
+
+        self.prepare(request, use_auth)
+    }
+    ///
+    /// Creates New Request with custom headers and a raw body
+    ///
+    /// # Arguments
+    /// * `method`   - HTTPMethod of the request
+    /// * `url`      - API Host url
+    /// * `use_auth` - Option of tuple conatining Username and Password
+    /// * `headers`  - Headers to be sent with the request
+    /// * `body`     - Option of raw body of the request
+    ///
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        use_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let method = match reqwest::Method::from_bytes(method.as_str().as_bytes()) {
+            Ok(method) => method,
+            Err(error) => return Err(OpenWhiskError::Request(format!("{}", error))),
+        };
+
+        let mut request = self.client.request(method, url);
+
+        for (key, value) in headers {
+            request = request.header(key.as_str(), value.as_str());
+        }
+
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+
+        self.prepare(request, use_auth)
+    }
+
+    ///
+    /// To invoke request and get the raw response out of request execution
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Http request with url,auth,headers and body
+    ///
+    async fn invoke_raw_request(
+        &self,
+        request: Self::Output,
+    ) -> Result<HttpResponse, OpenWhiskError> {
+        match self.send(request).await {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let headers = response.headers().clone();
+
+                match response.bytes().await {
+                    Ok(body) => Ok(HttpResponse {
+                        status,
+                        headers,
+                        body: body.to_vec(),
+                    }),
+                    Err(error) => Err(OpenWhiskError::Transport(format!("{}", error))),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::api::OpenWhiskError;
+
+/// Provides the headers which authenticate requests to OpenWhisk.
+///
+/// Clients without a provider authenticate with the basic credentials of the auth token.
+/// A provider replaces them on every request which is authenticated, requests sent without
+/// credentials, like those to public web actions, are left untouched
+pub trait AuthProvider: fmt::Debug + Send + Sync {
+    /// Returns the headers to add to an authenticated request
+    fn headers(&self) -> Result<Vec<(String, String)>, OpenWhiskError>;
+}
+
+/// Basic authentication with a username and password, as held by OpenWhisk auth tokens
+#[derive(Clone, PartialEq, Eq)]
+pub struct BasicAuth {
+    username: String,
+    password: String,
+}
+
+impl BasicAuth {
+    /// Creates basic authentication
+    ///
+    /// # Arguments
+    /// * `username` - Username, the uuid of an OpenWhisk auth token
+    /// * `password` - Password, the key of an OpenWhisk auth token
+    pub fn new(username: &str, password: &str) -> Self {
+        BasicAuth {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    /// Creates basic authentication from a `:` separated OpenWhisk auth token
+    ///
+    /// # Arguments
+    /// * `auth_token` - The authorization token
+    pub fn from_auth_token(auth_token: &str) -> Self {
+        let (username, password) = auth_token.split_once(':').unwrap_or((auth_token, ""));
+
+        Self::new(username, password)
+    }
+}
+
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+impl AuthProvider for BasicAuth {
+    fn headers(&self) -> Result<Vec<(String, String)>, OpenWhiskError> {
+        let credentials = base64::encode(format!("{}:{}", self.username, self.password));
+
+        Ok(vec![(
+            "Authorization".to_string(),
+            format!("Basic {}", credentials),
+        )])
+    }
+}
+
+/// Bearer authentication with a static token
+#[derive(Clone, PartialEq, Eq)]
+pub struct BearerToken(String);
+
+impl BearerToken {
+    /// Creates bearer authentication
+    ///
+    /// # Arguments
+    /// * `token` - The bearer token
+    pub fn new(token: &str) -> Self {
+        BearerToken(token.to_string())
+    }
+}
+
+impl fmt::Debug for BearerToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BearerToken(<redacted>)")
+    }
+}
+
+impl AuthProvider for BearerToken {
+    fn headers(&self) -> Result<Vec<(String, String)>, OpenWhiskError> {
+        Ok(vec![(
+            "Authorization".to_string(),
+            format!("Bearer {}", self.0),
+        )])
+    }
+}
+
+/// Custom headers, for gateways which authenticate with api keys or other headers
+#[derive(Clone, PartialEq, Eq, Default)]
+pub struct HeaderAuth {
+    headers: Vec<(String, String)>,
+}
+
+impl HeaderAuth {
+    /// Creates authentication without headers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header to authenticated requests
+    ///
+    /// # Arguments
+    /// * `name`  - Name of the header
+    /// * `value` - Value of the header
+    pub fn add_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl fmt::Debug for HeaderAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self.headers.iter().map(|(name, _)| name.as_str()).collect();
+
+        f.debug_struct("HeaderAuth")
+            .field("headers", &names)
+            .finish()
+    }
+}
+
+impl AuthProvider for HeaderAuth {
+    fn headers(&self) -> Result<Vec<(String, String)>, OpenWhiskError> {
+        Ok(self.headers.clone())
+    }
+}
+
+/// Short-lived token returned by the fetch function of a `RefreshingToken`
+#[derive(Clone)]
+pub struct AccessToken {
+    /// The bearer token
+    pub token: String,
+    /// Instant after which the token is no longer valid
+    pub expires_at: Instant,
+}
+
+impl AccessToken {
+    /// Creates an access token
+    ///
+    /// # Arguments
+    /// * `token`      - The bearer token
+    /// * `expires_in` - Time the token is valid for
+    pub fn new(token: &str, expires_in: Duration) -> Self {
+        AccessToken {
+            token: token.to_string(),
+            expires_at: Instant::now() + expires_in,
+        }
+    }
+}
+
+impl fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("token", &"<redacted>")
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+type FetchToken = dyn Fn() -> Result<AccessToken, OpenWhiskError> + Send + Sync;
+
+/// Bearer authentication with short-lived tokens, such as IAM tokens.
+/// The token is fetched on first use and fetched again once it is about to expire.
+/// The fetch function is called on the calling thread, also when used by the non blocking client
+pub struct RefreshingToken {
+    fetch: Box<FetchToken>,
+    refresh_margin: Duration,
+    token: Mutex<Option<AccessToken>>,
+}
+
+impl RefreshingToken {
+    /// Creates bearer authentication with tokens returned by the fetch function
+    ///
+    /// # Arguments
+    /// * `fetch` - Function which fetches a new token, like exchanging an api key for an IAM token
+    pub fn new<F>(fetch: F) -> Self
+    where
+        F: Fn() -> Result<AccessToken, OpenWhiskError> + Send + Sync + 'static,
+    {
+        RefreshingToken {
+            fetch: Box::new(fetch),
+            refresh_margin: Duration::from_secs(60),
+            token: Mutex::new(None),
+        }
+    }
+
+    /// Sets how long before its expiry a token is refreshed, one minute by default
+    ///
+    /// # Arguments
+    /// * `refresh_margin` - Time before expiry at which tokens are refreshed
+    pub fn set_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    /// Returns a valid token, fetching a new one when the current one is about to expire
+    pub fn token(&self) -> Result<String, OpenWhiskError> {
+        let mut token = match self.token.lock() {
+            Ok(token) => token,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        match token.as_ref() {
+            Some(current) if Instant::now() + self.refresh_margin < current.expires_at => {
+                Ok(current.token.clone())
+            }
+            _ => {
+                let fetched = (self.fetch)()?;
+                let value = fetched.token.clone();
+                *token = Some(fetched);
+
+                Ok(value)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for RefreshingToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefreshingToken")
+            .field("refresh_margin", &self.refresh_margin)
+            .finish()
+    }
+}
+
+impl AuthProvider for RefreshingToken {
+    fn headers(&self) -> Result<Vec<(String, String)>, OpenWhiskError> {
+        Ok(vec![(
+            "Authorization".to_string(),
+            format!("Bearer {}", self.token()?),
+        )])
+    }
+}
+
+/// Returns the headers authenticating a request, with the basic credentials of the auth token
+/// when no provider is set. Requests sent without credentials are not authenticated
+///
+/// # Arguments
+/// * `provider`  - Option of the provider set on the client
+/// * `user_auth` - Option of tuple conatining Username and Password
+pub(crate) fn auth_headers(
+    provider: Option<&Arc<dyn AuthProvider>>,
+    user_auth: Option<(&str, &str)>,
+) -> Result<Vec<(String, String)>, OpenWhiskError> {
+    match (user_auth, provider) {
+        (None, _) => Ok(Vec::new()),
+        (Some(_), Some(provider)) => provider.headers(),
+        (Some((user, pass)), None) => BasicAuth::new(user, pass).headers(),
+    }
+}
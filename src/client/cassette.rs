@@ -0,0 +1,896 @@
+use super::common::{OpenWhisk, WskProperties};
+use crate::api::{AsyncService, HttpMethods, HttpResponse, OpenWhiskError, Service};
+use http::header::{HeaderName, HeaderValue};
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::future::{ready, Future};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Value recorded in place of the secret headers
+static REDACTED: &str = "[REDACTED]";
+
+/// Headers whose values are never written to a cassette
+static DEFAULT_REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "apigw-access-token",
+    "cookie",
+    "set-cookie",
+];
+
+/// Lowercase names of the fields of json bodies whose values are never written to a cassette,
+/// like the `authKey` sent to feed actions and the `authkey` of API Gateway routes
+static DEFAULT_REDACTED_FIELDS: &[&str] = &["authkey"];
+
+/// Query parameters whose values are never written to a cassette,
+/// like the subject sent to the API Gateway
+static DEFAULT_REDACTED_QUERY_PARAMETERS: &[&str] = &["spaceguid"];
+
+/// Recorded requests and responses, saved as a JSON file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Interactions in the order they were recorded
+    #[serde(default)]
+    pub interactions: Vec<Interaction>,
+}
+
+/// A request and the response it received
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// Recorded request, whose secret headers are redacted
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method of the request, like `GET`
+    pub method: String,
+    /// Url of the request
+    pub url: String,
+    /// Headers of the request, as lowercase names and values
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// Body of the request
+    #[serde(default)]
+    pub body: Option<RecordedBody>,
+}
+
+/// Recorded response
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// Status code of the response
+    pub status: u16,
+    /// Headers of the response, as lowercase names and values
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// Body of the response
+    #[serde(default)]
+    pub body: RecordedBody,
+}
+
+/// Recorded body, kept as text when it is valid utf-8 and base64 encoded otherwise
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedBody {
+    /// Text of the body, or its base64 encoding
+    pub data: String,
+    /// Toggled to true when `data` is base64 encoded
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub base64: bool,
+}
+
+/// How a replayed request is matched with the recorded ones
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Same method, path, query and body. Json bodies match whatever the order of their keys
+    #[default]
+    Exact,
+    /// Same method and path, whatever the query and the body
+    Fuzzy,
+}
+
+impl Cassette {
+    /// Creates an empty cassette
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads a cassette from a JSON file
+    ///
+    /// # Arguments
+    /// * `path` - Path of the cassette file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, OpenWhiskError> {
+        let path = path.as_ref();
+
+        let content = match fs::read(path) {
+            Ok(content) => content,
+            Err(error) => {
+                return Err(OpenWhiskError::Configuration(format!(
+                    "Failed to read cassette {} {}",
+                    path.display(),
+                    error
+                )))
+            }
+        };
+
+        match serde_json::from_slice(&content) {
+            Ok(cassette) => Ok(cassette),
+            Err(error) => Err(OpenWhiskError::Deserialize(format!(
+                "Failed to deserialize cassette {} {}",
+                path.display(),
+                error
+            ))),
+        }
+    }
+
+    /// Writes the cassette to a JSON file, creating its parent directories
+    ///
+    /// # Arguments
+    /// * `path` - Path of the cassette file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), OpenWhiskError> {
+        let path = path.as_ref();
+
+        let content = match serde_json::to_vec_pretty(self) {
+            Ok(content) => content,
+            Err(error) => {
+                return Err(OpenWhiskError::Serialize(format!(
+                    "Failed to serialize cassette {}",
+                    error
+                )))
+            }
+        };
+
+        let written = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => {
+                fs::create_dir_all(parent).and_then(|_| fs::write(path, content))
+            }
+            _ => fs::write(path, content),
+        };
+
+        match written {
+            Ok(()) => Ok(()),
+            Err(error) => Err(OpenWhiskError::Configuration(format!(
+                "Failed to write cassette {} {}",
+                path.display(),
+                error
+            ))),
+        }
+    }
+}
+
+impl RecordedBody {
+    fn new(body: &[u8]) -> Self {
+        match std::str::from_utf8(body) {
+            Ok(data) => RecordedBody {
+                data: data.to_string(),
+                base64: false,
+            },
+            Err(_) => RecordedBody {
+                data: base64::encode(body),
+                base64: true,
+            },
+        }
+    }
+
+    /// Returns the raw bytes of the body
+    pub fn bytes(&self) -> Result<Vec<u8>, OpenWhiskError> {
+        if !self.base64 {
+            return Ok(self.data.clone().into_bytes());
+        }
+
+        match base64::decode(&self.data) {
+            Ok(bytes) => Ok(bytes),
+            Err(error) => Err(OpenWhiskError::Deserialize(format!(
+                "Failed to decode recorded body {}",
+                error
+            ))),
+        }
+    }
+
+    /// Returns true when both bodies hold the same bytes, or the same json value
+    fn matches(&self, other: &RecordedBody) -> bool {
+        if self == other {
+            return true;
+        }
+
+        match (self.json(), other.json()) {
+            (Some(left), Some(right)) => left == right,
+            _ => false,
+        }
+    }
+
+    fn json(&self) -> Option<Value> {
+        match self.base64 {
+            true => None,
+            false => serde_json::from_str(&self.data).ok(),
+        }
+    }
+}
+
+impl RecordedRequest {
+    /// Returns true when the requests match in the given mode.
+    /// The scheme and host are never compared, so a cassette recorded against one
+    /// OpenWhisk can be replayed with the properties of another
+    ///
+    /// # Arguments
+    /// * `other` - Request to be replayed
+    /// * `mode`  - How the requests are compared
+    pub fn matches(&self, other: &RecordedRequest, mode: MatchMode) -> bool {
+        let (path, query) = path_and_query(&self.url);
+        let (other_path, other_query) = path_and_query(&other.url);
+
+        if !self.method.eq_ignore_ascii_case(&other.method) || path != other_path {
+            return false;
+        }
+
+        match mode {
+            MatchMode::Fuzzy => true,
+            MatchMode::Exact => {
+                let empty = RecordedBody::default();
+
+                sorted_query(query) == sorted_query(other_query)
+                    && self
+                        .body
+                        .as_ref()
+                        .unwrap_or(&empty)
+                        .matches(other.body.as_ref().unwrap_or(&empty))
+            }
+        }
+    }
+}
+
+impl RecordedRequest {
+    /// Returns the recorded request of a json request
+    fn json(
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<&Value>,
+        redactions: &Redactions,
+    ) -> RecordedRequest {
+        let mut headers = vec![("content-type".to_string(), "application/json".to_string())];
+        if user_auth.is_some() {
+            headers.push(("authorization".to_string(), REDACTED.to_string()));
+        }
+
+        RecordedRequest {
+            method: method
+                .map(|method| method.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            url: redactions.url(url),
+            headers,
+            body: body.map(|body| {
+                let mut body = body.clone();
+                redactions.json(&mut body);
+
+                RecordedBody::new(body.to_string().as_bytes())
+            }),
+        }
+    }
+
+    /// Returns the recorded request of a raw request
+    fn raw(
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<&[u8]>,
+        redactions: &Redactions,
+    ) -> RecordedRequest {
+        let mut headers = headers.to_vec();
+        if user_auth.is_some() {
+            headers.push(("authorization".to_string(), REDACTED.to_string()));
+        }
+
+        RecordedRequest {
+            method: method.as_str().to_string(),
+            url: redactions.url(url),
+            headers: redactions.headers(headers),
+            body: body.map(|body| redactions.body(body)),
+        }
+    }
+}
+
+/// Returns the path and query of a url, without its scheme and host
+fn path_and_query(url: &str) -> (&str, &str) {
+    let url = match url.split_once("://") {
+        Some((_, url)) => url,
+        None => url,
+    };
+    let url = match url.find('/') {
+        Some(start) if !url.starts_with('/') => &url[start..],
+        Some(_) => url,
+        None => "/",
+    };
+
+    url.split_once('?').unwrap_or((url, ""))
+}
+
+fn sorted_query(query: &str) -> Vec<&str> {
+    let mut pairs: Vec<&str> = query.split('&').filter(|pair| !pair.is_empty()).collect();
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Names of the headers, json fields and query parameters whose values are redacted
+#[derive(Debug, Clone)]
+struct Redactions {
+    /// Lowercase names of the headers
+    headers: Vec<String>,
+    /// Lowercase names of the fields of json bodies, at any depth
+    fields: Vec<String>,
+    /// Lowercase names of the query parameters
+    query_parameters: Vec<String>,
+}
+
+impl Default for Redactions {
+    fn default() -> Self {
+        let owned = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+
+        Redactions {
+            headers: owned(DEFAULT_REDACTED_HEADERS),
+            fields: owned(DEFAULT_REDACTED_FIELDS),
+            query_parameters: owned(DEFAULT_REDACTED_QUERY_PARAMETERS),
+        }
+    }
+}
+
+impl Redactions {
+    /// Returns the headers with the secret ones redacted
+    fn headers(&self, headers: Vec<(String, String)>) -> Vec<(String, String)> {
+        headers
+            .into_iter()
+            .map(|(name, value)| {
+                let name = name.to_lowercase();
+
+                match self.headers.contains(&name) {
+                    true => (name, REDACTED.to_string()),
+                    false => (name, value),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the url with the values of the secret query parameters redacted
+    fn url(&self, url: &str) -> String {
+        let (base, query) = match url.split_once('?') {
+            Some(parts) => parts,
+            None => return url.to_string(),
+        };
+
+        let query: Vec<String> = query
+            .split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((name, _)) if self.query_parameters.contains(&name.to_lowercase()) => {
+                    format!("{}={}", name, REDACTED)
+                }
+                _ => pair.to_string(),
+            })
+            .collect();
+
+        format!("{}?{}", base, query.join("&"))
+    }
+
+    /// Redacts the secret fields of a json value, at any depth
+    fn json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    match self.fields.contains(&key.to_lowercase()) {
+                        true => *value = Value::String(REDACTED.to_string()),
+                        false => self.json(value),
+                    }
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(|value| self.json(value)),
+            _ => (),
+        }
+    }
+
+    /// Returns the recorded body with its secret fields redacted when it is json
+    fn body(&self, body: &[u8]) -> RecordedBody {
+        let mut value = match serde_json::from_slice::<Value>(body) {
+            Ok(value) => value,
+            Err(_) => return RecordedBody::new(body),
+        };
+
+        let original = value.clone();
+        self.json(&mut value);
+
+        // Bodies without secret fields are kept byte for byte
+        match value == original {
+            true => RecordedBody::new(body),
+            false => RecordedBody::new(value.to_string().as_bytes()),
+        }
+    }
+}
+
+fn lock(cassette: &Mutex<Cassette>) -> MutexGuard<'_, Cassette> {
+    cassette
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Http client decorator which records the requests made through another client and their
+/// responses to a cassette, redacting the `Authorization` header and the other secret headers,
+/// the `authKey` and `authkey` fields of json bodies and the `spaceguid` query parameter.
+/// Clones record to the same cassette, so the `client` of an `OpenwhiskClient` holds every
+/// interaction made through its services
+///
+/// # Example
+/// ```
+/// use openwhisk_rust::{NativeClient, OpenwhiskClient, RecordingClient, WskProperties};
+///
+/// let wsk_properties = WskProperties::new(
+///         "your:auth_token".to_string(),
+///         "host".to_string(),
+///         "namespace".to_string()
+///  );
+///
+/// let client = OpenwhiskClient::<RecordingClient<NativeClient>>::try_new(Some(&wsk_properties)).unwrap();
+///
+/// client.actions().list().unwrap();
+/// client.client.save("tests/cassettes/list_actions.json").unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecordingClient<T> {
+    inner: T,
+    cassette: Arc<Mutex<Cassette>>,
+    redactions: Redactions,
+}
+
+/// Request of a recording client, along with the request of the client it decorates
+#[derive(Debug)]
+pub struct RecordingRequest<R> {
+    recorded: RecordedRequest,
+    inner: R,
+}
+
+impl<T> RecordingClient<T> {
+    /// Creates a client which records the requests made through the given client
+    ///
+    /// # Arguments
+    /// * `inner` - Client which sends the requests
+    pub fn new(inner: T) -> Self {
+        RecordingClient {
+            inner,
+            cassette: Arc::new(Mutex::new(Cassette::new())),
+            redactions: Redactions::default(),
+        }
+    }
+
+    /// Redacts another header, like a custom authentication header
+    ///
+    /// # Arguments
+    /// * `name` - Case insensitive name of the header
+    pub fn redact_header(mut self, name: &str) -> Self {
+        self.redactions.headers.push(name.to_lowercase());
+        self
+    }
+
+    /// Redacts another field of json request and response bodies, at any depth
+    ///
+    /// # Arguments
+    /// * `name` - Case insensitive name of the field
+    pub fn redact_field(mut self, name: &str) -> Self {
+        self.redactions.fields.push(name.to_lowercase());
+        self
+    }
+
+    /// Redacts another query parameter of the request urls
+    ///
+    /// # Arguments
+    /// * `name` - Case insensitive name of the query parameter
+    pub fn redact_query_parameter(mut self, name: &str) -> Self {
+        self.redactions.query_parameters.push(name.to_lowercase());
+        self
+    }
+
+    /// Returns a copy of the interactions recorded so far
+    pub fn cassette(&self) -> Cassette {
+        lock(&self.cassette).clone()
+    }
+
+    /// Writes the interactions recorded so far to a cassette file
+    ///
+    /// # Arguments
+    /// * `path` - Path of the cassette file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), OpenWhiskError> {
+        self.cassette().save(path)
+    }
+
+    /// Records a request and the response it received
+    fn record(&self, request: RecordedRequest, response: &HttpResponse) {
+        let headers = response
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).to_string(),
+                )
+            })
+            .collect();
+
+        lock(&self.cassette).interactions.push(Interaction {
+            request,
+            response: RecordedResponse {
+                status: response.status,
+                headers: self.redactions.headers(headers),
+                body: self.redactions.body(&response.body),
+            },
+        });
+    }
+}
+
+impl<T> OpenWhisk for RecordingClient<T>
+where
+    T: OpenWhisk<Output = T> + Clone,
+{
+    type Output = RecordingClient<T>;
+
+    /// Creates a client which records the requests made through a new client
+    ///
+    /// # Arguments
+    /// * `insecure` - Option of Bool to specify connection type
+    fn new_whisk_client(insecure: Option<bool>) -> Self::Output {
+        RecordingClient::new(T::new_whisk_client(insecure))
+    }
+
+    /// Creates a client which records the requests made through a client
+    /// configured by the properties
+    ///
+    /// # Arguments
+    /// * `properties` - OpenWhisk properties
+    fn from_properties(properties: &WskProperties) -> Result<Self::Output, OpenWhiskError> {
+        Ok(RecordingClient::new(T::from_properties(properties)?))
+    }
+
+    /// Returns a copy of the client recording to the same cassette,
+    /// whose requests time out after the given duration
+    ///
+    /// # Arguments
+    /// * `timeout` - Timeout of each request
+    fn with_timeout(&self, timeout: Duration) -> Self::Output {
+        RecordingClient {
+            inner: self.inner.with_timeout(timeout),
+            cassette: self.cassette.clone(),
+            redactions: self.redactions.clone(),
+        }
+    }
+}
+
+impl<T: Service> Service for RecordingClient<T> {
+    type Output = RecordingRequest<T::Output>;
+
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let recorded =
+            RecordedRequest::json(method, url, user_auth, body.as_ref(), &self.redactions);
+        let inner = self.inner.new_request(method, url, user_auth, body)?;
+
+        Ok(RecordingRequest { recorded, inner })
+    }
+
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let recorded = RecordedRequest::raw(
+            method,
+            url,
+            user_auth,
+            headers,
+            body.as_deref(),
+            &self.redactions,
+        );
+        let inner = self
+            .inner
+            .new_raw_request(method, url, user_auth, headers, body)?;
+
+        Ok(RecordingRequest { recorded, inner })
+    }
+
+    /// Sends the request through the decorated client and records its response.
+    /// Requests which received no response are not recorded
+    fn invoke_raw_request(&self, request: Self::Output) -> Result<HttpResponse, OpenWhiskError> {
+        let response = self.inner.invoke_raw_request(request.inner)?;
+
+        self.record(request.recorded, &response);
+
+        Ok(response)
+    }
+}
+
+impl<T> AsyncService for RecordingClient<T>
+where
+    T: AsyncService + Clone + Send + Sync,
+{
+    type Output = RecordingRequest<T::Output>;
+
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let recorded =
+            RecordedRequest::json(method, url, user_auth, body.as_ref(), &self.redactions);
+        let inner = self.inner.new_request(method, url, user_auth, body)?;
+
+        Ok(RecordingRequest { recorded, inner })
+    }
+
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let recorded = RecordedRequest::raw(
+            method,
+            url,
+            user_auth,
+            headers,
+            body.as_deref(),
+            &self.redactions,
+        );
+        let inner = self
+            .inner
+            .new_raw_request(method, url, user_auth, headers, body)?;
+
+        Ok(RecordingRequest { recorded, inner })
+    }
+
+    fn invoke_raw_request(
+        &self,
+        request: Self::Output,
+    ) -> impl Future<Output = Result<HttpResponse, OpenWhiskError>> + Send {
+        let response = self.inner.invoke_raw_request(request.inner);
+        let recorder = self.clone();
+        let recorded = request.recorded;
+
+        async move {
+            let response = response.await?;
+
+            recorder.record(recorded, &response);
+
+            Ok(response)
+        }
+    }
+}
+
+/// Http client which answers requests with the responses of a cassette, without network.
+/// Each recorded interaction is served once, in the order of the cassette, so repeated
+/// requests like polling get the successive responses they were recorded with.
+/// Requests matching no interaction left fail with `OpenWhiskError::Transport`
+///
+/// # Example
+/// ```
+/// use openwhisk_rust::{MatchMode, OpenwhiskClient, ReplayClient, WskProperties};
+///
+/// let wsk_properties = WskProperties::new(
+///         "your:auth_token".to_string(),
+///         "host".to_string(),
+///         "namespace".to_string()
+///  );
+///
+/// let replay = ReplayClient::from_file("tests/cassettes/list_actions.json")
+///     .unwrap()
+///     .set_match_mode(MatchMode::Fuzzy);
+///
+/// let client = OpenwhiskClient::from_client(replay, Some(&wsk_properties));
+///
+/// let actions = client.actions().list().unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ReplayClient {
+    cassette: Arc<Mutex<Replay>>,
+    mode: MatchMode,
+    /// Redactions applied to the replayed requests, so they match the recorded ones
+    redactions: Redactions,
+}
+
+/// Interactions of a replay, and whether they were already served
+#[derive(Debug, Default)]
+struct Replay {
+    interactions: Vec<(Interaction, bool)>,
+}
+
+impl ReplayClient {
+    /// Creates a client which replays the interactions of a cassette
+    ///
+    /// # Arguments
+    /// * `cassette` - Recorded interactions
+    pub fn new(cassette: Cassette) -> Self {
+        let interactions = cassette
+            .interactions
+            .into_iter()
+            .map(|interaction| (interaction, false))
+            .collect();
+
+        ReplayClient {
+            cassette: Arc::new(Mutex::new(Replay { interactions })),
+            mode: MatchMode::default(),
+            redactions: Redactions::default(),
+        }
+    }
+
+    /// Creates a client which replays the interactions of a cassette file
+    ///
+    /// # Arguments
+    /// * `path` - Path of the cassette file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, OpenWhiskError> {
+        Ok(ReplayClient::new(Cassette::from_file(path)?))
+    }
+
+    /// Sets how requests are matched with the recorded ones, `MatchMode::Exact` by default
+    ///
+    /// # Arguments
+    /// * `mode` - How the requests are compared
+    pub fn set_match_mode(mut self, mode: MatchMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the interactions which were not served yet
+    pub fn remaining(&self) -> Vec<Interaction> {
+        self.replay()
+            .interactions
+            .iter()
+            .filter(|(_, served)| !served)
+            .map(|(interaction, _)| interaction.clone())
+            .collect()
+    }
+
+    fn replay(&self) -> MutexGuard<'_, Replay> {
+        self.cassette
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Returns the response of the first interaction left which matches the request
+    fn serve(&self, request: &RecordedRequest) -> Result<HttpResponse, OpenWhiskError> {
+        let mut replay = self.replay();
+
+        let interaction = replay
+            .interactions
+            .iter_mut()
+            .find(|(interaction, served)| {
+                !served && interaction.request.matches(request, self.mode)
+            });
+
+        let response = match interaction {
+            Some((interaction, served)) => {
+                *served = true;
+                interaction.response.clone()
+            }
+            None => {
+                return Err(OpenWhiskError::Transport(format!(
+                    "No recorded interaction left for {} {}",
+                    request.method, request.url
+                )))
+            }
+        };
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in response.headers.iter() {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.append(name, value);
+            }
+        }
+
+        Ok(HttpResponse {
+            status: response.status,
+            headers,
+            body: response.body.bytes()?,
+        })
+    }
+}
+
+impl OpenWhisk for ReplayClient {
+    type Output = ReplayClient;
+
+    /// Creates a client with an empty cassette, use `OpenwhiskClient::from_client`
+    /// to replay the interactions of a cassette
+    ///
+    /// # Arguments
+    /// * `insecure` - Ignored, the client makes no connection
+    fn new_whisk_client(_insecure: Option<bool>) -> Self::Output {
+        ReplayClient::default()
+    }
+}
+
+impl Service for ReplayClient {
+    type Output = RecordedRequest;
+
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Ok(RecordedRequest::json(
+            method,
+            url,
+            user_auth,
+            body.as_ref(),
+            &self.redactions,
+        ))
+    }
+
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Ok(RecordedRequest::raw(
+            method,
+            url,
+            user_auth,
+            headers,
+            body.as_deref(),
+            &self.redactions,
+        ))
+    }
+
+    fn invoke_raw_request(&self, request: Self::Output) -> Result<HttpResponse, OpenWhiskError> {
+        self.serve(&request)
+    }
+}
+
+impl AsyncService for ReplayClient {
+    type Output = RecordedRequest;
+
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Service::new_request(self, method, url, user_auth, body)
+    }
+
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Service::new_raw_request(self, method, url, user_auth, headers, body)
+    }
+
+    fn invoke_raw_request(
+        &self,
+        request: Self::Output,
+    ) -> impl Future<Output = Result<HttpResponse, OpenWhiskError>> + Send {
+        ready(self.serve(&request))
+    }
+}
@@ -1,12 +1,28 @@
+use super::{AuthProvider, ProxySettings, RetryPolicy, TlsVersion};
+use crate::api::{ApiError, OpenWhiskError};
 use http::StatusCode;
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
 
+/// Timeout of each request when none is set in the properties,
+/// outlasting blocking invokes which wait up to 60 seconds for the action
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(70);
+
+/// Error body returned by the OpenWhisk API
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WhiskError {
+    /// Error code id of the failed request
+    #[serde(default)]
     pub code: String,
+    /// Error message
+    #[serde(default)]
     pub error: String,
+    /// Activation id, when the response refers to an activation
+    #[serde(rename = "activationId", default)]
+    pub activation_id: Option<String>,
 }
 
 /// Representation of OpenWhisk Properties
@@ -29,6 +45,37 @@ pub struct WskProperties {
     /// Debug - Toggle to enable it
     #[serde(default = "bool::default")]
     pub debug: bool,
+    /// Path to the client certificate used for mutual TLS
+    #[serde(default)]
+    pub cert: Option<String>,
+    /// Path to the private key of the client certificate, in PKCS#8 PEM format
+    #[serde(default)]
+    pub key: Option<String>,
+    /// Paths to PEM bundles of root certificates trusted on top of the system ones
+    #[serde(default)]
+    pub ca_certs: Vec<String>,
+    /// Minimum TLS version accepted, the TLS backend default when unset
+    #[serde(default)]
+    pub min_tls_version: Option<TlsVersion>,
+    /// Access token for the API Gateway
+    #[serde(default)]
+    pub apigw_access_token: Option<String>,
+    /// Timeout of each request, from connecting until the response body is read.
+    /// Requests time out after `DEFAULT_TIMEOUT` when unset
+    #[serde(default)]
+    pub timeout: Option<Duration>,
+    /// Timeout of connecting to OpenWhisk
+    #[serde(default)]
+    pub connect_timeout: Option<Duration>,
+    /// Proxies requests are sent through, the proxies of the environment when unset
+    #[serde(default)]
+    pub proxy: Option<ProxySettings>,
+    /// Policy of retrying requests which failed with a transient error
+    #[serde(skip)]
+    pub retry_policy: RetryPolicy,
+    /// Provider of the headers authenticating requests, basic auth of `auth_token` when unset
+    #[serde(skip)]
+    pub auth_provider: Option<Arc<dyn AuthProvider>>,
 }
 
 fn default() -> String {
@@ -50,6 +97,11 @@ pub struct Context {
     password: String,
     /// Version
     version: String,
+    /// Access token for the API Gateway
+    apigw_access_token: Option<String>,
+    /// Toggle set when requests are authenticated by an auth provider instead of the auth token
+    #[serde(default)]
+    auth_provider: bool,
 }
 
 impl WskProperties {
@@ -174,6 +226,224 @@ impl WskProperties {
 
         self
     }
+
+    /// To set the client certificate and private key used for mutual TLS.
+    /// The key must be in PKCS#8 PEM format
+    ///
+    /// # Arguments
+    /// * `cert`   - Path to the PEM client certificate
+    /// * `key`    - Path to the PEM private key of the certificate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).set_client_certificate("/path/to/cert.pem".to_string(), "/path/to/key.pem".to_string());
+    ///
+    /// ```
+    pub fn set_client_certificate(mut self, cert: String, key: String) -> Self {
+        self.cert = Some(cert);
+        self.key = Some(key);
+
+        self
+    }
+
+    /// To add a PEM bundle of root certificates to trust, such as the CA of a private deployment
+    ///
+    /// # Arguments
+    /// * `path`   - Path to the PEM bundle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).add_ca_certificate("/path/to/ca.pem".to_string());
+    ///
+    /// ```
+    pub fn add_ca_certificate(mut self, path: String) -> Self {
+        self.ca_certs.push(path);
+
+        self
+    }
+
+    /// To set the minimum TLS version accepted
+    ///
+    /// # Arguments
+    /// * `version` - Minimum TLS version
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{TlsVersion, WskProperties};
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).set_min_tls_version(TlsVersion::Tls1_2);
+    ///
+    /// ```
+    pub fn set_min_tls_version(mut self, version: TlsVersion) -> Self {
+        self.min_tls_version = Some(version);
+
+        self
+    }
+
+    /// To set the access token used for API Gateway requests, sent in the `apigw-access-token` header
+    ///
+    /// # Arguments
+    /// * `token`   - API Gateway access token
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).set_apigw_access_token("token".to_string());
+    ///
+    /// ```
+    pub fn set_apigw_access_token(mut self, token: String) -> Self {
+        self.apigw_access_token = Some(token);
+
+        self
+    }
+
+    /// To set the timeout of each request, from connecting until the response body is read.
+    /// Blocking invokes wait up to 60 seconds for the action, the timeout of a single call
+    /// can be set with `OpenwhiskClient::with_timeout`.
+    ///
+    /// There is no separate read timeout, the http client does not support one, so a
+    /// response which stalls while streaming its body is only cut off by this timeout
+    ///
+    /// # Arguments
+    /// * `timeout` - Timeout of each request
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    /// use std::time::Duration;
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).set_timeout(Duration::from_secs(10));
+    ///
+    /// ```
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// To set the timeout of connecting to OpenWhisk
+    ///
+    /// # Arguments
+    /// * `timeout` - Timeout of connecting
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    /// use std::time::Duration;
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).set_connect_timeout(Duration::from_secs(2));
+    ///
+    /// ```
+    pub fn set_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+
+        self
+    }
+
+    /// To set the proxies requests are sent through
+    ///
+    /// # Arguments
+    /// * `proxy` - Proxies and hosts reached without proxy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{ProxySettings, WskProperties};
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).set_proxy(ProxySettings::new().set_all("http://proxy:3128").add_no_proxy("localhost"));
+    ///
+    /// ```
+    pub fn set_proxy(mut self, proxy: ProxySettings) -> Self {
+        self.proxy = Some(proxy);
+
+        self
+    }
+
+    /// To set the policy of retrying requests which failed with a transient error
+    ///
+    /// # Arguments
+    /// * `retry_policy` - Attempts, backoff and retryable statuses and methods
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{RetryPolicy, WskProperties};
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).set_retry_policy(RetryPolicy::new().set_max_attempts(5));
+    ///
+    /// ```
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+
+        self
+    }
+
+    /// To set the provider of the headers authenticating requests, such as bearer tokens
+    /// or refreshing IAM tokens, in place of basic auth with the auth token
+    ///
+    /// # Arguments
+    /// * `provider` - Provider of authentication headers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{BearerToken, WskProperties};
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "".to_string(),
+    /// "host".to_string(),
+    /// "namespace".to_string()
+    /// ).set_auth_provider(BearerToken::new("token"));
+    ///
+    /// ```
+    pub fn set_auth_provider<A: AuthProvider + 'static>(mut self, provider: A) -> Self {
+        self.auth_provider = Some(Arc::new(provider));
+
+        self
+    }
 }
 
 /// Trait OpenWhisk
@@ -181,6 +451,27 @@ pub trait OpenWhisk {
     type Output;
     /// Creates a new OpenWhisk client
     fn new_whisk_client(insecure: Option<bool>) -> Self::Output;
+
+    /// Creates a new OpenWhisk client configured by the properties.
+    /// Clients without further settings only use the connection type
+    ///
+    /// # Arguments
+    /// * `properties` - OpenWhisk properties
+    fn from_properties(properties: &WskProperties) -> Result<Self::Output, OpenWhiskError> {
+        Ok(Self::new_whisk_client(Some(properties.insecure)))
+    }
+
+    /// Returns a copy of the client whose requests time out after the given duration.
+    /// Clients which can not time out requests return an unchanged copy
+    ///
+    /// # Arguments
+    /// * `timeout` - Timeout of each request
+    fn with_timeout(&self, _timeout: Duration) -> Self::Output
+    where
+        Self: Clone + Into<Self::Output>,
+    {
+        self.clone().into()
+    }
 }
 
 impl Context {
@@ -197,7 +488,8 @@ impl Context {
                 None => "test:test".to_string(),
             }
         };
-        let auth: Vec<&str> = api_key.split(':').collect();
+        // Tokens of clients authenticating with a provider may hold no password
+        let (username, password) = api_key.split_once(':').unwrap_or((&api_key, ""));
         let host = if env::var("__OW_API_HOST").is_ok() {
             env::var("__OW_API_HOST").unwrap()
         } else {
@@ -225,13 +517,25 @@ impl Context {
             None => "v1".to_string(),
         };
 
+        let apigw_access_token = match wskprops {
+            Some(config) => config.apigw_access_token.clone(),
+            None => None,
+        };
+
+        let auth_provider = match wskprops {
+            Some(config) => config.auth_provider.is_some(),
+            None => false,
+        };
+
         Context {
             host,
             namespace,
             insecure: connection_type,
-            username: auth[0].to_string(),
-            password: auth[1].to_string(),
+            username: username.to_string(),
+            password: password.to_string(),
             version,
+            apigw_access_token,
+            auth_provider,
         }
     }
 
@@ -250,12 +554,48 @@ impl Context {
         (&self.username, &self.password)
     }
 
+    /// Returns true when requests are authenticated by an auth provider,
+    /// the username and password of the auth token are not the credentials in use
+    pub fn has_auth_provider(&self) -> bool {
+        self.auth_provider
+    }
+
     /// Returns host
     pub fn host(&self) -> &str {
         &self.host
     }
+
+    /// Returns API Gateway access token
+    pub fn apigw_access_token(&self) -> Option<&str> {
+        self.apigw_access_token.as_deref()
+    }
 }
 
-pub fn whisk_errors(code: StatusCode, message: String) -> String {
-    format!(": Error -> [ Status :{}, Message : {} ]", code, message)
+/// Creates the OpenWhiskError for an unsuccessful response
+///
+/// # Arguments
+/// * `code` - Status code of the response
+/// * `body` - Raw body of the response
+pub fn whisk_errors(code: StatusCode, body: &[u8]) -> OpenWhiskError {
+    let error = match serde_json::from_slice::<WhiskError>(body) {
+        Ok(error) if !error.error.is_empty() => error,
+        Ok(error) => WhiskError {
+            error: String::from_utf8_lossy(body).to_string(),
+            ..error
+        },
+        Err(_) => WhiskError {
+            error: String::from_utf8_lossy(body).to_string(),
+            ..Default::default()
+        },
+    };
+
+    OpenWhiskError::from_status(
+        code.as_u16(),
+        ApiError {
+            status: code.as_u16(),
+            code: error.code,
+            message: error.error,
+            activation_id: error.activation_id,
+        },
+    )
 }
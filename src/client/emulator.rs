@@ -0,0 +1,1229 @@
+use super::common::{OpenWhisk, WskProperties};
+use super::executor::{developer_error, execute, success, LocalActions, LocalActivation};
+use crate::api::{
+    Action, Activation, ActivationResponse, AsyncService, Exec, HttpMethods, HttpResponse,
+    KeyValue, OpenWhiskError, Package, PackageAction, Rule, RuleResponse, Service, Trigger,
+    ACTIVATION_ID_HEADER, MAX_LIST_LIMIT,
+};
+use http::header::{HeaderValue, CONTENT_TYPE};
+use http::HeaderMap;
+use percent_encoding::percent_decode_str;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::future::{ready, Future};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Namespace of the emulator when the properties do not set one
+const DEFAULT_NAMESPACE: &str = "guest";
+/// Number of entities returned by list requests without limit, as OpenWhisk does
+const DEFAULT_LIST_LIMIT: i64 = 30;
+/// Number of nested sequences an invoke may walk before failing
+const MAX_SEQUENCE_DEPTH: usize = 50;
+
+/// Entities of a collection keyed by namespace and `[package/]name`
+type Store<T> = BTreeMap<(String, String), T>;
+
+/// Result of handling a request
+type Handled = Result<HttpResponse, Rejection>;
+
+/// Failure of a request, answered with its status and the `{"error"}` body of OpenWhisk
+#[derive(Debug)]
+struct Rejection {
+    status: u16,
+    message: String,
+}
+
+/// In-process OpenWhisk backend which stores actions, triggers, rules, packages and activations
+/// in memory, so code built on `OpenwhiskClient` can be tested without a server or http.
+///
+/// Requests are answered with the statuses of OpenWhisk: inserting an existing entity without
+/// overwrite fails with 409, missing entities with 404 and rules must reference an existing
+/// trigger and action. Invokes and fires run the Rust closures registered for the actions with
+/// `register_action` and record their activations, actions without closure echo their parameters.
+/// Sequences pass the result through each component.
+/// Web actions and the API Gateway are not emulated and answer with 404.
+///
+/// Clones share the stored entities, so the `client` of an `OpenwhiskClient` can be inspected
+/// after the calls made through its services.
+///
+/// # Example
+/// ```
+/// use openwhisk_rust::{Action, EmulatorClient, Exec, OpenwhiskClient, Runtime};
+///
+/// let client = OpenwhiskClient::<EmulatorClient>::try_new(None).unwrap();
+///
+/// let action = Action {
+///     name: "hello".to_string(),
+///     exec: Exec::code(Runtime::new("nodejs:20").unwrap(), "function main() {}"),
+///     ..Default::default()
+/// };
+///
+/// client.actions().insert(&action, false).unwrap();
+///
+/// assert!(client.actions().insert(&action, false).is_err());
+/// assert_eq!(client.client.actions().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmulatorClient {
+    state: Arc<Mutex<State>>,
+}
+
+/// Request handled by the emulator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmulatorRequest {
+    /// HTTP method of the request
+    pub method: HttpMethods,
+    /// Url of the request, only its path and query are read
+    pub url: String,
+    /// User name of the credentials, the subject of the activations it creates
+    pub subject: Option<String>,
+    /// Headers of the request
+    pub headers: Vec<(String, String)>,
+    /// Raw body of the request
+    pub body: Option<Vec<u8>>,
+}
+
+impl Default for EmulatorClient {
+    fn default() -> Self {
+        EmulatorClient::new()
+    }
+}
+
+impl OpenWhisk for EmulatorClient {
+    type Output = EmulatorClient;
+
+    /// Creates an empty emulator whose default namespace is `guest`
+    ///
+    /// # Arguments
+    /// * `insecure` - Ignored, the emulator makes no connection
+    fn new_whisk_client(_insecure: Option<bool>) -> Self::Output {
+        EmulatorClient::new()
+    }
+
+    /// Creates an empty emulator whose default namespace is the namespace of the properties
+    ///
+    /// # Arguments
+    /// * `properties` - OpenWhisk properties
+    fn from_properties(properties: &WskProperties) -> Result<Self::Output, OpenWhiskError> {
+        match properties.namespace.as_str() {
+            "" | "_" => Ok(EmulatorClient::new()),
+            namespace => Ok(EmulatorClient::with_namespace(namespace)),
+        }
+    }
+}
+
+impl EmulatorClient {
+    /// Creates an empty emulator whose default namespace is `guest`
+    pub fn new() -> Self {
+        EmulatorClient::with_namespace(DEFAULT_NAMESPACE)
+    }
+
+    /// Creates an empty emulator with the namespace `_` stands for
+    ///
+    /// # Arguments
+    /// * `namespace` - Default namespace
+    pub fn with_namespace(namespace: &str) -> Self {
+        EmulatorClient {
+            state: Arc::new(Mutex::new(State {
+                default_namespace: namespace.to_string(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Returns the stored actions, ordered by namespace and `[package/]name`
+    pub fn actions(&self) -> Vec<Action> {
+        self.state().actions.values().cloned().collect()
+    }
+
+    /// Returns the stored triggers, ordered by namespace and name
+    pub fn triggers(&self) -> Vec<Trigger> {
+        self.state().triggers.values().cloned().collect()
+    }
+
+    /// Returns the stored rules, ordered by namespace and name
+    pub fn rules(&self) -> Vec<RuleResponse> {
+        self.state().rules.values().cloned().collect()
+    }
+
+    /// Returns the stored packages, ordered by namespace and name
+    pub fn packages(&self) -> Vec<Package> {
+        self.state().packages.values().cloned().collect()
+    }
+
+    /// Returns the recorded activations, oldest first
+    pub fn activations(&self) -> Vec<Activation> {
+        self.state().activations.clone()
+    }
+
+    /// Registers the closure run when an action is invoked, directly, by a rule or in a sequence.
+    /// The action itself must still be inserted, actions without closure echo their parameters.
+    /// Closures run outside the lock of the emulator and may use it, like firing other triggers
+    ///
+    /// # Arguments
+    /// * `action_name` - Name of the action, like `hello`, `package/hello` or `/namespace/package/hello`
+    /// * `action`      - Closure taking the parameters and returning the result, or its `error`
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{EmulatorClient, LocalActivation, OpenwhiskClient};
+    /// use serde_json::{json, Value};
+    ///
+    /// let client = OpenwhiskClient::<EmulatorClient>::try_new(None).unwrap();
+    ///
+    /// client
+    ///     .client
+    ///     .register_action("hello", |params: Value, activation: &mut LocalActivation| {
+    ///         activation.log("greeting");
+    ///         Ok(json!({ "greeting": format!("Hello {}", params["name"]) }))
+    ///     });
+    /// ```
+    pub fn register_action<F>(&self, action_name: &str, action: F)
+    where
+        F: Fn(Value, &mut LocalActivation) -> Result<Value, Value> + Send + Sync + 'static,
+    {
+        let mut state = self.state();
+        let key = qualified_key(&state.default_namespace, action_name);
+
+        state.local_actions.insert(key, Arc::new(action));
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // A panic while handling a request leaves the entities usable
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Routes a request to its collection and returns the response of OpenWhisk
+    fn handle(&self, request: &EmulatorRequest) -> HttpResponse {
+        let (path, query) = split_url(&request.url);
+        let segments: Vec<String> = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| percent_decode_str(segment).decode_utf8_lossy().to_string())
+            .collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+        let result = match segments.as_slice() {
+            ["api", "v1", "namespaces"] => match request.method {
+                HttpMethods::GET => Ok(json_response(200, &json!(self.state().namespaces()))),
+                _ => Err(method_not_allowed()),
+            },
+            ["api", "v1", "namespaces", namespace, collection, path @ ..] => {
+                let call = Call {
+                    method: request.method,
+                    namespace: self.state().resolve_namespace(namespace),
+                    path: path.iter().map(|segment| segment.to_string()).collect(),
+                    query,
+                    subject: request.subject.as_deref(),
+                    body: request.body.as_deref(),
+                };
+
+                match (*collection, call.method) {
+                    ("actions", HttpMethods::POST) if !call.path.is_empty() => {
+                        self.invoke_action(&call)
+                    }
+                    ("triggers", HttpMethods::POST) if !call.path.is_empty() => {
+                        self.fire_trigger(&call)
+                    }
+                    (collection, _) => self.state().collection(collection, &call),
+                }
+            }
+            _ => Err(not_found()),
+        };
+
+        result.unwrap_or_else(|rejection| {
+            json_response(rejection.status, &json!({ "error": rejection.message }))
+        })
+    }
+
+    fn invoke_action(&self, call: &Call) -> Handled {
+        let key = (call.namespace.clone(), call.entity_name());
+
+        if !self.state().actions.contains_key(&key) {
+            return Err(not_found());
+        }
+
+        let activation = self.run(&key, call.body()?, call.subject, 0);
+        let activation_id = activation.activation_id.clone();
+
+        if !call.query_flag("blocking") {
+            let response = json_response(202, &json!({ "activationId": activation_id }));
+
+            return Ok(with_activation_id(response, &activation_id));
+        }
+
+        let status = if activation.response.success {
+            200
+        } else {
+            502
+        };
+        let body = if call.query_flag("result") {
+            activation.response.result
+        } else {
+            to_json(&activation)?
+        };
+
+        Ok(with_activation_id(
+            json_response(status, &body),
+            &activation_id,
+        ))
+    }
+
+    /// Fires a trigger, invoking the actions of its active rules.
+    /// Triggers without active rules create no activation and answer with 204
+    fn fire_trigger(&self, call: &Call) -> Handled {
+        let key = (call.namespace.clone(), call.entity_name());
+
+        let (mut run, rules, params) = {
+            let mut state = self.state();
+            let trigger = get(&state.triggers, &key)?.clone();
+
+            let rules: Vec<(String, (String, String))> = state
+                .rules
+                .iter()
+                .filter(|(_, rule)| rule.status == "active" && entity_key(&rule.trigger) == key)
+                .map(|((namespace, name), rule)| {
+                    (format!("{}/{}", namespace, name), entity_key(&rule.action))
+                })
+                .collect();
+
+            if rules.is_empty() {
+                return Ok(HttpResponse {
+                    status: 204,
+                    ..Default::default()
+                });
+            }
+
+            let mut params = Map::new();
+            extend_params(&mut params, &trigger.parameters);
+            params.extend(call.body()?);
+
+            let run = state.start(&key, &trigger.version, call.subject);
+
+            (run, rules, params)
+        };
+
+        for (rule, action) in rules {
+            let activation = self.run(&action, params.clone(), call.subject, 0);
+
+            run.logs.push(
+                json!({
+                    "statusCode": activation.status_code,
+                    "success": activation.response.success,
+                    "activationId": activation.activation_id,
+                    "rule": rule,
+                    "action": format!("{}/{}", action.0, action.1),
+                })
+                .to_string(),
+            );
+        }
+
+        let activation = self.record(run.finish(success(Value::Object(params))));
+        let response = json_response(202, &json!({ "activationId": activation.activation_id }));
+
+        Ok(with_activation_id(response, &activation.activation_id))
+    }
+
+    /// Runs an action with the parameters of its package and its own, overridden by the payload,
+    /// and records its activation. Sequences run their components in order, passing the result
+    /// of each one as the parameters of the next
+    ///
+    /// # Arguments
+    /// * `key`     - Namespace and `[package/]name` of the action
+    /// * `payload` - Parameters of the invoke
+    /// * `subject` - Subject of the activation
+    /// * `depth`   - Number of sequences the action runs in
+    fn run(
+        &self,
+        key: &(String, String),
+        payload: Map<String, Value>,
+        subject: Option<&str>,
+        depth: usize,
+    ) -> Activation {
+        let (action, params, local_action, mut run) = {
+            let mut state = self.state();
+            let action = state.actions.get(key).cloned();
+
+            let mut params = Map::new();
+            if let Some((package, _)) = key.1.rsplit_once('/') {
+                if let Some(package) = state.packages.get(&(key.0.clone(), package.to_string())) {
+                    extend_params(&mut params, &package.parameters);
+                }
+            }
+            if let Some(action) = &action {
+                extend_params(&mut params, &action.parameters);
+            }
+            params.extend(payload);
+
+            let local_action = state.local_actions.get(key).cloned();
+            let version = action.as_ref().map(|action| action.version.clone());
+            let run = state.start(key, &version.unwrap_or_default(), subject);
+
+            (action, params, local_action, run)
+        };
+
+        // The lock is released while actions run, so they may call the emulator
+        let response = match action.map(|action| action.exec) {
+            None => developer_error("The requested resource does not exist."),
+            Some(Exec::Sequence(_)) if depth >= MAX_SEQUENCE_DEPTH => {
+                developer_error("Too many nested sequences.")
+            }
+            Some(Exec::Sequence(exec)) => {
+                let mut response = success(Value::Object(params));
+
+                for component in exec.components.iter() {
+                    let input = match &response.result {
+                        Value::Object(result) => result.clone(),
+                        _ => Map::new(),
+                    };
+                    let component_key = qualified_key(&key.0, component);
+                    let activation = self.run(&component_key, input, subject, depth + 1);
+
+                    run.logs.push(activation.activation_id);
+                    response = activation.response;
+
+                    if !response.success {
+                        break;
+                    }
+                }
+
+                response
+            }
+            Some(_) => match local_action {
+                Some(local_action) => {
+                    let action_name = format!("/{}/{}", key.0, key.1);
+                    let (response, logs) =
+                        execute(&local_action, action_name, &run.activation_id, params);
+
+                    run.logs.extend(logs);
+                    response
+                }
+                None => success(Value::Object(params)),
+            },
+        };
+
+        self.record(run.finish(response))
+    }
+
+    /// Records an activation and returns it
+    fn record(&self, activation: Activation) -> Activation {
+        self.state().activations.push(activation.clone());
+
+        activation
+    }
+
+    fn request(
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> EmulatorRequest {
+        EmulatorRequest {
+            method,
+            url: url.to_string(),
+            subject: user_auth.map(|(user, _)| user.to_string()),
+            headers: headers.to_vec(),
+            body,
+        }
+    }
+}
+
+impl Service for EmulatorClient {
+    type Output = EmulatorRequest;
+
+    /// Creates a request with a json body
+    ///
+    /// # Arguments
+    /// * `method`    - HTTPMethod of the request
+    /// * `url`       - Url of the endpoint
+    /// * `user_auth` - Credentials, the user name is the subject of activations
+    /// * `body`      - Json body of the request
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let method = match method {
+            Some(method) => method,
+            None => {
+                return Err(OpenWhiskError::Request(
+                    "Falied to create request".to_string(),
+                ))
+            }
+        };
+
+        let body = match body.map(|body| serde_json::to_vec(&body)).transpose() {
+            Ok(body) => body,
+            Err(error) => {
+                return Err(OpenWhiskError::Serialize(format!(
+                    "Failed to serialize body {}",
+                    error
+                )))
+            }
+        };
+
+        let headers = [("Content-Type".to_string(), "application/json".to_string())];
+
+        Ok(EmulatorClient::request(
+            method, url, user_auth, &headers, body,
+        ))
+    }
+
+    /// Creates a request with custom headers and a raw body
+    ///
+    /// # Arguments
+    /// * `method`    - HTTPMethod of the request
+    /// * `url`       - Url of the endpoint
+    /// * `user_auth` - Credentials, the user name is the subject of activations
+    /// * `headers`   - Headers of the request
+    /// * `body`      - Raw body of the request
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Ok(EmulatorClient::request(
+            method, url, user_auth, headers, body,
+        ))
+    }
+
+    /// Handles the request against the stored entities
+    fn invoke_raw_request(&self, request: Self::Output) -> Result<HttpResponse, OpenWhiskError> {
+        Ok(self.handle(&request))
+    }
+}
+
+impl AsyncService for EmulatorClient {
+    type Output = EmulatorRequest;
+
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Service::new_request(self, method, url, user_auth, body)
+    }
+
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Service::new_raw_request(self, method, url, user_auth, headers, body)
+    }
+
+    fn invoke_raw_request(
+        &self,
+        request: Self::Output,
+    ) -> impl Future<Output = Result<HttpResponse, OpenWhiskError>> + Send {
+        ready(Service::invoke_raw_request(self, request))
+    }
+}
+
+/// Entities and activations of the emulator
+#[derive(Debug, Default)]
+struct State {
+    /// Namespace `_` stands for
+    default_namespace: String,
+    actions: Store<Action>,
+    triggers: Store<Trigger>,
+    rules: Store<RuleResponse>,
+    packages: Store<Package>,
+    /// Closures run as the code of actions
+    local_actions: LocalActions,
+    /// Activations, oldest first
+    activations: Vec<Activation>,
+    /// Number of activations created, used to generate their ids
+    activation_count: u64,
+}
+
+/// Activation being run, recorded once its response is known
+struct Run {
+    key: (String, String),
+    version: String,
+    subject: String,
+    activation_id: String,
+    /// Start time in milliseconds since epoch
+    start: i64,
+    started: Instant,
+    logs: Vec<String>,
+}
+
+impl Run {
+    /// Returns the activation of the run, ending now
+    fn finish(self, response: ActivationResponse) -> Activation {
+        let duration = self.started.elapsed().as_millis() as i64;
+        let name = self
+            .key
+            .1
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        Activation {
+            namespace: self.key.0.clone(),
+            name,
+            version: self.version,
+            subject: self.subject,
+            activation_id: self.activation_id,
+            start: self.start,
+            end: self.start + duration,
+            duration,
+            status_code: response.status_code,
+            response,
+            logs: self.logs,
+            annotations: vec![KeyValue {
+                key: "path".to_string(),
+                value: json!(format!("{}/{}", self.key.0, self.key.1)),
+            }],
+            publish: false,
+        }
+    }
+}
+
+/// Parsed request the collections are handled with
+struct Call<'a> {
+    method: HttpMethods,
+    namespace: String,
+    /// Path segments following the collection
+    path: Vec<String>,
+    query: HashMap<String, String>,
+    subject: Option<&'a str>,
+    body: Option<&'a [u8]>,
+}
+
+impl Call<'_> {
+    /// Returns the `[package/]name` of the entity the request refers to
+    fn entity_name(&self) -> String {
+        self.path.join("/")
+    }
+
+    fn query_flag(&self, name: &str) -> bool {
+        self.query.get(name).map(String::as_str) == Some("true")
+    }
+
+    fn query_number(&self, name: &str) -> Result<Option<i64>, Rejection> {
+        match self.query.get(name) {
+            Some(value) => match value.parse() {
+                Ok(number) => Ok(Some(number)),
+                Err(_) => Err(bad_request(&format!("Invalid {} {}", name, value))),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the json object of the body, an empty object when there is no body
+    fn body(&self) -> Result<Map<String, Value>, Rejection> {
+        let body = match self.body {
+            Some(body) if !body.iter().all(u8::is_ascii_whitespace) => body,
+            _ => return Ok(Map::new()),
+        };
+
+        match serde_json::from_slice(body) {
+            Ok(Value::Object(body)) => Ok(body),
+            _ => Err(bad_request("The request content was malformed.")),
+        }
+    }
+
+    /// Returns the entities of a page, set by the `limit` and `skip` query parameters
+    fn page<'a, T: 'a + Serialize>(&self, entities: impl Iterator<Item = &'a T>) -> Handled {
+        let limit = match self.query_number("limit")? {
+            Some(limit) if limit <= 0 || limit > MAX_LIST_LIMIT => MAX_LIST_LIMIT,
+            Some(limit) => limit,
+            None => DEFAULT_LIST_LIMIT,
+        };
+        let skip = self.query_number("skip")?.unwrap_or_default().max(0);
+
+        let page: Vec<&T> = entities.skip(skip as usize).take(limit as usize).collect();
+
+        Ok(json_response(200, &to_json(&page)?))
+    }
+}
+
+impl State {
+    /// Handles a request made to one of the collections of a namespace
+    fn collection(&mut self, collection: &str, call: &Call) -> Handled {
+        match collection {
+            "actions" => self.actions(call),
+            "triggers" => self.triggers(call),
+            "rules" => self.rules(call),
+            "packages" => self.packages(call),
+            "activations" => self.activations(call),
+            _ => Err(not_found()),
+        }
+    }
+
+    fn resolve_namespace(&self, namespace: &str) -> String {
+        match namespace {
+            "_" => self.default_namespace.clone(),
+            namespace => namespace.to_string(),
+        }
+    }
+
+    /// Returns the default namespace and the namespaces holding entities
+    fn namespaces(&self) -> BTreeSet<String> {
+        let mut namespaces = BTreeSet::from([self.default_namespace.clone()]);
+
+        namespaces.extend(self.actions.keys().map(|(namespace, _)| namespace.clone()));
+        namespaces.extend(self.triggers.keys().map(|(namespace, _)| namespace.clone()));
+        namespaces.extend(self.rules.keys().map(|(namespace, _)| namespace.clone()));
+        namespaces.extend(self.packages.keys().map(|(namespace, _)| namespace.clone()));
+
+        namespaces
+    }
+
+    fn actions(&mut self, call: &Call) -> Handled {
+        if call.path.is_empty() {
+            return match call.method {
+                HttpMethods::GET => call.page(in_namespace(&self.actions, &call.namespace)),
+                _ => Err(method_not_allowed()),
+            };
+        }
+
+        let key = (call.namespace.clone(), call.entity_name());
+
+        match call.method {
+            HttpMethods::GET => Ok(json_response(200, &to_json(get(&self.actions, &key)?)?)),
+            HttpMethods::PUT => self.put_action(call, key),
+            HttpMethods::DELETE => Ok(json_response(
+                200,
+                &to_json(&remove(&mut self.actions, &key)?)?,
+            )),
+            _ => Err(method_not_allowed()),
+        }
+    }
+
+    fn put_action(&mut self, call: &Call, key: (String, String)) -> Handled {
+        // Actions in a package live in the namespace `namespace/package`
+        let (namespace, name) = match key.1.rsplit_once('/') {
+            Some((package, name)) => {
+                get(&self.packages, &(key.0.clone(), package.to_string()))?;
+                (format!("{}/{}", key.0, package), name.to_string())
+            }
+            None => (key.0.clone(), key.1.clone()),
+        };
+
+        let action: Action = merge(&self.actions, &key, call, &namespace, &name)?;
+
+        if let Exec::Sequence(exec) = &action.exec {
+            for component in exec.components.iter() {
+                if !self.actions.contains_key(&qualified_key(&key.0, component)) {
+                    return Err(bad_request(&format!(
+                        "Sequence component {} does not exist.",
+                        component
+                    )));
+                }
+            }
+        }
+
+        self.actions.insert(key, action.clone());
+
+        Ok(json_response(200, &to_json(&action)?))
+    }
+
+    /// Starts the activation of an action or trigger
+    fn start(&mut self, key: &(String, String), version: &str, subject: Option<&str>) -> Run {
+        self.activation_count += 1;
+
+        Run {
+            key: key.clone(),
+            version: version.to_string(),
+            subject: subject.unwrap_or(&key.0).to_string(),
+            activation_id: format!("{:032x}", self.activation_count),
+            start: now(),
+            started: Instant::now(),
+            logs: Vec::new(),
+        }
+    }
+
+    fn triggers(&mut self, call: &Call) -> Handled {
+        if call.path.is_empty() {
+            return match call.method {
+                HttpMethods::GET => call.page(in_namespace(&self.triggers, &call.namespace)),
+                _ => Err(method_not_allowed()),
+            };
+        }
+
+        let key = (call.namespace.clone(), call.entity_name());
+
+        match call.method {
+            HttpMethods::GET => Ok(json_response(200, &to_json(get(&self.triggers, &key)?)?)),
+            HttpMethods::PUT => {
+                let trigger: Trigger = merge(&self.triggers, &key, call, &key.0, &key.1)?;
+                self.triggers.insert(key, trigger.clone());
+
+                Ok(json_response(200, &to_json(&trigger)?))
+            }
+            HttpMethods::DELETE => Ok(json_response(
+                200,
+                &to_json(&remove(&mut self.triggers, &key)?)?,
+            )),
+            _ => Err(method_not_allowed()),
+        }
+    }
+
+    fn rules(&mut self, call: &Call) -> Handled {
+        if call.path.is_empty() {
+            return match call.method {
+                HttpMethods::GET => call.page(in_namespace(&self.rules, &call.namespace)),
+                _ => Err(method_not_allowed()),
+            };
+        }
+
+        let key = (call.namespace.clone(), call.entity_name());
+
+        match call.method {
+            HttpMethods::GET => Ok(json_response(200, &to_json(get(&self.rules, &key)?)?)),
+            HttpMethods::PUT => self.put_rule(call, key),
+            HttpMethods::DELETE => Ok(json_response(
+                200,
+                &to_json(&remove(&mut self.rules, &key)?)?,
+            )),
+            HttpMethods::POST => {
+                let status = match call.body()?.get("status").and_then(Value::as_str) {
+                    Some(status) if status == "active" || status == "inactive" => {
+                        status.to_string()
+                    }
+                    _ => return Err(bad_request("Invalid rule status.")),
+                };
+
+                match self.rules.get_mut(&key) {
+                    Some(rule) => rule.status = status,
+                    None => return Err(not_found()),
+                }
+
+                Ok(HttpResponse {
+                    status: 200,
+                    ..Default::default()
+                })
+            }
+            _ => Err(method_not_allowed()),
+        }
+    }
+
+    /// Inserts a rule, failing when its trigger or action does not exist
+    fn put_rule(&mut self, call: &Call, key: (String, String)) -> Handled {
+        let existing = self.rules.get(&key);
+
+        if existing.is_some() && !call.query_flag("overwrite") {
+            return Err(conflict());
+        }
+
+        let rule: Rule = from_json(Value::Object(call.body()?))?;
+
+        let trigger = qualified_key(&key.0, &rule.trigger);
+        if !self.triggers.contains_key(&trigger) {
+            return Err(not_found_message(&format!(
+                "Trigger /{}/{} does not exist.",
+                trigger.0, trigger.1
+            )));
+        }
+
+        let action = qualified_key(&key.0, &rule.action);
+        if !self.actions.contains_key(&action) {
+            return Err(not_found_message(&format!(
+                "Action /{}/{} does not exist.",
+                action.0, action.1
+            )));
+        }
+
+        let status = match (rule.status.as_str(), existing) {
+            ("", Some(existing)) => existing.status.clone(),
+            ("", None) => "active".to_string(),
+            (status, _) => status.to_string(),
+        };
+
+        let rule = RuleResponse {
+            namespace: key.0.clone(),
+            name: key.1.clone(),
+            version: next_version(existing.map(|rule| rule.version.as_str())),
+            annotations: existing
+                .map(|rule| rule.annotations.clone())
+                .unwrap_or_default(),
+            status,
+            trigger: path_name(&trigger),
+            action: path_name(&action),
+            publish: false,
+            updated: now(),
+        };
+
+        self.rules.insert(key, rule.clone());
+
+        Ok(json_response(200, &to_json(&rule)?))
+    }
+
+    fn packages(&mut self, call: &Call) -> Handled {
+        if call.path.is_empty() {
+            return match call.method {
+                HttpMethods::GET => call.page(in_namespace(&self.packages, &call.namespace)),
+                _ => Err(method_not_allowed()),
+            };
+        }
+
+        let key = (call.namespace.clone(), call.entity_name());
+
+        match call.method {
+            HttpMethods::GET => {
+                let package = get(&self.packages, &key)?;
+
+                Ok(json_response(200, &self.package_json(&key, package)?))
+            }
+            HttpMethods::PUT => {
+                let package: Package = merge(&self.packages, &key, call, &key.0, &key.1)?;
+
+                if let Some(binding) = &package.binding {
+                    let bound = (
+                        self.resolve_namespace(&binding.namespace),
+                        binding.name.clone(),
+                    );
+
+                    if !self.packages.contains_key(&bound) {
+                        return Err(not_found_message(&format!(
+                            "Package /{}/{} does not exist.",
+                            bound.0, bound.1
+                        )));
+                    }
+                }
+
+                self.packages.insert(key.clone(), package.clone());
+
+                Ok(json_response(200, &self.package_json(&key, &package)?))
+            }
+            HttpMethods::DELETE => {
+                let prefix = format!("{}/", key.1);
+                let contained = self
+                    .actions
+                    .keys()
+                    .filter(|(namespace, name)| *namespace == key.0 && name.starts_with(&prefix))
+                    .count();
+
+                if contained > 0 {
+                    return Err(rejection(
+                        409,
+                        &format!("Package not empty (contains {} entities)", contained),
+                    ));
+                }
+
+                Ok(json_response(
+                    200,
+                    &to_json(&remove(&mut self.packages, &key)?)?,
+                ))
+            }
+            _ => Err(method_not_allowed()),
+        }
+    }
+
+    /// Returns the json of a package along with the actions it contains
+    fn package_json(&self, key: &(String, String), package: &Package) -> Result<Value, Rejection> {
+        let namespace = format!("{}/{}", key.0, key.1);
+        let actions: Vec<PackageAction> = self
+            .actions
+            .values()
+            .filter(|action| action.namespace == namespace)
+            .map(|action| PackageAction {
+                name: action.name.clone(),
+                version: action.version.clone(),
+                annotations: action.annotations.clone(),
+            })
+            .collect();
+
+        let mut value = to_json(package)?;
+        value["actions"] = to_json(&actions)?;
+
+        Ok(value)
+    }
+
+    fn activations(&mut self, call: &Call) -> Handled {
+        if call.method != HttpMethods::GET {
+            return Err(method_not_allowed());
+        }
+
+        let in_namespace = self
+            .activations
+            .iter()
+            .rev()
+            .filter(|activation| activation.namespace == call.namespace);
+
+        let path: Vec<&str> = call.path.iter().map(String::as_str).collect();
+        let activation = match path.as_slice() {
+            [] => {
+                let name = call.query.get("name");
+
+                return call.page(in_namespace.filter(|activation| match name {
+                    Some(name) => {
+                        activation.name == *name
+                            || path_annotation(activation)
+                                == Some(format!("{}/{}", call.namespace, name))
+                    }
+                    None => true,
+                }));
+            }
+            [activation_id, ..] => in_namespace
+                .clone()
+                .find(|activation| activation.activation_id == *activation_id)
+                .ok_or_else(not_found)?,
+        };
+
+        match path.as_slice() {
+            [_] => Ok(json_response(200, &to_json(activation)?)),
+            [_, "logs"] => Ok(json_response(200, &json!({ "logs": activation.logs }))),
+            [_, "result"] => Ok(json_response(200, &to_json(&activation.response)?)),
+            _ => Err(not_found()),
+        }
+    }
+}
+
+/// Entities whose version is bumped on each update
+trait Versioned {
+    fn version(&self) -> &str;
+}
+
+impl Versioned for Action {
+    fn version(&self) -> &str {
+        &self.version
+    }
+}
+
+impl Versioned for Trigger {
+    fn version(&self) -> &str {
+        &self.version
+    }
+}
+
+impl Versioned for Package {
+    fn version(&self) -> &str {
+        &self.version
+    }
+}
+
+/// Adds the `x-openwhisk-activation-id` header to a response
+fn with_activation_id(mut response: HttpResponse, activation_id: &str) -> HttpResponse {
+    if let Ok(value) = HeaderValue::from_str(activation_id) {
+        response.headers.insert(ACTIVATION_ID_HEADER, value);
+    }
+
+    response
+}
+
+/// Inserts an entity, or merges the fields of the body into the existing one when overwriting.
+/// Fails with 409 when the entity exists and the request does not overwrite it
+///
+/// # Arguments
+/// * `store`     - Entities of the collection
+/// * `key`       - Key of the entity
+/// * `call`      - Request holding the body and the `overwrite` query parameter
+/// * `namespace` - Namespace set on the entity
+/// * `name`      - Name set on the entity
+fn merge<T: Serialize + DeserializeOwned + Versioned + Clone>(
+    store: &Store<T>,
+    key: &(String, String),
+    call: &Call,
+    namespace: &str,
+    name: &str,
+) -> Result<T, Rejection> {
+    let existing = store.get(key);
+
+    if existing.is_some() && !call.query_flag("overwrite") {
+        return Err(conflict());
+    }
+
+    let mut entity = match existing {
+        Some(existing) => match to_json(existing)? {
+            Value::Object(entity) => entity,
+            _ => Map::new(),
+        },
+        None => Map::new(),
+    };
+
+    entity.extend(call.body()?);
+    entity.insert("namespace".to_string(), json!(namespace));
+    entity.insert("name".to_string(), json!(name));
+    entity.insert(
+        "version".to_string(),
+        json!(next_version(existing.map(|entity| entity.version()))),
+    );
+    entity.insert("updated".to_string(), json!(now()));
+
+    from_json(Value::Object(entity))
+}
+
+/// Returns the entities of a namespace
+fn in_namespace<'a, T>(store: &'a Store<T>, namespace: &'a str) -> impl Iterator<Item = &'a T> {
+    store
+        .iter()
+        .filter(move |((entity_namespace, _), _)| entity_namespace == namespace)
+        .map(|(_, entity)| entity)
+}
+
+fn get<'a, T>(store: &'a Store<T>, key: &(String, String)) -> Result<&'a T, Rejection> {
+    store.get(key).ok_or_else(not_found)
+}
+
+fn remove<T>(store: &mut Store<T>, key: &(String, String)) -> Result<T, Rejection> {
+    store.remove(key).ok_or_else(not_found)
+}
+
+/// Returns the key of an entity referenced by name, like `/guest/package/action/` in rules
+/// and sequences, or by `[package/]name` in the given namespace
+fn qualified_key(namespace: &str, name: &str) -> (String, String) {
+    let name = name.trim_end_matches('/');
+
+    match name.strip_prefix('/').and_then(|name| name.split_once('/')) {
+        Some((namespace, name)) => (namespace.to_string(), name.to_string()),
+        None => (
+            namespace.to_string(),
+            name.trim_start_matches('/').to_string(),
+        ),
+    }
+}
+
+/// Returns the `{"path", "name"}` reference of an entity, as rules hold their trigger and action
+fn path_name(key: &(String, String)) -> Value {
+    match key.1.rsplit_once('/') {
+        Some((package, name)) => json!({ "path": format!("{}/{}", key.0, package), "name": name }),
+        None => json!({ "path": key.0, "name": key.1 }),
+    }
+}
+
+/// Returns the key of an entity referenced as `{"path", "name"}`
+fn entity_key(reference: &Value) -> (String, String) {
+    let path = reference
+        .get("path")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let name = reference
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    match path.split_once('/') {
+        Some((namespace, package)) => (namespace.to_string(), format!("{}/{}", package, name)),
+        None => (path.to_string(), name.to_string()),
+    }
+}
+
+fn path_annotation(activation: &Activation) -> Option<String> {
+    activation
+        .annotations
+        .iter()
+        .find(|annotation| annotation.key == "path")
+        .and_then(|annotation| annotation.value.as_str())
+        .map(str::to_string)
+}
+
+fn extend_params(params: &mut Map<String, Value>, key_values: &[KeyValue]) {
+    for key_value in key_values.iter() {
+        params.insert(key_value.key.clone(), key_value.value.clone());
+    }
+}
+
+/// Returns the semantic version following the version of an entity, `0.0.1` for new entities
+fn next_version(version: Option<&str>) -> String {
+    let version = match version {
+        Some(version) => version,
+        None => return "0.0.1".to_string(),
+    };
+
+    match version.rsplit_once('.') {
+        Some((major_minor, patch)) => match patch.parse::<u64>() {
+            Ok(patch) => format!("{}.{}", major_minor, patch + 1),
+            Err(_) => version.to_string(),
+        },
+        None => version.to_string(),
+    }
+}
+
+/// Returns the path and the decoded query parameters of a url
+fn split_url(url: &str) -> (&str, HashMap<String, String>) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let path = match path.find("/api/") {
+        Some(start) => &path[start..],
+        None => path,
+    };
+
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (
+                percent_decode_str(key).decode_utf8_lossy().to_string(),
+                percent_decode_str(value).decode_utf8_lossy().to_string(),
+            )
+        })
+        .collect();
+
+    (path, query)
+}
+
+/// Returns the current time in milliseconds since epoch
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<Value, Rejection> {
+    serde_json::to_value(value).map_err(|error| rejection(500, &format!("{}", error)))
+}
+
+fn from_json<T: DeserializeOwned>(value: Value) -> Result<T, Rejection> {
+    serde_json::from_value(value)
+        .map_err(|error| bad_request(&format!("The request content was malformed: {}", error)))
+}
+
+fn json_response(status: u16, body: &Value) -> HttpResponse {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    HttpResponse {
+        status,
+        headers,
+        body: body.to_string().into_bytes(),
+    }
+}
+
+fn rejection(status: u16, message: &str) -> Rejection {
+    Rejection {
+        status,
+        message: message.to_string(),
+    }
+}
+
+fn bad_request(message: &str) -> Rejection {
+    rejection(400, message)
+}
+
+fn not_found() -> Rejection {
+    not_found_message("The requested resource does not exist.")
+}
+
+fn not_found_message(message: &str) -> Rejection {
+    rejection(404, message)
+}
+
+fn conflict() -> Rejection {
+    rejection(409, "resource already exists")
+}
+
+fn method_not_allowed() -> Rejection {
+    rejection(405, "Method not allowed.")
+}
@@ -0,0 +1,133 @@
+use crate::api::{ActivationId, ActivationResponse};
+use serde_json::{json, Map, Value};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+
+/// Rust closure run by the emulator as the code of an action.
+/// It takes the parameters of the activation and returns its result, or the `error` of the result
+pub(crate) type LocalAction =
+    Arc<dyn Fn(Value, &mut LocalActivation) -> Result<Value, Value> + Send + Sync>;
+
+/// Activation of an action run by the emulator, handed to the closure which runs the action
+#[derive(Debug, Clone)]
+pub struct LocalActivation {
+    activation_id: ActivationId,
+    action_name: String,
+    logs: Vec<String>,
+}
+
+impl LocalActivation {
+    /// Returns the id of the activation
+    pub fn activation_id(&self) -> &ActivationId {
+        &self.activation_id
+    }
+
+    /// Returns the fully qualified name of the action, like `/guest/package/action`
+    pub fn action_name(&self) -> &str {
+        &self.action_name
+    }
+
+    /// Adds a line to the logs of the activation
+    ///
+    /// # Arguments
+    /// * `line` - Log line, as the action would write it to stdout
+    pub fn log(&mut self, line: impl fmt::Display) {
+        self.logs.push(line.to_string());
+    }
+}
+
+/// Runs the closure of an action and returns the response of its activation and its logs.
+/// Results which are not json objects and panics are developer errors, as OpenWhisk reports
+/// actions which do not return a dictionary or crash
+///
+/// # Arguments
+/// * `action`        - Closure of the action
+/// * `action_name`   - Fully qualified name of the action
+/// * `activation_id` - Id of the activation
+/// * `params`        - Parameters of the activation
+pub(crate) fn execute(
+    action: &LocalAction,
+    action_name: String,
+    activation_id: &str,
+    params: Map<String, Value>,
+) -> (ActivationResponse, Vec<String>) {
+    let mut activation = LocalActivation {
+        activation_id: ActivationId::from(activation_id),
+        action_name,
+        logs: Vec::new(),
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        action(Value::Object(params), &mut activation)
+    }));
+
+    let response = match result {
+        Ok(Ok(result)) if result.is_object() => success(result),
+        Ok(Ok(_)) => developer_error("The action did not return a dictionary."),
+        Ok(Err(error)) => ActivationResponse {
+            status: "application error".to_string(),
+            status_code: 1,
+            success: false,
+            result: json!({ "error": error }),
+            size: None,
+        },
+        Err(panic) => developer_error(&format!("The action panicked: {}", panic_message(&panic))),
+    };
+
+    (response, activation.logs)
+}
+
+/// Response of a successful activation
+pub(crate) fn success(result: Value) -> ActivationResponse {
+    ActivationResponse {
+        status: "success".to_string(),
+        status_code: 0,
+        success: true,
+        result,
+        size: None,
+    }
+}
+
+/// Response of an activation which failed because of the action itself
+pub(crate) fn developer_error(error: &str) -> ActivationResponse {
+    ActivationResponse {
+        status: "action developer error".to_string(),
+        status_code: 2,
+        success: false,
+        result: json!({ "error": error }),
+        size: None,
+    }
+}
+
+fn panic_message(panic: &Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Closures of the actions run by the emulator, keyed by namespace and `[package/]name`
+#[derive(Default, Clone)]
+pub(crate) struct LocalActions(HashMap<(String, String), LocalAction>);
+
+impl LocalActions {
+    pub(crate) fn insert(&mut self, key: (String, String), action: LocalAction) {
+        self.0.insert(key, action);
+    }
+
+    pub(crate) fn get(&self, key: &(String, String)) -> Option<&LocalAction> {
+        self.0.get(key)
+    }
+}
+
+impl fmt::Debug for LocalActions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
@@ -1,14 +1,43 @@
+mod auth;
+#[cfg(feature = "testing")]
+mod cassette;
 mod common;
+#[cfg(feature = "testing")]
+mod emulator;
+#[cfg(feature = "testing")]
+mod executor;
 mod openwhisk_client;
+mod proxy;
+mod retry;
+mod tls;
+mod wskprops;
 
+pub use auth::*;
+#[cfg(feature = "testing")]
+pub use cassette::{
+    Cassette, Interaction, MatchMode, RecordedBody, RecordedRequest, RecordedResponse,
+    RecordingClient, RecordingRequest, ReplayClient,
+};
 pub use common::*;
+#[cfg(feature = "testing")]
+pub use emulator::{EmulatorClient, EmulatorRequest};
+#[cfg(feature = "testing")]
+pub use executor::LocalActivation;
 pub use openwhisk_client::OpenwhiskClient;
+pub use proxy::ProxySettings;
+pub use retry::*;
+pub use tls::TlsVersion;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod native_client;
 #[cfg(not(target_arch = "wasm32"))]
 pub use native_client::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod async_native_client;
+#[cfg(not(target_arch = "wasm32"))]
+pub use async_native_client::*;
+
 #[cfg(target_arch = "wasm32")]
 mod wasmtime_client;
 #[cfg(target_arch = "wasm32")]
@@ -1,12 +1,22 @@
-use super::common::{whisk_errors, OpenWhisk, WhiskError};
-use crate::api::{HttpMethods, Service};
-use http::StatusCode;
-use reqwest::blocking::Client;
+use super::common::{OpenWhisk, WskProperties, DEFAULT_TIMEOUT};
+use super::tls::TlsSettings;
+use super::{auth_headers, AuthProvider, RetryPolicy};
+use crate::api::{HttpMethods, HttpResponse, OpenWhiskError, Service};
+use reqwest::blocking::{Client, RequestBuilder, Response};
 use serde_json::Value;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 /// A Client to make Requests with.
 #[derive(Debug, Default)]
-pub struct NativeClient(Client);
+pub struct NativeClient {
+    client: Client,
+    retry_policy: RetryPolicy,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
+    /// Timeout of each request, overriding the timeout of the client
+    timeout: Option<Duration>,
+}
 
 impl OpenWhisk for NativeClient {
     /// NativeClient - Http Client (Here client is Reqwest Client)
@@ -16,23 +26,146 @@ impl OpenWhisk for NativeClient {
     /// # Arguments
     /// * `insecure` - Option of Bool to specify connection type
     fn new_whisk_client(insecure: Option<bool>) -> Self::Output {
-        match insecure {
-            Some(x) => match x {
-                true => NativeClient(
-                    reqwest::blocking::Client::builder()
-                        .danger_accept_invalid_certs(x)
-                        .timeout(None)
-                        .build()
-                        .unwrap(),
-                ),
-                false => NativeClient(
-                    reqwest::blocking::Client::builder()
-                        .timeout(None)
-                        .build()
-                        .unwrap(),
-                ),
-            },
-            None => todo!(),
+        NativeClient::with_client(
+            reqwest::blocking::Client::builder()
+                .danger_accept_invalid_certs(insecure.unwrap_or_default())
+                .timeout(DEFAULT_TIMEOUT)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    /// Creates New WhiskClient with the connection type, TLS settings, timeouts, proxies,
+    /// retry policy and authentication of the properties
+    ///
+    /// # Arguments
+    /// * `properties` - OpenWhisk properties
+    fn from_properties(properties: &WskProperties) -> Result<Self::Output, OpenWhiskError> {
+        let tls = TlsSettings::from_properties(properties)?;
+        let mut builder = reqwest::blocking::Client::builder()
+            .danger_accept_invalid_certs(properties.insecure)
+            .timeout(properties.timeout.unwrap_or(DEFAULT_TIMEOUT));
+
+        if let Some(connect_timeout) = properties.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(proxy) = &properties.proxy {
+            for proxy in proxy.proxies()? {
+                builder = builder.proxy(proxy);
+            }
+        }
+
+        if let Some(identity) = tls.identity {
+            builder = builder.identity(identity);
+        }
+
+        for certificate in tls.root_certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+
+        if let Some(version) = tls.min_version {
+            builder = builder.min_tls_version(version);
+        }
+
+        let client = match builder.build() {
+            Ok(client) => client,
+            Err(error) => {
+                return Err(OpenWhiskError::Configuration(format!(
+                    "Failed to create http client {}",
+                    error
+                )))
+            }
+        };
+
+        Ok(NativeClient {
+            retry_policy: properties.retry_policy.clone(),
+            auth_provider: properties.auth_provider.clone(),
+            ..NativeClient::with_client(client)
+        })
+    }
+
+    /// Returns a copy of the client whose requests time out after the given duration
+    ///
+    /// # Arguments
+    /// * `timeout` - Timeout of each request
+    fn with_timeout(&self, timeout: Duration) -> Self::Output {
+        NativeClient {
+            timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+}
+
+impl NativeClient {
+    /// Creates a client with the default retry policy and basic authentication
+    fn with_client(client: Client) -> Self {
+        NativeClient {
+            client,
+            retry_policy: RetryPolicy::default(),
+            auth_provider: None,
+            timeout: None,
+        }
+    }
+
+    /// Adds the authentication headers and the request timeout override to a request
+    fn prepare(
+        &self,
+        mut request: RequestBuilder,
+        use_auth: Option<(&str, &str)>,
+    ) -> Result<RequestBuilder, OpenWhiskError> {
+        for (key, value) in auth_headers(self.auth_provider.as_ref(), use_auth)? {
+            request = request.header(key, value);
+        }
+
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        Ok(request)
+    }
+
+    /// Sends a request, retrying transient failures as set by the retry policy
+    fn send(&self, request: RequestBuilder) -> Result<Response, OpenWhiskError> {
+        let request = match request.build() {
+            Ok(request) => request,
+            Err(error) => return Err(OpenWhiskError::Request(format!("{}", error))),
+        };
+        let method = request.method().as_str().to_string();
+        let mut attempt = 1;
+
+        loop {
+            // Requests with streamed bodies can not be cloned and are sent once
+            let mut attempt_request = match request.try_clone() {
+                Some(attempt_request) => attempt_request,
+                None => {
+                    return self
+                        .client
+                        .execute(request)
+                        .map_err(|error| OpenWhiskError::Transport(format!("{}", error)))
+                }
+            };
+            // Blocking requests lose their timeout when cloned
+            *attempt_request.timeout_mut() = request.timeout().copied();
+
+            let delay = match self.client.execute(attempt_request) {
+                Ok(response)
+                    if self
+                        .retry_policy
+                        .is_retry_status(response.status().as_u16())
+                        && self.retry_policy.can_retry(&method, attempt) =>
+                {
+                    self.retry_policy.delay(attempt, Some(response.headers()))
+                }
+                Ok(response) => return Ok(response),
+                Err(_) if self.retry_policy.can_retry(&method, attempt) => {
+                    self.retry_policy.delay(attempt, None)
+                }
+                Err(error) => return Err(OpenWhiskError::Transport(format!("{}", error))),
+            };
+
+            thread::sleep(delay);
+            attempt += 1;
         }
     }
 }
@@ -55,74 +188,96 @@ impl Service for NativeClient {
         url: &str,
         use_auth: Option<(&str, &str)>,
         body: Option<Value>,
-    ) -> Result<Self::Output, String> {
+    ) -> Result<Self::Output, OpenWhiskError> {
         let body = body.unwrap_or_else(|| serde_json::json!({}));
 
-        match use_auth {
-            Some(auth) => {
-                let user = auth.0;
-                let pass = auth.1;
-
-                match method {
-                    Some(http_method) => match http_method {
-                        HttpMethods::GET => Ok(self.0.get(url).basic_auth(user, Some(pass))),
-                        HttpMethods::POST => {
-                            Ok(self.0.post(url).basic_auth(user, Some(pass)).json(&body))
-                        }
-                        HttpMethods::PUT => {
-                            Ok(self.0.put(url).basic_auth(user, Some(pass)).json(&body))
-                        }
-                        HttpMethods::DELETE => {
-                            Ok(self.0.delete(url).basic_auth(user, Some(pass)).json(&body))
-                        }
-                    },
-                    None => Err("Falied to create request".to_string()),
-                }
+        let request = match method {
+            Some(HttpMethods::GET) => self.client.get(url),
+            Some(HttpMethods::POST) => self.client.post(url).json(&body),
+            Some(HttpMethods::PUT) => self.client.put(url).json(&body),
+            Some(HttpMethods::DELETE) => self.client.delete(url).json(&body),
+            Some(HttpMethods::PATCH) => self.client.patch(url).json(&body),
+            Some(HttpMethods::HEAD) => self.client.head(url),
+            Some(HttpMethods::OPTIONS) => self.client.request(reqwest::Method::OPTIONS, url),
+            None => {
+                return Err(OpenWhiskError::Request(
+                    "Falied to create request".to_string(),
+                ))
             }
-            None => match method {
-                Some(http_method) => match http_method {
-                    HttpMethods::GET => Ok(self.0.get(url)),
-                    HttpMethods::POST => Ok(self.0.post(url).json(&body)),
-                    HttpMethods::PUT => Ok(self.0.put(url).json(&body)),
-                    HttpMethods::DELETE => Ok(self.0.delete(url).json(&body)),
-                },
-                None => Err("Falied to create request".to_string()),
-            },
-        }
-    }
+        };
 
+        self.prepare(request, use_auth)
+    }
     ///
-    /// To invoke request and get response out of request execution
+    /// Creates New Request with custom headers and a raw body
     ///
     /// # Arguments
+    /// * `method`   - HTTPMethod of the request
+    /// * `url`      - API Host url
+    /// * `use_auth` - Option of tuple conatining Username and Password
+    /// * `headers`  - Headers to be sent with the request
+    /// * `body`     - Option of raw body of the request
+    ///
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        use_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let method = match reqwest::Method::from_bytes(method.as_str().as_bytes()) {
+            Ok(method) => method,
+            Err(error) => return Err(OpenWhiskError::Request(format!("{}", error))),
+        };
+
+        let mut request = self.client.request(method, url);
+
+        for (key, value) in headers {
+            request = request.header(key.as_str(), value.as_str());
+        }
+
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+
+        self.prepare(request, use_auth)
+    }
+
     ///
-    /// * `request` - Http request with url,auth and body
+    /// To invoke request and get the raw response out of request execution
     ///
+    /// # Arguments
     ///
+    /// * `request` - Http request with url,auth,headers and body
     ///
-    fn invoke_request(&self, request: Self::Output) -> Result<Value, String> {
-        match request.send() {
-            Ok(response) => match response.status() {
-                StatusCode::OK => Ok(response.json().unwrap_or_default()),
-                _ => {
-                    let code = response.status();
-                    let error: WhiskError = response.json().unwrap();
-
-                    Err(whisk_errors(code, error.error))
+    fn invoke_raw_request(&self, request: Self::Output) -> Result<HttpResponse, OpenWhiskError> {
+        match self.send(request) {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let headers = response.headers().clone();
+
+                match response.bytes() {
+                    Ok(body) => Ok(HttpResponse {
+                        status,
+                        headers,
+                        body: body.to_vec(),
+                    }),
+                    Err(error) => Err(OpenWhiskError::Transport(format!("{}", error))),
                 }
-            },
-            Err(error) => Err(format!("{}", error)),
+            }
+            Err(error) => Err(error),
         }
     }
 }
 
 impl Clone for NativeClient {
     fn clone(&self) -> Self {
-        NativeClient(self.0.clone())
-    }
-
-    #[allow(clippy::unnecessary_operation)]
-    fn clone_from(&mut self, _source: &Self) {
-        NativeClient(self.0.clone());
+        NativeClient {
+            client: self.client.clone(),
+            retry_policy: self.retry_policy.clone(),
+            auth_provider: self.auth_provider.clone(),
+            timeout: self.timeout,
+        }
     }
 }
@@ -2,7 +2,11 @@ use super::{
     common::{Context, WskProperties},
     OpenWhisk,
 };
-use crate::api::{ActionService, NamespaceService, RuleService, TriggerService};
+use crate::api::{
+    ActionService, ActivationService, NamespaceService, OpenWhiskError, PackageService,
+    RouteService, RuleService, TriggerService,
+};
+use std::time::Duration;
 
 /// Representation of Openwhisk Client
 #[derive(Debug, Default, Clone)]
@@ -19,6 +23,12 @@ pub struct OpenwhiskClient<T> {
     rules: RuleService<T>,
     /// namespace endpoint to access Openwhisk API
     namespaces: NamespaceService<T>,
+    /// activation endpoint to access Openwhisk API
+    activations: ActivationService<T>,
+    /// package endpoint to access Openwhisk API
+    packages: PackageService<T>,
+    /// API Gateway route endpoint to access Openwhisk API
+    routes: RouteService<T>,
 }
 
 impl<T: Clone> OpenwhiskClient<T>
@@ -29,7 +39,7 @@ where
     ///
     /// # Arguments
     /// * `config` - Can be None or Openwhisk Properties defined by User
-    ///    when None is supplied poperties are set by environment
+    ///   when None is supplied poperties are set by environment
     ///
     /// # Example
     /// ```
@@ -44,17 +54,89 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// ```
+    ///
+    /// # Panics
+    /// When the http client can not be created from the properties, use `try_new` to handle the error
+    #[deprecated(
+        since = "0.2.0",
+        note = "panics when the http client can not be created from the properties, use try_new"
+    )]
     pub fn new(config: Option<&WskProperties>) -> Self {
+        match Self::try_new(config) {
+            Ok(client) => client,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// To set Openwhisk config for library to interact with Openwhisk API's,
+    /// returning an error when the http client can not be created from the properties
+    ///
+    /// # Arguments
+    /// * `config` - Can be None or Openwhisk Properties defined by User
+    ///   when None is supplied poperties are set by environment
+    pub fn try_new(config: Option<&WskProperties>) -> Result<Self, OpenWhiskError> {
         let context = Context::new(config);
-        let client = T::new_whisk_client(Some(context.is_secure()));
+        let client = match config {
+            Some(config) => T::from_properties(config)?,
+            None => T::new_whisk_client(Some(context.is_secure())),
+        };
+
+        Ok(Self::with_client(client, context))
+    }
+
+    /// To set Openwhisk config for library to interact with Openwhisk API's through
+    /// an http client created by the caller, like a `ReplayClient` loaded from a cassette
+    ///
+    /// # Arguments
+    /// * `client` - Http client sending the requests
+    /// * `config` - Can be None or Openwhisk Properties defined by User
+    ///   when None is supplied poperties are set by environment
+    pub fn from_client(client: T, config: Option<&WskProperties>) -> Self {
+        Self::with_client(client, Context::new(config))
+    }
+
+    /// Returns a copy of the client whose requests time out after the given duration,
+    /// overriding the timeout set in the properties. Useful to give blocking invokes
+    /// the time they need while other calls fail fast
+    ///
+    /// # Arguments
+    /// * `timeout` - Timeout of each request
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// use std::time::Duration;
+    ///
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         "namespace".to_string()
+    ///  ).set_timeout(Duration::from_secs(5));
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    ///
+    /// let result = client
+    ///     .with_timeout(Duration::from_secs(65))
+    ///     .actions()
+    ///     .invoke("action_name", serde_json::json!({}), true, true)
+    ///     .unwrap();
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self::with_client(self.client.with_timeout(timeout), self.context.clone())
+    }
+
+    fn with_client(client: T, context: Context) -> Self {
         let actions = ActionService::new(client.clone(), context.clone());
         let triggers = TriggerService::new(client.clone(), context.clone());
         let rules = RuleService::new(client.clone(), context.clone());
         let namespaces = NamespaceService::new(client.clone(), context.clone());
+        let activations = ActivationService::new(client.clone(), context.clone());
+        let packages = PackageService::new(client.clone(), context.clone());
+        let routes = RouteService::new(client.clone(), context.clone());
         Self {
             client,
             context,
@@ -62,6 +144,9 @@ where
             triggers,
             rules,
             namespaces,
+            activations,
+            packages,
+            routes,
         }
     }
 
@@ -86,7 +171,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // Lists the actions deployed in the openwhisk
@@ -109,7 +194,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // get the action properties which is deployed in the openwhisk
@@ -132,7 +217,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // deletes the action  which is deployed in the openwhisk
@@ -155,7 +240,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // insert the action and deploys in the openwhisk
@@ -178,7 +263,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // invoke the action deployed in the openwhisk
@@ -209,7 +294,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // Lists the triggers deployed in the openwhisk
@@ -232,7 +317,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // get the trigger properties which is deployed in the openwhisk
@@ -255,7 +340,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // deletes the trigger  which is deployed in the openwhisk
@@ -278,7 +363,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // insert the trigger and deploys in the openwhisk
@@ -302,14 +387,13 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // Fires a trigger to an action
     /// let trigger = client.actions().fire("trigger_name",value).unwrap();
     ///
     /// ```
-
     pub fn triggers(&self) -> &TriggerService<T> {
         &self.triggers
     }
@@ -334,7 +418,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // Lists the rules deployed in the openwhisk
@@ -357,7 +441,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // get the rule properties which is deployed in the openwhisk
@@ -380,7 +464,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // deletes the rule  which is deployed in the openwhisk
@@ -404,7 +488,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // set state for the rule deployed in the openwhisk
@@ -436,7 +520,7 @@ where
     ///
     /// // creating new client from using the propety
     ///
-    /// let client = OpenwhiskClient::<NativeClient>::new(Some(&new_wsk_props));
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
     /// // use initilalised client to interact with openwhisk API
     ///
     /// // Lists the namespaces available in the openwhisk
@@ -446,4 +530,361 @@ where
     pub fn namespaces(&self) -> &NamespaceService<T> {
         &self.namespaces
     }
+
+    /// To Access activation endpoints from the Openwhisk Client using this method
+    ///
+    /// Returns ActivationService
+    ///
+    /// This can be used to call underlying activation service methods
+    ///
+    /// * `list`    - Lists all the activations in the namesapce
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // Lists the activations recorded in the openwhisk
+    /// let activations = client.activations().list().unwrap();
+    /// ```
+    ///
+    /// * `get`     - Get the activation property based on the activation id provided as paramter
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // get the activation properties recorded in the openwhisk
+    /// let activation = client.activations().get("activation_id").unwrap();
+    /// ```
+    ///
+    /// * `logs`    - Get the logs written by the activation
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // get the logs of the activation
+    /// let logs = client.activations().logs("activation_id").unwrap();
+    /// ```
+    ///
+    /// * `result`  - Get the result returned by the activation
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // get the result of the activation
+    /// let result = client.activations().result("activation_id").unwrap();
+    /// ```
+    pub fn activations(&self) -> &ActivationService<T> {
+        &self.activations
+    }
+
+    /// To Access package endpoints from the Openwhisk Client using this method
+    ///
+    /// Returns PackageService
+    ///
+    /// This can be used to call underlying package service methods
+    ///
+    /// * `list`    - Lists all the packages in the namesapce
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // Lists the packages deployed in the openwhisk
+    /// let packages = client.packages().list().unwrap();
+    /// ```
+    ///
+    /// * `get`     - Get the package property based on the package name provided as paramter
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // get the package properties which is deployed in the openwhisk
+    /// let package = client.packages().get("package_name").unwrap();
+    /// ```
+    ///
+    /// * `delete`  - Delete package based on the package name
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // deletes the package which is deployed in the openwhisk
+    /// let package = client.packages().delete("package_name").unwrap();
+    /// ```
+    ///
+    /// * `insert`  - Insert package and returns new package created
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // insert the package and deploys in the openwhisk
+    /// let package = client.packages().insert(&package,true).unwrap();
+    /// ```
+    ///
+    /// * `bind`    - Creates a binding to an existing package
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // binds the package with parameters
+    /// let binding = client.packages().bind("binding_name",&package_binding,parameters,true).unwrap();
+    /// ```
+    ///
+    /// * `share`   - Shares the package with other namespaces
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // shares the package which is deployed in the openwhisk
+    /// let package = client.packages().share("package_name",true).unwrap();
+    /// ```
+    pub fn packages(&self) -> &PackageService<T> {
+        &self.packages
+    }
+
+    /// To Access API Gateway endpoints from the Openwhisk Client using this method
+    ///
+    /// Returns RouteService
+    ///
+    /// This can be used to call underlying route service methods
+    ///
+    /// * `list`    - Lists all the APIs in the namesapce
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // Lists the APIs configured in the openwhisk
+    /// let apis = client.routes().list(None).unwrap();
+    /// ```
+    ///
+    /// * `insert`  - Creates a route which invokes a web action
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // creates the route for the web action
+    /// let api = client.routes().insert(&route).unwrap();
+    /// ```
+    ///
+    /// * `import`  - Creates the routes described by an OpenAPI definition
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // creates the routes of the swagger
+    /// let api = client.routes().import(&swagger).unwrap();
+    /// ```
+    ///
+    /// * `export`  - Returns the OpenAPI definition of an API
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // exports the swagger of the API
+    /// let swagger = client.routes().export("/base_path").unwrap();
+    /// ```
+    ///
+    /// * `delete`  - Deletes an API or some of its routes
+    ///
+    /// # Example
+    /// ```
+    /// use openwhisk_rust::{NativeClient, OpenwhiskClient, WskProperties};
+    /// // setting openwhisk props with user Input
+    /// let new_wsk_props = WskProperties::new(
+    ///         "your:auth_token".to_string(),
+    ///         "host".to_string(),
+    ///         true,
+    ///         "namespace".to_string()
+    ///  );
+    ///
+    /// // creating new client from using the propety
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(Some(&new_wsk_props)).unwrap();
+    /// // use initilalised client to interact with openwhisk API
+    ///
+    /// // deletes the API configured in the openwhisk
+    /// let result = client.routes().delete("/base_path",None,None).unwrap();
+    /// ```
+    pub fn routes(&self) -> &RouteService<T> {
+        &self.routes
+    }
 }
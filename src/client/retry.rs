@@ -0,0 +1,200 @@
+use http::HeaderMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+use crate::api::HttpMethods;
+
+/// Statuses retried by default: too many requests and controller unavailability
+pub static DEFAULT_RETRY_STATUSES: &[u16] = &[429, 502, 503, 504];
+
+/// Methods retried by default. `POST` is left out as invoking actions and firing triggers
+/// is not idempotent, a retried invoke may run the action twice
+pub static DEFAULT_RETRY_METHODS: &[HttpMethods] = &[
+    HttpMethods::GET,
+    HttpMethods::PUT,
+    HttpMethods::DELETE,
+    HttpMethods::HEAD,
+    HttpMethods::OPTIONS,
+];
+
+/// Policy of retrying requests which failed with a transient error.
+///
+/// Requests are retried when their method is retryable and they failed with a retryable
+/// status or a transport error, including connect errors, as a request which failed to
+/// connect may still have reached OpenWhisk through a proxy. The delay between attempts
+/// grows exponentially with jitter, a `Retry-After` header sent by OpenWhisk takes precedence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. 1 disables retries
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between attempts, including delays asked by `Retry-After`
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each retry
+    pub multiplier: u32,
+    /// Toggle to randomize delays, which spreads retries of concurrent clients
+    pub jitter: bool,
+    /// Response statuses which are retried
+    pub retry_statuses: Vec<u16>,
+    /// Request methods which are retried
+    pub retry_methods: Vec<HttpMethods>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2,
+            jitter: true,
+            retry_statuses: DEFAULT_RETRY_STATUSES.to_vec(),
+            retry_methods: DEFAULT_RETRY_METHODS.to_vec(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates the default retry policy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy which sends each request once
+    pub fn disabled() -> Self {
+        Self::default().set_max_attempts(1)
+    }
+
+    /// Sets the maximum number of attempts, including the first one
+    ///
+    /// # Arguments
+    /// * `max_attempts` - Maximum number of attempts, 1 disables retries
+    ///
+    pub fn set_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry and its upper bound
+    ///
+    /// # Arguments
+    /// * `initial_backoff` - Delay before the first retry
+    /// * `max_backoff`     - Upper bound of the delay between attempts
+    ///
+    pub fn set_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor applied to the delay after each retry
+    ///
+    /// # Arguments
+    /// * `multiplier` - Factor applied to the delay, 1 retries at a fixed delay
+    ///
+    pub fn set_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Toggles randomized delays
+    ///
+    /// # Arguments
+    /// * `jitter` - Bool to toggle jitter
+    ///
+    pub fn set_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the response statuses which are retried
+    ///
+    /// # Arguments
+    /// * `statuses` - Retryable statuses
+    ///
+    pub fn set_retry_statuses(mut self, statuses: &[u16]) -> Self {
+        self.retry_statuses = statuses.to_vec();
+        self
+    }
+
+    /// Sets the request methods which are retried. Adding `POST` retries invokes and fires,
+    /// which may then run more than once
+    ///
+    /// # Arguments
+    /// * `methods` - Retryable methods
+    ///
+    pub fn set_retry_methods(mut self, methods: &[HttpMethods]) -> Self {
+        self.retry_methods = methods.to_vec();
+        self
+    }
+
+    /// Returns true when a request can be attempted again after the given attempt
+    pub(crate) fn can_retry(&self, method: &str, attempt: u32) -> bool {
+        attempt < self.max_attempts
+            && self
+                .retry_methods
+                .iter()
+                .any(|retry_method| retry_method.as_str() == method)
+    }
+
+    /// Returns true when a response status is retryable
+    pub(crate) fn is_retry_status(&self, status: u16) -> bool {
+        self.retry_statuses.contains(&status)
+    }
+
+    /// Returns the delay before the attempt following the given one
+    ///
+    /// # Arguments
+    /// * `attempt` - Number of the failed attempt, starting at 1
+    /// * `headers` - Headers of the failed response, if any
+    pub(crate) fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        if let Some(retry_after) = headers.and_then(retry_after) {
+            return retry_after.min(self.max_backoff);
+        }
+
+        let exponent = attempt.saturating_sub(1);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(self.multiplier.max(1).saturating_pow(exponent))
+            .min(self.max_backoff);
+
+        if self.jitter {
+            // Equal jitter keeps half of the backoff and randomizes the other half
+            let half = backoff / 2;
+            half + half.mul_f64(random_fraction())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Reads a `Retry-After` header, either delay seconds or an HTTP date
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get("Retry-After")?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Returns a random number in `[0, 1)`, seeded by the random keys of the std hasher
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -1,13 +1,16 @@
-use super::common::{whisk_errors, OpenWhisk};
-use crate::api::{HttpMethods, Service};
+use super::common::{OpenWhisk, WskProperties};
+use super::{auth_headers, AuthProvider};
+use crate::api::{HttpMethods, HttpResponse, OpenWhiskError, Service};
 use bytes::Bytes;
-use http::{HeaderMap, Request, StatusCode};
-use serde_json::{Error, Value};
+use http::{HeaderMap, Request};
+use serde_json::Value;
+use std::sync::Arc;
 use wasi_experimental_http::request as wasi_request;
 
 #[derive(Debug, Default, Clone)]
 pub struct WasmClient {
     headers: http::HeaderMap,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
 }
 impl OpenWhisk for WasmClient {
     type Output = WasmClient;
@@ -23,8 +26,16 @@ impl OpenWhisk for WasmClient {
         };
         WasmClient {
             headers: header_map,
+            auth_provider: None,
         }
     }
+
+    fn from_properties(properties: &WskProperties) -> Result<Self::Output, OpenWhiskError> {
+        Ok(WasmClient {
+            auth_provider: properties.auth_provider.clone(),
+            ..Self::new_whisk_client(Some(properties.insecure))
+        })
+    }
 }
 
 impl Service for WasmClient {
@@ -36,133 +47,77 @@ impl Service for WasmClient {
         url: &str,
         user_auth: Option<(&str, &str)>,
         body: Option<Value>,
-    ) -> Result<Self::Output, String> {
+    ) -> Result<Self::Output, OpenWhiskError> {
         let mut req = http::request::Builder::new().header("Content-Type", "application/json");
         for (key, value) in self.headers.iter() {
             req = req.header(key, value);
         }
-        let body = Bytes::from(serde_json::to_vec(&body).unwrap());
-        match user_auth {
-            Some(auth) => {
-                let user = auth.0;
-                let pass = auth.1;
-                let bse64_encode = base64::encode(format!("{}:{}", user, pass));
-
-                match method {
-                    Some(http_methods) => match http_methods {
-                        HttpMethods::GET => {
-                            let req = req
-                                .header("Authorization", format!("Basic {}", bse64_encode))
-                                .method("GET")
-                                .uri(url)
-                                .body(Some(body));
-                            match req {
-                                Ok(req) => Ok(req),
-                                Err(error) => Err(format!("{}", error)),
-                            }
-                        }
-                        HttpMethods::PUT => {
-                            let req = req
-                                .header("Authorization", format!("Basic {}", bse64_encode))
-                                .method("PUT")
-                                .uri(url)
-                                .body(Some(body));
-                            match req {
-                                Ok(req) => Ok(req),
-                                Err(error) => Err(format!("{}", error)),
-                            }
-                        }
-                        HttpMethods::POST => {
-                            let req = req
-                                .header("Authorization", format!("Basic {}", bse64_encode))
-                                .method("POST")
-                                .uri(url)
-                                .body(Some(body));
-                            match req {
-                                Ok(req) => Ok(req),
-                                Err(error) => Err(format!("{}", error)),
-                            }
-                        }
-                        HttpMethods::DELETE => {
-                            let req = req
-                                .header("Authorization", format!("Basic {}", bse64_encode))
-                                .method("DELETE")
-                                .uri(url)
-                                .body(Some(body));
-                            match req {
-                                Ok(req) => Ok(req),
-                                Err(error) => Err(format!("{}", error)),
-                            }
-                        }
-                    },
-                    None => Err("Falied to create request".to_string()),
+        let body = match serde_json::to_vec(&body) {
+            Ok(body) => Bytes::from(body),
+            Err(error) => return Err(OpenWhiskError::Serialize(format!("{}", error))),
+        };
+        for (key, value) in auth_headers(self.auth_provider.as_ref(), user_auth)? {
+            req = req.header(key, value);
+        }
+        match method {
+            Some(http_methods) => {
+                let req = req.method(http_methods.as_str()).uri(url).body(Some(body));
+                match req {
+                    Ok(req) => Ok(req),
+                    Err(error) => Err(OpenWhiskError::Request(format!("{}", error))),
                 }
             }
-            None => match method {
-                Some(http_methods) => match http_methods {
-                    HttpMethods::GET => {
-                        let req = req.method("GET").uri(url).body(Some(body));
-                        match req {
-                            Ok(req) => Ok(req),
-                            Err(error) => Err(format!("{}", error)),
-                        }
-                    }
-                    HttpMethods::PUT => {
-                        let req = req.method("PUT").uri(url).body(Some(body));
-                        match req {
-                            Ok(req) => Ok(req),
-                            Err(error) => Err(format!("{}", error)),
-                        }
-                    }
-                    HttpMethods::POST => {
-                        let req = req.method("POST").uri(url).body(Some(body));
-                        match req {
-                            Ok(req) => Ok(req),
-                            Err(error) => Err(format!("{}", error)),
-                        }
-                    }
-                    HttpMethods::DELETE => {
-                        let req = req.method("DELETE").uri(url).body(Some(body));
-                        match req {
-                            Ok(req) => Ok(req),
-                            Err(error) => Err(format!("{}", error)),
-                        }
-                    }
-                },
-                None => Err("Falied to create request".to_string()),
-            },
+            None => Err(OpenWhiskError::Request(
+                "Falied to create request".to_string(),
+            )),
+        }
+    }
+    fn new_raw_request(
+        &self,
+        method: HttpMethods,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        headers: &[(String, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        let mut req = http::request::Builder::new();
+        for (key, value) in self.headers.iter() {
+            req = req.header(key, value);
+        }
+        for (key, value) in headers {
+            req = req.header(key.as_str(), value.as_str());
+        }
+        for (key, value) in auth_headers(self.auth_provider.as_ref(), user_auth)? {
+            req = req.header(key, value);
+        }
+
+        let req = req
+            .method(method.as_str())
+            .uri(url)
+            .body(body.map(Bytes::from));
+        match req {
+            Ok(req) => Ok(req),
+            Err(error) => Err(OpenWhiskError::Request(format!("{}", error))),
         }
     }
 
-    fn invoke_request(&self, request: Self::Output) -> Result<Value, String> {
+    fn invoke_raw_request(&self, request: Self::Output) -> Result<HttpResponse, OpenWhiskError> {
         match wasi_request(request) {
-            Ok(mut response) => match response.status_code {
-                StatusCode::OK => match response.body_read_all() {
-                    Ok(response) => match String::from_utf8(response) {
-                        Ok(response) => {
-                            let response_to_value: Result<Value, Error> =
-                                serde_json::from_str(&response);
-                            match response_to_value {
-                                Ok(value) => Ok(value),
-                                Err(error) => Err(error.to_string()),
-                            }
-                        }
-                        Err(error) => Err(error.to_string()),
-                    },
-                    Err(error) => Err(format!("{}", error)),
-                },
-                _ => {
-                    let code = response.status_code;
-                    let error = response.body_read_all().unwrap();
-                    let s = match std::str::from_utf8(&error) {
-                        Ok(v) => v,
-                        Err(error) => return Err(format!("{}", error)),
-                    };
-
-                    Err(whisk_errors(code, s.to_string()))
+            Ok(mut response) => {
+                let headers = match response.headers_get_all() {
+                    Ok(headers) => headers,
+                    Err(error) => return Err(OpenWhiskError::Transport(error.to_string())),
+                };
+                match response.body_read_all() {
+                    Ok(body) => Ok(HttpResponse {
+                        status: response.status_code.as_u16(),
+                        headers,
+                        body,
+                    }),
+                    Err(error) => Err(OpenWhiskError::Transport(format!("{}", error))),
                 }
-            },
-            Err(error) => Err(error.to_string()),
+            }
+            Err(error) => Err(OpenWhiskError::Transport(error.to_string())),
         }
     }
 }
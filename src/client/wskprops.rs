@@ -0,0 +1,229 @@
+use super::common::WskProperties;
+use crate::api::OpenWhiskError;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable which points to the wsk CLI properties file
+pub static WSK_CONFIG_FILE: &str = "WSK_CONFIG_FILE";
+/// Name of the wsk CLI properties file in the home directory
+pub static WSKPROPS_FILE: &str = ".wskprops";
+
+impl WskProperties {
+    /// Reads OpenWhisk properties from a wsk CLI properties file
+    ///
+    /// The file holds `KEY=VALUE` lines, the keys read are `AUTH`, `APIHOST`, `NAMESPACE`,
+    /// `APIVERSION`, `CERT`, `KEY` and `APIGW_ACCESS_TOKEN`. Blank lines and lines
+    /// starting with `#` are ignored.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the properties file
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    ///
+    /// let wsk_property = WskProperties::from_wskprops_file("/home/user/.wskprops").unwrap();
+    ///
+    /// ```
+    pub fn from_wskprops_file<P: AsRef<Path>>(path: P) -> Result<Self, OpenWhiskError> {
+        let content = match fs::read_to_string(path.as_ref()) {
+            Ok(content) => content,
+            Err(error) => {
+                return Err(OpenWhiskError::Configuration(format!(
+                    "Failed to read {} {}",
+                    path.as_ref().display(),
+                    error
+                )))
+            }
+        };
+
+        Ok(Self::from_wskprops(&content))
+    }
+
+    /// Reads OpenWhisk properties the way the wsk CLI does
+    ///
+    /// Properties are read from the file set in `WSK_CONFIG_FILE`, or from `~/.wskprops`
+    /// when it is not set. A missing `~/.wskprops` is not an error. The environment
+    /// variables `__OW_API_KEY`, `__OW_API_HOST` and `__OW_NAMESPACE` take precedence
+    /// over the values read from the file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    ///
+    /// let wsk_property = WskProperties::from_default_locations().unwrap();
+    ///
+    /// ```
+    pub fn from_default_locations() -> Result<Self, OpenWhiskError> {
+        let mut properties = match env::var(WSK_CONFIG_FILE) {
+            Ok(path) if !path.is_empty() => Self::from_wskprops_file(path)?,
+            _ => match home_dir() {
+                Some(home) if home.join(WSKPROPS_FILE).is_file() => {
+                    Self::from_wskprops_file(home.join(WSKPROPS_FILE))?
+                }
+                _ => Self::from_wskprops(""),
+            },
+        };
+
+        if let Ok(auth_token) = env::var("__OW_API_KEY") {
+            properties.auth_token = auth_token;
+        }
+
+        if let Ok(host) = env::var("__OW_API_HOST") {
+            properties.host = api_host(&host);
+        }
+
+        if let Ok(namespace) = env::var("__OW_NAMESPACE") {
+            properties.namespace = namespace;
+        }
+
+        Ok(properties)
+    }
+
+    /// Returns the path of the wsk CLI properties file, the file set in `WSK_CONFIG_FILE`
+    /// or `~/.wskprops` when it is not set
+    pub fn wskprops_path() -> Option<PathBuf> {
+        match env::var(WSK_CONFIG_FILE) {
+            Ok(path) if !path.is_empty() => Some(PathBuf::from(path)),
+            _ => home_dir().map(|home| home.join(WSKPROPS_FILE)),
+        }
+    }
+
+    /// Sets the API host the way the wsk CLI reads `APIHOST`, a host without scheme
+    /// defaults to https
+    ///
+    /// # Arguments
+    /// * `host` - Host of the API, like `localhost:3233` or `http://localhost:3233`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    ///
+    /// let new_wsk_property = WskProperties::new(
+    /// "your:auth_token".to_string(),
+    /// "".to_string(),
+    /// "namespace".to_string()
+    /// ).set_api_host("openwhisk.example.com");
+    ///
+    /// ```
+    pub fn set_api_host(mut self, host: &str) -> Self {
+        self.host = api_host(host);
+
+        self
+    }
+
+    /// Sets or removes properties of a wsk CLI properties file, like `wsk property set` does.
+    /// Other lines of the file are kept, the file is created when missing
+    ///
+    /// # Arguments
+    /// * `path`    - Path to the properties file
+    /// * `updates` - Keys, like `APIHOST`, with the value to set or None to remove the key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::WskProperties;
+    ///
+    /// WskProperties::update_wskprops_file(
+    ///     "/home/user/.wskprops",
+    ///     &[("APIHOST", Some("localhost:3233")), ("CERT", None)],
+    /// ).unwrap();
+    ///
+    /// ```
+    pub fn update_wskprops_file<P: AsRef<Path>>(
+        path: P,
+        updates: &[(&str, Option<&str>)],
+    ) -> Result<(), OpenWhiskError> {
+        let path = path.as_ref();
+        let content = if path.exists() {
+            match fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(error) => {
+                    return Err(OpenWhiskError::Configuration(format!(
+                        "Failed to read {} {}",
+                        path.display(),
+                        error
+                    )))
+                }
+            }
+        } else {
+            String::new()
+        };
+
+        let mut lines: Vec<String> = content
+            .lines()
+            .filter(|line| {
+                let key = line.split_once('=').map(|(key, _)| key.trim());
+                !updates.iter().any(|(update, _)| Some(*update) == key)
+            })
+            .map(str::to_string)
+            .collect();
+
+        for (key, value) in updates {
+            if let Some(value) = value {
+                lines.push(format!("{}={}", key, value));
+            }
+        }
+
+        let mut content = lines.join("\n");
+        content.push('\n');
+
+        match fs::write(path, content) {
+            Ok(()) => Ok(()),
+            Err(error) => Err(OpenWhiskError::Configuration(format!(
+                "Failed to write {} {}",
+                path.display(),
+                error
+            ))),
+        }
+    }
+
+    fn from_wskprops(content: &str) -> Self {
+        let mut properties = WskProperties::new(String::new(), String::new(), "_".to_string());
+
+        for line in content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim().to_string()),
+                None => continue,
+            };
+
+            match key {
+                "AUTH" => properties.auth_token = value,
+                "APIHOST" => properties.host = api_host(&value),
+                "NAMESPACE" => properties.namespace = value,
+                "APIVERSION" => properties.version = value,
+                "CERT" => properties.cert = Some(value),
+                "KEY" => properties.key = Some(value),
+                "APIGW_ACCESS_TOKEN" => properties.apigw_access_token = Some(value),
+                _ => {}
+            }
+        }
+
+        properties
+    }
+}
+
+/// The wsk CLI accepts an api host without scheme, which defaults to https
+fn api_host(host: &str) -> String {
+    if host.is_empty() || host.starts_with("http://") || host.starts_with("https://") {
+        host.trim_end_matches('/').to_string()
+    } else {
+        format!("https://{}", host.trim_end_matches('/'))
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
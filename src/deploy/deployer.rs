@@ -0,0 +1,162 @@
+use super::{Manifest, Project, ProjectTrigger};
+use crate::api::{FeedLifecycleEvent, OpenWhiskError, Service};
+use crate::client::{OpenWhisk, OpenwhiskClient};
+
+impl<T: Clone> OpenwhiskClient<T>
+where
+    T: Service + OpenWhisk + OpenWhisk<Output = T>,
+{
+    /// Deploys the entities of a manifest and returns the deployed project.
+    /// Packages, actions, sequences, triggers, rules and APIs are created in this order,
+    /// existing entities are overwritten
+    ///
+    /// # Arguments
+    /// * `manifest` - The manifest, with deployment overrides applied
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{Manifest, NativeClient, OpenwhiskClient, WskProperties};
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(None).unwrap();
+    ///
+    /// let manifest = Manifest::from_file("manifest.yaml").unwrap();
+    /// let project = client.deploy(&manifest).unwrap();
+    ///
+    /// ```
+    pub fn deploy(&self, manifest: &Manifest) -> Result<Project, OpenWhiskError> {
+        let project = manifest.project(self.context.namespace())?;
+
+        self.deploy_project(&project)?;
+
+        Ok(project)
+    }
+
+    /// Deletes the entities of a manifest and returns the undeployed project.
+    /// Entities are deleted in the reverse order of deploy, those already missing are skipped
+    ///
+    /// # Arguments
+    /// * `manifest` - The manifest, with deployment overrides applied
+    pub fn undeploy(&self, manifest: &Manifest) -> Result<Project, OpenWhiskError> {
+        let project = manifest.project(self.context.namespace())?;
+
+        self.undeploy_project(&project)?;
+
+        Ok(project)
+    }
+
+    /// Deploys the entities of a resolved project
+    ///
+    /// # Arguments
+    /// * `project` - The project to be deployed
+    pub fn deploy_project(&self, project: &Project) -> Result<(), OpenWhiskError> {
+        for package in &project.packages {
+            self.packages().insert(package, true)?;
+        }
+
+        for action in project.actions.iter().chain(project.sequences.iter()) {
+            self.actions().insert(action, true)?;
+        }
+
+        for trigger in &project.triggers {
+            self.create_trigger(trigger)?;
+        }
+
+        for rule in &project.rules {
+            self.rules().insert(rule, true)?;
+        }
+
+        for route in &project.routes {
+            self.routes().insert(route)?;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the entities of a resolved project
+    ///
+    /// # Arguments
+    /// * `project` - The project to be undeployed
+    pub fn undeploy_project(&self, project: &Project) -> Result<(), OpenWhiskError> {
+        for route in &project.routes {
+            skip_not_found(self.routes().delete(
+                &route.base_path,
+                Some(&route.relative_path),
+                Some(&route.verb),
+            ))?;
+        }
+
+        for rule in &project.rules {
+            skip_not_found(self.rules().delete(&rule.name))?;
+        }
+
+        for trigger in &project.triggers {
+            self.delete_trigger(trigger)?;
+        }
+
+        for action in project
+            .sequences
+            .iter()
+            .rev()
+            .chain(project.actions.iter().rev())
+        {
+            skip_not_found(self.actions().delete(&action.name))?;
+        }
+
+        for package in project.packages.iter().rev() {
+            skip_not_found(self.packages().delete(&package.name))?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates a trigger, and registers it with its feed
+    pub(crate) fn create_trigger(
+        &self,
+        project_trigger: &ProjectTrigger,
+    ) -> Result<(), OpenWhiskError> {
+        let feed = match &project_trigger.feed {
+            Some(feed) => feed,
+            None => {
+                self.triggers().insert(&project_trigger.trigger, true)?;
+
+                return Ok(());
+            }
+        };
+
+        let mut trigger = project_trigger.trigger.clone();
+        let parameters = std::mem::take(&mut trigger.parameters);
+
+        self.triggers()
+            .create_with_feed(&trigger, feed, &parameters, true)?;
+
+        Ok(())
+    }
+
+    /// Unregisters a trigger from its feed, and deletes it
+    pub(crate) fn delete_trigger(
+        &self,
+        project_trigger: &ProjectTrigger,
+    ) -> Result<(), OpenWhiskError> {
+        let name = &project_trigger.trigger.name;
+
+        if let Some(feed) = &project_trigger.feed {
+            skip_not_found(self.triggers().feed_lifecycle(
+                feed,
+                name,
+                FeedLifecycleEvent::Delete,
+                &[],
+            ))?;
+        }
+
+        skip_not_found(self.triggers().delete(name))
+    }
+}
+
+/// Treats a missing entity as already deleted
+pub(crate) fn skip_not_found<V>(result: Result<V, OpenWhiskError>) -> Result<(), OpenWhiskError> {
+    match result {
+        Ok(_) | Err(OpenWhiskError::NotFound(_)) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
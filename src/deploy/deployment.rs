@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::api::OpenWhiskError;
+
+/// Representation of a wskdeploy `deployment.yaml`, which overrides the inputs of a manifest
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct Deployment {
+    /// Project which holds the package overrides
+    #[serde(default)]
+    pub project: Option<ProjectDeployment>,
+    /// Package overrides declared outside of a project
+    #[serde(default)]
+    pub packages: BTreeMap<String, PackageDeployment>,
+}
+
+/// Representation of the project of a deployment file
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct ProjectDeployment {
+    /// Name of the project
+    #[serde(default)]
+    pub name: String,
+    /// Package overrides of the project
+    #[serde(default)]
+    pub packages: BTreeMap<String, PackageDeployment>,
+}
+
+/// Representation of the overrides of a package
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct PackageDeployment {
+    /// Parameters of the package
+    #[serde(default)]
+    pub inputs: BTreeMap<String, Value>,
+    /// Overrides of the actions of the package
+    #[serde(default)]
+    pub actions: BTreeMap<String, EntityDeployment>,
+    /// Overrides of the triggers of the package
+    #[serde(default)]
+    pub triggers: BTreeMap<String, EntityDeployment>,
+}
+
+/// Representation of the overrides of an action or trigger
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct EntityDeployment {
+    /// Parameters of the entity
+    #[serde(default)]
+    pub inputs: BTreeMap<String, Value>,
+    /// Annotations of the entity
+    #[serde(default)]
+    pub annotations: BTreeMap<String, Value>,
+}
+
+impl Deployment {
+    /// Reads a deployment file from a yaml file
+    ///
+    /// # Arguments
+    /// * `path` - Path to the `deployment.yaml`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{Deployment, Manifest};
+    ///
+    /// let manifest = Manifest::from_file("manifest.yaml")
+    ///     .unwrap()
+    ///     .with_deployment(&Deployment::from_file("deployment.yaml").unwrap());
+    ///
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, OpenWhiskError> {
+        let path = path.as_ref();
+
+        match fs::read_to_string(path) {
+            Ok(content) => Self::from_yaml(&content),
+            Err(error) => Err(OpenWhiskError::InvalidInput(format!(
+                "Failed to read {} {}",
+                path.display(),
+                error
+            ))),
+        }
+    }
+
+    /// Parses a deployment file from yaml
+    ///
+    /// # Arguments
+    /// * `content` - Yaml content of the deployment file
+    pub fn from_yaml(content: &str) -> Result<Self, OpenWhiskError> {
+        match serde_yaml::from_str(content) {
+            Ok(deployment) => Ok(deployment),
+            Err(error) => Err(OpenWhiskError::InvalidInput(format!(
+                "Failed to parse deployment {}",
+                error
+            ))),
+        }
+    }
+
+    /// Returns the package overrides of the project along with those declared outside of it
+    pub fn all_packages(&self) -> BTreeMap<&str, &PackageDeployment> {
+        let mut packages: BTreeMap<&str, &PackageDeployment> = self
+            .packages
+            .iter()
+            .map(|(name, package)| (name.as_str(), package))
+            .collect();
+
+        if let Some(project) = &self.project {
+            for (name, package) in &project.packages {
+                packages.insert(name, package);
+            }
+        }
+
+        packages
+    }
+}
@@ -0,0 +1,297 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::Deployment;
+use crate::api::OpenWhiskError;
+
+/// Representation of a wskdeploy `manifest.yaml`
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct Manifest {
+    /// Project which holds the packages
+    #[serde(default)]
+    pub project: Option<ProjectManifest>,
+    /// Packages declared outside of a project
+    #[serde(default)]
+    pub packages: BTreeMap<String, PackageManifest>,
+    /// Directory which `function` paths are relative to
+    #[serde(skip)]
+    pub base_dir: PathBuf,
+}
+
+/// Representation of the project of a manifest
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct ProjectManifest {
+    /// Name of the project
+    #[serde(default)]
+    pub name: String,
+    /// Packages of the project
+    #[serde(default)]
+    pub packages: BTreeMap<String, PackageManifest>,
+}
+
+/// Representation of a package of a manifest.
+/// Entities of the package named `default` are created in the namespace, outside of any package
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct PackageManifest {
+    /// Version of the package
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub version: Option<String>,
+    /// License of the package
+    #[serde(default)]
+    pub license: Option<String>,
+    /// Toggle to share the package with other namespaces
+    #[serde(default)]
+    pub public: bool,
+    /// Parameters of the package
+    #[serde(default)]
+    pub inputs: BTreeMap<String, Value>,
+    /// Annotations of the package
+    #[serde(default)]
+    pub annotations: BTreeMap<String, Value>,
+    /// Actions of the package
+    #[serde(default)]
+    pub actions: BTreeMap<String, ActionManifest>,
+    /// Sequences of the package
+    #[serde(default)]
+    pub sequences: BTreeMap<String, SequenceManifest>,
+    /// Triggers declared by the package
+    #[serde(default)]
+    pub triggers: BTreeMap<String, TriggerManifest>,
+    /// Rules declared by the package
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleManifest>,
+    /// APIs keyed by API name
+    #[serde(default)]
+    pub apis: BTreeMap<String, ApiManifest>,
+}
+
+/// Operations of an API keyed by base path, relative path and action name.
+/// An operation is either the HTTP verb, or a mapping of `method` and `response` type
+pub type ApiManifest = BTreeMap<String, BTreeMap<String, BTreeMap<String, Value>>>;
+
+/// Representation of an action of a manifest
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct ActionManifest {
+    /// Path to the source file, directory or archive, relative to the manifest
+    #[serde(default)]
+    pub function: Option<String>,
+    /// Inline source code
+    #[serde(default)]
+    pub code: Option<String>,
+    /// Runtime kind, inferred from the function when not set
+    #[serde(default)]
+    pub runtime: Option<String>,
+    /// Entry point of the action
+    #[serde(default)]
+    pub main: Option<String>,
+    /// Docker image of a blackbox action
+    #[serde(default)]
+    pub docker: Option<String>,
+    /// Web export of the action, `true`, `false` or `raw`
+    #[serde(default)]
+    pub web: Option<Value>,
+    /// Legacy spelling of `web`
+    #[serde(default, rename = "web-export")]
+    pub web_export: Option<Value>,
+    /// Parameters of the action
+    #[serde(default)]
+    pub inputs: BTreeMap<String, Value>,
+    /// Annotations of the action
+    #[serde(default)]
+    pub annotations: BTreeMap<String, Value>,
+    /// Limits of the action
+    #[serde(default)]
+    pub limits: Option<LimitsManifest>,
+}
+
+/// Representation of the limits of an action of a manifest
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct LimitsManifest {
+    /// Timeout in milliseconds
+    #[serde(default)]
+    pub timeout: Option<i64>,
+    /// Memory in MB
+    #[serde(default, rename = "memorySize")]
+    pub memory_size: Option<i64>,
+    /// Log size in MB
+    #[serde(default, rename = "logSize")]
+    pub log_size: Option<i64>,
+    /// Number of activations that can be processed at once
+    #[serde(default, rename = "concurrentActivations")]
+    pub concurrent_activations: Option<i64>,
+}
+
+/// Representation of a sequence of a manifest
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct SequenceManifest {
+    /// Comma separated names of the chained actions
+    pub actions: String,
+    /// Web export of the sequence, `true`, `false` or `raw`
+    #[serde(default)]
+    pub web: Option<Value>,
+    /// Annotations of the sequence
+    #[serde(default)]
+    pub annotations: BTreeMap<String, Value>,
+}
+
+/// Representation of a trigger of a manifest
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct TriggerManifest {
+    /// Fully qualified name of the feed action, e.g. `/whisk.system/alarms/alarm`
+    #[serde(default)]
+    pub feed: Option<String>,
+    /// Parameters of the trigger, or of the feed when set
+    #[serde(default)]
+    pub inputs: BTreeMap<String, Value>,
+    /// Annotations of the trigger
+    #[serde(default)]
+    pub annotations: BTreeMap<String, Value>,
+}
+
+/// Representation of a rule of a manifest
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct RuleManifest {
+    /// Name of the trigger
+    pub trigger: String,
+    /// Name of the action, relative to the package when not qualified
+    pub action: String,
+}
+
+impl Manifest {
+    /// Reads a manifest from a yaml file
+    ///
+    /// # Arguments
+    /// * `path` - Path to the `manifest.yaml`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::Manifest;
+    ///
+    /// let manifest = Manifest::from_file("manifest.yaml").unwrap();
+    ///
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, OpenWhiskError> {
+        let path = path.as_ref();
+
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) => {
+                return Err(OpenWhiskError::InvalidInput(format!(
+                    "Failed to read {} {}",
+                    path.display(),
+                    error
+                )))
+            }
+        };
+
+        let base_dir = match path.parent() {
+            Some(dir) => dir.to_path_buf(),
+            None => PathBuf::new(),
+        };
+
+        Self::from_yaml(&content, base_dir)
+    }
+
+    /// Parses a manifest from yaml
+    ///
+    /// # Arguments
+    /// * `content`  - Yaml content of the manifest
+    /// * `base_dir` - Directory which `function` paths are relative to
+    pub fn from_yaml<P: AsRef<Path>>(content: &str, base_dir: P) -> Result<Self, OpenWhiskError> {
+        match serde_yaml::from_str::<Manifest>(content) {
+            Ok(manifest) => Ok(Manifest {
+                base_dir: base_dir.as_ref().to_path_buf(),
+                ..manifest
+            }),
+            Err(error) => Err(OpenWhiskError::InvalidInput(format!(
+                "Failed to parse manifest {}",
+                error
+            ))),
+        }
+    }
+
+    /// Returns the project name, empty when the manifest has no project
+    pub fn project_name(&self) -> &str {
+        match &self.project {
+            Some(project) => &project.name,
+            None => "",
+        }
+    }
+
+    /// Returns the packages of the project along with those declared outside of it
+    pub fn all_packages(&self) -> BTreeMap<&str, &PackageManifest> {
+        let mut packages: BTreeMap<&str, &PackageManifest> = self
+            .packages
+            .iter()
+            .map(|(name, package)| (name.as_str(), package))
+            .collect();
+
+        if let Some(project) = &self.project {
+            for (name, package) in &project.packages {
+                packages.insert(name, package);
+            }
+        }
+
+        packages
+    }
+
+    /// Overrides the inputs of the manifest with those of a deployment file
+    ///
+    /// # Arguments
+    /// * `deployment` - The deployment file
+    pub fn with_deployment(mut self, deployment: &Deployment) -> Self {
+        for (name, package_deployment) in deployment.all_packages() {
+            let package = match self.package_mut(name) {
+                Some(package) => package,
+                None => continue,
+            };
+
+            package.inputs.extend(package_deployment.inputs.clone());
+
+            for (action_name, action_deployment) in &package_deployment.actions {
+                if let Some(action) = package.actions.get_mut(action_name) {
+                    action.inputs.extend(action_deployment.inputs.clone());
+                    action
+                        .annotations
+                        .extend(action_deployment.annotations.clone());
+                }
+            }
+
+            for (trigger_name, trigger_deployment) in &package_deployment.triggers {
+                if let Some(trigger) = package.triggers.get_mut(trigger_name) {
+                    trigger.inputs.extend(trigger_deployment.inputs.clone());
+                    trigger
+                        .annotations
+                        .extend(trigger_deployment.annotations.clone());
+                }
+            }
+        }
+
+        self
+    }
+
+    fn package_mut(&mut self, name: &str) -> Option<&mut PackageManifest> {
+        if let Some(project) = &mut self.project {
+            if let Some(package) = project.packages.get_mut(name) {
+                return Some(package);
+            }
+        }
+
+        self.packages.get_mut(name)
+    }
+}
+
+/// Reads a string which yaml may parse as a number, like `version: 1.0`
+fn string_or_number<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::String(value)) => Ok(Some(value)),
+        Some(Value::Null) | None => Ok(None),
+        Some(value) => Ok(Some(value.to_string())),
+    }
+}
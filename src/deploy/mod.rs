@@ -0,0 +1,10 @@
+mod deployer;
+mod deployment;
+mod manifest;
+mod project;
+mod reconciler;
+
+pub use deployment::*;
+pub use manifest::*;
+pub use project::*;
+pub use reconciler::*;
@@ -0,0 +1,485 @@
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::env;
+
+use super::{ActionManifest, Manifest, PackageManifest};
+use crate::api::{
+    Action, BlackboxExec, Exec, KeyValue, Limits, OpenWhiskError, Package, Route, Rule, Runtime,
+    Trigger, BLACKBOX_KIND,
+};
+
+/// Name of the manifest package whose entities are created outside of any package
+pub static DEFAULT_PACKAGE: &str = "default";
+/// Version given to entities when the manifest sets none
+pub static DEFAULT_VERSION: &str = "0.0.1";
+/// Annotation which marks the entities deployed as part of a project, as wskdeploy does
+pub static MANAGED_ANNOTATION: &str = "whisk-managed";
+
+/// Entities of a manifest, resolved for a namespace and ready to be deployed
+#[derive(Debug, Clone, Default)]
+pub struct Project {
+    /// Name of the project
+    pub name: String,
+    /// Packages of the project
+    pub packages: Vec<Package>,
+    /// Code and docker actions, named `package/action`
+    pub actions: Vec<Action>,
+    /// Sequences, ordered so that chained sequences come before the sequences using them
+    pub sequences: Vec<Action>,
+    /// Triggers of the project
+    pub triggers: Vec<ProjectTrigger>,
+    /// Rules of the project
+    pub rules: Vec<Rule>,
+    /// API Gateway routes of the project
+    pub routes: Vec<Route>,
+}
+
+/// Representation of a trigger of a project and the feed which fires it
+#[derive(Debug, Clone, Default)]
+pub struct ProjectTrigger {
+    /// Definition of the trigger, parameters are handed to the feed when set
+    pub trigger: Trigger,
+    /// Fully qualified name of the feed action
+    pub feed: Option<String>,
+}
+
+impl Manifest {
+    /// Resolves the entities of the manifest for a namespace.
+    /// Source files are read and packaged, runtimes are validated and inputs are resolved
+    ///
+    /// # Arguments
+    /// * `namespace` - Namespace the project is deployed to
+    pub fn project(&self, namespace: &str) -> Result<Project, OpenWhiskError> {
+        let mut project = Project {
+            name: self.project_name().to_string(),
+            ..Default::default()
+        };
+
+        for (package_name, package) in self.all_packages() {
+            let version = match &package.version {
+                Some(version) => version.clone(),
+                None => DEFAULT_VERSION.to_string(),
+            };
+
+            let entity_name = |name: &str| {
+                if package_name == DEFAULT_PACKAGE || name.contains('/') {
+                    name.to_string()
+                } else {
+                    format!("{}/{}", package_name, name)
+                }
+            };
+
+            if package_name != DEFAULT_PACKAGE {
+                project.packages.push(Package {
+                    namespace: namespace.to_string(),
+                    name: package_name.to_string(),
+                    version: version.clone(),
+                    publish: package.public,
+                    annotations: key_values(&package.annotations),
+                    parameters: inputs(&package.inputs),
+                    ..Default::default()
+                });
+            }
+
+            for (name, action) in &package.actions {
+                project.actions.push(Action {
+                    namespace: namespace.to_string(),
+                    name: entity_name(name),
+                    version: version.clone(),
+                    limits: action.limits.as_ref().map(|limits| Limits {
+                        timeout: limits.timeout,
+                        memory: limits.memory_size,
+                        logsize: limits.log_size,
+                        concurrency: limits.concurrent_activations,
+                    }),
+                    exec: self.action_exec(name, action)?,
+                    annotations: annotations(
+                        &action.annotations,
+                        action.web.as_ref().or(action.web_export.as_ref()),
+                    ),
+                    parameters: inputs(&action.inputs),
+                    ..Default::default()
+                });
+            }
+
+            for (name, sequence) in &package.sequences {
+                let components = sequence
+                    .actions
+                    .split(',')
+                    .map(|component| component.trim())
+                    .filter(|component| !component.is_empty())
+                    .map(|component| {
+                        if component.starts_with('/') {
+                            component.to_string()
+                        } else {
+                            format!("/{}/{}", namespace, entity_name(component))
+                        }
+                    })
+                    .collect();
+
+                project.sequences.push(Action {
+                    namespace: namespace.to_string(),
+                    name: entity_name(name),
+                    version: version.clone(),
+                    exec: Exec::sequence(components),
+                    annotations: annotations(&sequence.annotations, sequence.web.as_ref()),
+                    ..Default::default()
+                });
+            }
+
+            for (name, trigger) in &package.triggers {
+                let mut annotations = key_values(&trigger.annotations);
+
+                if let Some(feed) = &trigger.feed {
+                    annotations.push(KeyValue {
+                        key: "feed".to_string(),
+                        value: json!(feed),
+                    });
+                }
+
+                project.triggers.push(ProjectTrigger {
+                    trigger: Trigger {
+                        namespace: namespace.to_string(),
+                        name: name.clone(),
+                        version: version.clone(),
+                        annotations,
+                        parameters: inputs(&trigger.inputs),
+                        ..Default::default()
+                    },
+                    feed: trigger.feed.clone(),
+                });
+            }
+
+            for (name, rule) in &package.rules {
+                project.rules.push(Rule {
+                    name: name.clone(),
+                    trigger: rule.trigger.clone(),
+                    action: entity_name(&rule.action),
+                    status: "active".to_string(),
+                });
+            }
+
+            project.routes.extend(routes(package, &entity_name));
+        }
+
+        project.sequences = order_sequences(project.sequences, namespace);
+
+        if !project.name.is_empty() {
+            project.add_managed_annotation();
+        }
+
+        Ok(project)
+    }
+
+    fn action_exec(&self, name: &str, action: &ActionManifest) -> Result<Exec, OpenWhiskError> {
+        let runtime = match &action.runtime {
+            Some(runtime) => Some(Runtime::new(runtime)?),
+            None => None,
+        };
+
+        let exec = match (&action.docker, &action.code, &action.function) {
+            (Some(image), _, Some(function)) => {
+                let exec = Exec::from_path(
+                    self.base_dir.join(function),
+                    Some(Runtime::custom(BLACKBOX_KIND)),
+                )?;
+
+                Exec::Blackbox(BlackboxExec {
+                    image: image.clone(),
+                    code: exec.source().map(str::to_string),
+                    main: None,
+                    binary: exec.is_binary(),
+                })
+            }
+            (Some(image), Some(code), None) => Exec::Blackbox(BlackboxExec {
+                image: image.clone(),
+                code: Some(code.clone()),
+                ..Default::default()
+            }),
+            (Some(image), None, None) => Exec::blackbox(image),
+            (None, Some(code), _) => match runtime {
+                Some(runtime) => Exec::code(runtime, code),
+                None => {
+                    return Err(OpenWhiskError::InvalidInput(format!(
+                        "Action {} with inline code must set a runtime",
+                        name
+                    )))
+                }
+            },
+            (None, None, Some(function)) => Exec::from_path(self.base_dir.join(function), runtime)?,
+            (None, None, None) => {
+                return Err(OpenWhiskError::InvalidInput(format!(
+                    "Action {} must set a function, code or docker image",
+                    name
+                )))
+            }
+        };
+
+        match &action.main {
+            Some(main) => Ok(exec.set_main(main)),
+            None => Ok(exec),
+        }
+    }
+}
+
+impl Project {
+    /// Returns the value of the managed annotation of the project
+    pub fn managed_annotation(&self) -> Value {
+        json!({ "projectName": self.name })
+    }
+
+    /// Returns true when an entity carries the managed annotation of the project
+    ///
+    /// # Arguments
+    /// * `annotations` - Annotations of the entity
+    pub fn manages(&self, annotations: &[KeyValue]) -> bool {
+        !self.name.is_empty()
+            && annotations.iter().any(|annotation| {
+                annotation.key == MANAGED_ANNOTATION
+                    && annotation.value.get("projectName") == Some(&json!(self.name))
+            })
+    }
+
+    fn add_managed_annotation(&mut self) {
+        let managed = KeyValue {
+            key: MANAGED_ANNOTATION.to_string(),
+            value: self.managed_annotation(),
+        };
+
+        let annotations = self
+            .packages
+            .iter_mut()
+            .map(|package| &mut package.annotations)
+            .chain(
+                self.actions
+                    .iter_mut()
+                    .chain(self.sequences.iter_mut())
+                    .map(|action| &mut action.annotations),
+            )
+            .chain(
+                self.triggers
+                    .iter_mut()
+                    .map(|trigger| &mut trigger.trigger.annotations),
+            );
+
+        for annotations in annotations {
+            annotations.retain(|annotation| annotation.key != MANAGED_ANNOTATION);
+            annotations.push(managed.clone());
+        }
+    }
+}
+
+/// Returns the API Gateway routes of a package
+fn routes(package: &PackageManifest, entity_name: &dyn Fn(&str) -> String) -> Vec<Route> {
+    let mut routes = Vec::new();
+
+    for (api_name, base_paths) in &package.apis {
+        for (base_path, relative_paths) in base_paths {
+            for (relative_path, actions) in relative_paths {
+                for (action, operation) in actions {
+                    let (verb, response_type) = match operation {
+                        Value::String(verb) => (verb.clone(), "json".to_string()),
+                        Value::Object(operation) => (
+                            operation
+                                .get("method")
+                                .and_then(Value::as_str)
+                                .unwrap_or("GET")
+                                .to_string(),
+                            operation
+                                .get("response")
+                                .and_then(Value::as_str)
+                                .unwrap_or("json")
+                                .to_string(),
+                        ),
+                        _ => ("GET".to_string(), "json".to_string()),
+                    };
+
+                    routes.push(Route {
+                        base_path: with_leading_slash(base_path),
+                        relative_path: with_leading_slash(relative_path),
+                        verb: verb.to_uppercase(),
+                        action: entity_name(action),
+                        response_type,
+                        api_name: Some(api_name.clone()),
+                    });
+                }
+            }
+        }
+    }
+
+    routes
+}
+
+fn with_leading_slash(path: &str) -> String {
+    if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    }
+}
+
+/// Orders sequences so that a sequence comes after the sequences it chains.
+/// Sequences which chain each other keep their order, OpenWhisk rejects them anyway
+fn order_sequences(mut pending: Vec<Action>, namespace: &str) -> Vec<Action> {
+    let mut ordered: Vec<Action> = Vec::new();
+
+    while !pending.is_empty() {
+        let names: Vec<String> = pending
+            .iter()
+            .map(|sequence| format!("/{}/{}", namespace, sequence.name))
+            .collect();
+
+        let ready = pending.iter().position(|sequence| match &sequence.exec {
+            Exec::Sequence(exec) => !exec
+                .components
+                .iter()
+                .any(|component| names.contains(component)),
+            _ => true,
+        });
+
+        ordered.push(pending.remove(ready.unwrap_or(0)));
+    }
+
+    ordered
+}
+
+/// Converts annotations of a manifest, adding the annotations of the web export
+fn annotations(annotations: &BTreeMap<String, Value>, web: Option<&Value>) -> Vec<KeyValue> {
+    let mut web_annotations = match web.map(web_export) {
+        Some(Some(raw)) => vec![
+            ("web-export", json!(true)),
+            ("final", json!(true)),
+            ("raw-http", json!(raw)),
+        ],
+        Some(None) => vec![("web-export", json!(false))],
+        None => vec![],
+    };
+
+    web_annotations.retain(|(key, _)| !annotations.contains_key(*key));
+
+    web_annotations
+        .into_iter()
+        .map(|(key, value)| KeyValue {
+            key: key.to_string(),
+            value,
+        })
+        .chain(key_values(annotations))
+        .collect()
+}
+
+/// Returns whether the web export is raw, or None when the action is not exported
+fn web_export(web: &Value) -> Option<bool> {
+    match web {
+        Value::Bool(true) => Some(false),
+        Value::String(web) => match web.to_lowercase().as_str() {
+            "true" | "yes" => Some(false),
+            "raw" => Some(true),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn key_values(values: &BTreeMap<String, Value>) -> Vec<KeyValue> {
+    values
+        .iter()
+        .map(|(key, value)| KeyValue {
+            key: key.clone(),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+/// Resolves the inputs of a manifest into parameters
+fn inputs(values: &BTreeMap<String, Value>) -> Vec<KeyValue> {
+    values
+        .iter()
+        .map(|(key, value)| KeyValue {
+            key: key.clone(),
+            value: input_value(value),
+        })
+        .collect()
+}
+
+/// Resolves an input, which is a value, a type name standing for the empty value of the type,
+/// or a `type`, `value`, `default` and `description` mapping.
+/// Strings holding `$VAR` or `${VAR}` are read from the environment
+fn input_value(value: &Value) -> Value {
+    match value {
+        Value::String(value) => match type_default(value) {
+            Some(default) => default,
+            None => interpolate(value),
+        },
+        Value::Object(map) if is_typed_input(map) => {
+            match map.get("value").or_else(|| map.get("default")) {
+                Some(value) => input_value(value),
+                None => map
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .and_then(type_default)
+                    .unwrap_or(Value::Null),
+            }
+        }
+        value => value.clone(),
+    }
+}
+
+fn is_typed_input(map: &Map<String, Value>) -> bool {
+    map.contains_key("type")
+        && map.keys().all(|key| {
+            matches!(
+                key.as_str(),
+                "type" | "value" | "default" | "description" | "required"
+            )
+        })
+}
+
+fn type_default(type_name: &str) -> Option<Value> {
+    match type_name {
+        "string" => Some(json!("")),
+        "integer" => Some(json!(0)),
+        "float" => Some(json!(0.0)),
+        "boolean" => Some(json!(false)),
+        "json" => Some(json!({})),
+        _ => None,
+    }
+}
+
+/// Replaces `$VAR` and `${VAR}` with environment variables, unset variables are replaced with nothing
+fn interpolate(value: &str) -> Value {
+    if !value.contains('$') {
+        return json!(value);
+    }
+
+    let mut result = String::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find('$') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        let (name, remaining) = if let Some(braced) = rest.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], &braced[end + 1..]),
+                None => (braced, ""),
+            }
+        } else {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            (&rest[..end], &rest[end..])
+        };
+
+        if name.is_empty() {
+            result.push('$');
+        } else {
+            result.push_str(&env::var(name).unwrap_or_default());
+        }
+
+        rest = remaining;
+    }
+
+    result.push_str(rest);
+
+    json!(result)
+}
@@ -0,0 +1,581 @@
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt;
+
+use super::deployer::skip_not_found;
+use super::{Project, ProjectTrigger};
+use crate::api::{
+    Action, ActionListOptions, Exec, KeyValue, Limits, OpenWhiskError, Package, PackageListOptions,
+    Rule, RuleListOptions, RuleResponse, Service, Trigger, TriggerListOptions,
+};
+use crate::client::{OpenWhisk, OpenwhiskClient};
+
+/// Annotations which OpenWhisk adds to entities, ignored when comparing annotations
+static SERVER_ANNOTATIONS: &[&str] = &["exec", "provide-api-key", "binding"];
+
+/// Kind of a change of a plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The entity does not exist and is created
+    Create,
+    /// The entity differs and is overwritten
+    Update,
+    /// The entity is managed by the project but no longer desired, and is deleted
+    Delete,
+    /// The entity is up to date
+    NoOp,
+}
+
+/// Entity a change applies to
+#[derive(Debug, Clone)]
+pub enum Entity {
+    Package(Package),
+    Action(Action),
+    Trigger(ProjectTrigger),
+    Rule(Rule),
+}
+
+impl Entity {
+    /// Returns the kind of the entity, as used by the wsk CLI
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Entity::Package(_) => "package",
+            Entity::Action(_) => "action",
+            Entity::Trigger(_) => "trigger",
+            Entity::Rule(_) => "rule",
+        }
+    }
+
+    /// Returns the name of the entity
+    pub fn name(&self) -> &str {
+        match self {
+            Entity::Package(package) => &package.name,
+            Entity::Action(action) => &action.name,
+            Entity::Trigger(trigger) => &trigger.trigger.name,
+            Entity::Rule(rule) => &rule.name,
+        }
+    }
+}
+
+/// Representation of a change of a plan
+#[derive(Debug, Clone)]
+pub struct Change {
+    /// Kind of the change
+    pub kind: ChangeKind,
+    /// Desired entity, or the live entity for deletes
+    pub entity: Entity,
+    /// Properties which differ between the desired and the live entity, for updates
+    pub differences: Vec<String>,
+}
+
+/// Changes which bring a namespace to the desired state, in the order they are applied
+#[derive(Debug, Clone, Default)]
+pub struct Plan {
+    /// Name of the project
+    pub project: String,
+    /// Changes of the plan, including no-ops
+    pub changes: Vec<Change>,
+}
+
+impl Plan {
+    /// Returns true when applying the plan changes the namespace
+    pub fn has_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.kind != ChangeKind::NoOp)
+    }
+
+    /// Returns the changes of a kind
+    ///
+    /// # Arguments
+    /// * `kind` - Kind of the changes
+    pub fn changes_of(&self, kind: ChangeKind) -> Vec<&Change> {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == kind)
+            .collect()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            let (symbol, verb) = match change.kind {
+                ChangeKind::Create => ("+", "create"),
+                ChangeKind::Update => ("~", "update"),
+                ChangeKind::Delete => ("-", "delete"),
+                ChangeKind::NoOp => ("=", "unchanged"),
+            };
+
+            write!(
+                f,
+                "{} {} {} {}",
+                symbol,
+                verb,
+                change.entity.kind(),
+                change.entity.name()
+            )?;
+
+            if !change.differences.is_empty() {
+                write!(f, " ({})", change.differences.join(", "))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Clone> OpenwhiskClient<T>
+where
+    T: Service + OpenWhisk + OpenWhisk<Output = T>,
+{
+    /// Compares a project against the live namespace and returns the changes which bring
+    /// the namespace to the project, without changing anything.
+    /// Live entities which carry the managed annotation of the project but are no longer
+    /// desired are deleted, rules are deleted when their trigger belongs to the project.
+    /// Feed parameters and API Gateway routes are not compared
+    ///
+    /// # Arguments
+    /// * `project` - The desired state
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openwhisk_rust::{Manifest, NativeClient, OpenwhiskClient};
+    ///
+    /// let client = OpenwhiskClient::<NativeClient>::try_new(None).unwrap();
+    ///
+    /// let project = Manifest::from_file("manifest.yaml").unwrap().project("guest").unwrap();
+    /// let plan = client.plan(&project).unwrap();
+    /// println!("{}", plan);
+    ///
+    /// client.apply(&plan).unwrap();
+    ///
+    /// ```
+    pub fn plan(&self, project: &Project) -> Result<Plan, OpenWhiskError> {
+        let mut changes = Vec::new();
+
+        for package in &project.packages {
+            let live = live(self.packages().get(&package.name))?;
+            changes.push(change(
+                Entity::Package(package.clone()),
+                live.map(|live| package_differences(package, &live)),
+            ));
+        }
+
+        for action in project.actions.iter().chain(project.sequences.iter()) {
+            let live = live(self.actions().get(&action.name, true))?;
+            changes.push(change(
+                Entity::Action(action.clone()),
+                live.map(|live| {
+                    let namespace = resolved_namespace(self.context.namespace(), &live.namespace);
+                    action_differences(action, &live, namespace)
+                }),
+            ));
+        }
+
+        for trigger in &project.triggers {
+            let live = live(self.triggers().get(&trigger.trigger.name))?;
+            changes.push(change(
+                Entity::Trigger(trigger.clone()),
+                live.map(|live| trigger_differences(trigger, &live)),
+            ));
+        }
+
+        for rule in &project.rules {
+            let live = live(self.rules().get(&rule.name))?;
+            changes.push(change(
+                Entity::Rule(rule.clone()),
+                live.map(|live| {
+                    let namespace = resolved_namespace(self.context.namespace(), &live.namespace);
+                    rule_differences(rule, &live, namespace)
+                }),
+            ));
+        }
+
+        changes.extend(self.deletes(project)?);
+
+        Ok(Plan {
+            project: project.name.clone(),
+            changes,
+        })
+    }
+
+    /// Applies the changes of a plan, no-ops are skipped
+    ///
+    /// # Arguments
+    /// * `plan` - The plan returned by `plan`
+    pub fn apply(&self, plan: &Plan) -> Result<(), OpenWhiskError> {
+        for change in &plan.changes {
+            let overwrite = change.kind == ChangeKind::Update;
+
+            match (change.kind, &change.entity) {
+                (ChangeKind::NoOp, _) => {}
+                (ChangeKind::Delete, Entity::Package(package)) => {
+                    skip_not_found(self.packages().delete(&package.name))?
+                }
+                (ChangeKind::Delete, Entity::Action(action)) => {
+                    skip_not_found(self.actions().delete(&action.name))?
+                }
+                (ChangeKind::Delete, Entity::Trigger(trigger)) => self.delete_trigger(trigger)?,
+                (ChangeKind::Delete, Entity::Rule(rule)) => {
+                    skip_not_found(self.rules().delete(&rule.name))?
+                }
+                (_, Entity::Package(package)) => {
+                    self.packages().insert(package, overwrite)?;
+                }
+                (_, Entity::Action(action)) => {
+                    self.actions().insert(action, overwrite)?;
+                }
+                (_, Entity::Trigger(trigger)) => self.create_trigger(trigger)?,
+                (_, Entity::Rule(rule)) => {
+                    self.rules().insert(rule, overwrite)?;
+
+                    // Inserting a rule leaves its state unchanged, new rules are active
+                    if needs_state(change, rule) {
+                        self.rules().set_state(&rule.name, &rule.status)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the deletes of the live entities managed by the project which are no longer desired,
+    /// ordered rules, triggers, actions and packages
+    fn deletes(&self, project: &Project) -> Result<Vec<Change>, OpenWhiskError> {
+        if project.name.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut deletes = Vec::new();
+
+        let desired_triggers: HashSet<&str> = project
+            .triggers
+            .iter()
+            .map(|trigger| trigger.trigger.name.as_str())
+            .collect();
+        let mut project_triggers: HashSet<String> = desired_triggers
+            .iter()
+            .map(|trigger| trigger.to_string())
+            .collect();
+        let mut trigger_deletes = Vec::new();
+
+        for trigger in self.triggers().iter(TriggerListOptions::default()) {
+            let trigger = trigger?;
+
+            if !project.manages(&trigger.annotations) {
+                continue;
+            }
+
+            project_triggers.insert(trigger.name.clone());
+
+            if !desired_triggers.contains(trigger.name.as_str()) {
+                let feed = annotation(&trigger.annotations, "feed")
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+
+                trigger_deletes.push(delete(Entity::Trigger(ProjectTrigger { trigger, feed })));
+            }
+        }
+
+        let desired_rules: HashSet<&str> = project
+            .rules
+            .iter()
+            .map(|rule| rule.name.as_str())
+            .collect();
+
+        for rule in self.rules().iter(RuleListOptions::default()) {
+            let rule = rule?;
+
+            let trigger = rule
+                .trigger
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+
+            if project_triggers.contains(trigger) && !desired_rules.contains(rule.name.as_str()) {
+                deletes.push(delete(Entity::Rule(Rule {
+                    name: rule.name,
+                    ..Default::default()
+                })));
+            }
+        }
+
+        deletes.extend(trigger_deletes);
+
+        let desired_actions: HashSet<&str> = project
+            .actions
+            .iter()
+            .chain(project.sequences.iter())
+            .map(|action| action.name.as_str())
+            .collect();
+
+        for action in self.actions().iter(ActionListOptions::default()) {
+            let action = action?;
+            let name = entity_name(&action.namespace, &action.name);
+
+            if desired_actions.contains(name.as_str()) {
+                continue;
+            }
+
+            // Listed actions carry no annotations, so they are read one by one
+            if let Some(live) = live(self.actions().get(&name, false))? {
+                if project.manages(&live.annotations) {
+                    deletes.push(delete(Entity::Action(Action { name, ..live })));
+                }
+            }
+        }
+
+        let desired_packages: HashSet<&str> = project
+            .packages
+            .iter()
+            .map(|package| package.name.as_str())
+            .collect();
+
+        for package in self.packages().iter(PackageListOptions::default()) {
+            let package = package?;
+
+            if project.manages(&package.annotations)
+                && !desired_packages.contains(package.name.as_str())
+            {
+                deletes.push(delete(Entity::Package(package)));
+            }
+        }
+
+        Ok(deletes)
+    }
+}
+
+/// Returns the live entity, or None when it does not exist
+fn live<V>(result: Result<V, OpenWhiskError>) -> Result<Option<V>, OpenWhiskError> {
+    match result {
+        Ok(entity) => Ok(Some(entity)),
+        Err(OpenWhiskError::NotFound(_)) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the change for a desired entity, given the differences with the live entity if it exists
+fn change(entity: Entity, differences: Option<Vec<String>>) -> Change {
+    match differences {
+        None => Change {
+            kind: ChangeKind::Create,
+            entity,
+            differences: vec![],
+        },
+        Some(differences) if differences.is_empty() => Change {
+            kind: ChangeKind::NoOp,
+            entity,
+            differences,
+        },
+        Some(differences) => Change {
+            kind: ChangeKind::Update,
+            entity,
+            differences,
+        },
+    }
+}
+
+fn delete(entity: Entity) -> Change {
+    Change {
+        kind: ChangeKind::Delete,
+        entity,
+        differences: vec![],
+    }
+}
+
+/// Returns the `package/action` name of a listed action, whose namespace holds the package
+fn entity_name(namespace: &str, name: &str) -> String {
+    match namespace.split_once('/') {
+        Some((_, package)) => format!("{}/{}", package, name),
+        None => name.to_string(),
+    }
+}
+
+/// Returns the namespace the default namespace `_` stands for, read from the namespace
+/// of a live entity, other namespaces are returned as they are
+fn resolved_namespace<'a>(namespace: &'a str, live_namespace: &'a str) -> &'a str {
+    match (namespace, live_namespace.split('/').next()) {
+        ("_", Some(live_namespace)) if !live_namespace.is_empty() => live_namespace,
+        _ => namespace,
+    }
+}
+
+/// Returns a fully qualified name with the default namespace `_` replaced by `namespace`
+fn resolved_name(name: &str, namespace: &str) -> String {
+    match name.strip_prefix("/_/") {
+        Some(name) => format!("/{}/{}", namespace, name),
+        None => name.to_string(),
+    }
+}
+
+fn package_differences(desired: &Package, live: &Package) -> Vec<String> {
+    let mut differences = Vec::new();
+
+    if desired.publish != live.publish {
+        differences.push("publish".to_string());
+    }
+    if !same_key_values(&desired.parameters, &live.parameters) {
+        differences.push("parameters".to_string());
+    }
+    if !same_annotations(&desired.annotations, &live.annotations) {
+        differences.push("annotations".to_string());
+    }
+
+    differences
+}
+
+fn action_differences(desired: &Action, live: &Action, namespace: &str) -> Vec<String> {
+    let mut differences = Vec::new();
+
+    if !same_exec(&desired.exec, &live.exec, namespace) {
+        differences.push("exec".to_string());
+    }
+    if !same_key_values(&desired.parameters, &live.parameters) {
+        differences.push("parameters".to_string());
+    }
+    if !same_annotations(&desired.annotations, &live.annotations) {
+        differences.push("annotations".to_string());
+    }
+    if let Some(limits) = &desired.limits {
+        if !same_limits(limits, &live.limits.clone().unwrap_or_default()) {
+            differences.push("limits".to_string());
+        }
+    }
+
+    differences
+}
+
+fn trigger_differences(desired: &ProjectTrigger, live: &Trigger) -> Vec<String> {
+    let mut differences = Vec::new();
+
+    // The parameters of feed triggers are handed to the feed, not stored with the trigger
+    if desired.feed.is_none() && !same_key_values(&desired.trigger.parameters, &live.parameters) {
+        differences.push("parameters".to_string());
+    }
+    if !same_annotations(&desired.trigger.annotations, &live.annotations) {
+        differences.push("annotations".to_string());
+    }
+
+    differences
+}
+
+fn rule_differences(desired: &Rule, live: &RuleResponse, namespace: &str) -> Vec<String> {
+    let mut differences = Vec::new();
+
+    if desired.trigger != path_name(&live.trigger, namespace) {
+        differences.push("trigger".to_string());
+    }
+    if desired.action != path_name(&live.action, namespace) {
+        differences.push("action".to_string());
+    }
+    if !desired.status.is_empty() && desired.status != live.status {
+        differences.push("status".to_string());
+    }
+
+    differences
+}
+
+/// Returns true when the state of a created or updated rule must be set after inserting it
+fn needs_state(change: &Change, rule: &Rule) -> bool {
+    match change.kind {
+        ChangeKind::Create => {
+            !rule.status.is_empty() && !rule.status.eq_ignore_ascii_case("active")
+        }
+        ChangeKind::Update => change
+            .differences
+            .iter()
+            .any(|difference| difference == "status"),
+        _ => false,
+    }
+}
+
+/// Returns the `[package/]name` of an entity referenced by a rule as `{"path", "name"}`
+fn path_name(entity: &Value, namespace: &str) -> String {
+    let name = entity
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let path = entity
+        .get("path")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    match path
+        .strip_prefix(namespace)
+        .and_then(|path| path.strip_prefix('/'))
+    {
+        Some(package) if !package.is_empty() => format!("{}/{}", package, name),
+        _ => name.to_string(),
+    }
+}
+
+/// Compares executables by kind, code, entry point, image and components.
+/// The entry point is only compared when the desired executable sets it, components in the
+/// default namespace are compared in `namespace`
+fn same_exec(desired: &Exec, live: &Exec, namespace: &str) -> bool {
+    let same_main = match (desired, live) {
+        (Exec::Code(desired), Exec::Code(live)) => {
+            desired.main.is_none() || desired.main == live.main
+        }
+        (Exec::Blackbox(desired), Exec::Blackbox(live)) => {
+            desired.image == live.image && (desired.main.is_none() || desired.main == live.main)
+        }
+        (Exec::Sequence(desired), Exec::Sequence(live)) => desired
+            .components
+            .iter()
+            .map(|component| resolved_name(component, namespace))
+            .eq(live.components.iter().cloned()),
+        _ => false,
+    };
+
+    same_main
+        && desired.kind() == live.kind()
+        && desired.is_binary() == live.is_binary()
+        && desired.source() == live.source()
+}
+
+/// Compares the limits which are set in the desired limits
+fn same_limits(desired: &Limits, live: &Limits) -> bool {
+    let same = |desired: Option<i64>, live: Option<i64>| desired.is_none() || desired == live;
+
+    same(desired.timeout, live.timeout)
+        && same(desired.memory, live.memory)
+        && same(desired.logsize, live.logsize)
+        && same(desired.concurrency, live.concurrency)
+}
+
+fn same_key_values(desired: &[KeyValue], live: &[KeyValue]) -> bool {
+    sorted(desired.iter()) == sorted(live.iter())
+}
+
+fn same_annotations(desired: &[KeyValue], live: &[KeyValue]) -> bool {
+    sorted(user_annotations(desired)) == sorted(user_annotations(live))
+}
+
+/// Returns the annotations which are not added by OpenWhisk
+fn user_annotations(annotations: &[KeyValue]) -> impl Iterator<Item = &KeyValue> {
+    annotations
+        .iter()
+        .filter(|annotation| !SERVER_ANNOTATIONS.contains(&annotation.key.as_str()))
+}
+
+fn sorted<'a>(key_values: impl Iterator<Item = &'a KeyValue>) -> Vec<(&'a str, &'a Value)> {
+    let mut key_values: Vec<(&str, &Value)> = key_values
+        .map(|key_value| (key_value.key.as_str(), &key_value.value))
+        .collect();
+    key_values.sort_by(|a, b| a.0.cmp(b.0));
+
+    key_values
+}
+
+fn annotation<'a>(annotations: &'a [KeyValue], key: &str) -> Option<&'a Value> {
+    annotations
+        .iter()
+        .find(|annotation| annotation.key == key)
+        .map(|annotation| &annotation.value)
+}
@@ -1,12 +1,35 @@
 mod api;
 mod client;
+mod deploy;
 pub use api::{
-    Action, ActionList, Exec, HttpMethods, KeyValue, Limits, Rule, RuleResponse, Service, Trigger,
+    runtime_for_extension, zip_dir, Action, ActionList, ActionListOptions, ActionTree, Activation,
+    ActivationId, ActivationLogs, ActivationResponse, Api, ApiError, ApiItem, ApiList,
+    AsyncListIterator, AsyncService, BlackboxExec, CodeExec, Exec, FeedLifecycleEvent, HttpMethods,
+    HttpResponse, KeyValue, Limits, ListIterator, OpenWhiskError, Package, PackageAction,
+    PackageBinding, PackageListOptions, PollOptions, Route, Rule, RuleListOptions, RuleResponse,
+    Runtime, SequenceExec, Service, Trigger, TriggerListOptions, TypedActivation, WebExtension,
+    WebRequest, KNOWN_RUNTIME_KINDS,
+};
+pub use client::{
+    AccessToken, AuthProvider, BasicAuth, BearerToken, HeaderAuth, OpenWhisk, OpenwhiskClient,
+    ProxySettings, RefreshingToken, RetryPolicy, TlsVersion, WskProperties, DEFAULT_RETRY_METHODS,
+    DEFAULT_RETRY_STATUSES, DEFAULT_TIMEOUT,
+};
+pub use deploy::{
+    ActionManifest, ApiManifest, Change, ChangeKind, Deployment, Entity, EntityDeployment,
+    LimitsManifest, Manifest, PackageDeployment, PackageManifest, Plan, Project, ProjectDeployment,
+    ProjectManifest, ProjectTrigger, RuleManifest, SequenceManifest, TriggerManifest,
+};
+
+#[cfg(feature = "testing")]
+pub use client::{
+    Cassette, EmulatorClient, EmulatorRequest, Interaction, LocalActivation, MatchMode,
+    RecordedBody, RecordedRequest, RecordedResponse, RecordingClient, RecordingRequest,
+    ReplayClient,
 };
-pub use client::{OpenWhisk, OpenwhiskClient, WskProperties};
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use client::NativeClient;
+pub use client::{AsyncNativeClient, NativeClient};
 
 #[cfg(target_arch = "wasm32")]
-pub use client::WasmClient;
\ No newline at end of file
+pub use client::WasmClient;
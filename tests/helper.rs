@@ -1,7 +1,11 @@
-use openwhisk_client_rust::{Action, Exec, KeyValue, Limits, RuleResponse, Trigger};
+use openwhisk_client_rust::{
+    Action, Activation, ActivationResponse, Exec, KeyValue, Limits, Package, PackageAction,
+    PackageBinding, Project, RuleResponse, Runtime, Trigger,
+};
 use serde_json::json;
+use std::time::Duration;
 use wiremock::{
-    matchers::{method, path, query_param},
+    matchers::{body_partial_json, body_string, header, header_exists, method, path, query_param},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -16,15 +20,7 @@ fn action_data() -> Action {
             logsize: Some(3),
             concurrency: Some(3),
         }),
-        exec: Exec {
-            kind: "rust:1.34".to_string(),
-            code: "bas64_data".to_string(),
-            image: "openwhisk/action-rust-v1.34".to_string(),
-            init: "".to_string(),
-            main: "".to_string(),
-            components: vec![],
-            binary: true,
-        },
+        exec: Exec::binary(Runtime::RUST_1_34, "bas64_data"),
         error: "".to_string(),
         publish: true,
         updated: 0,
@@ -52,6 +48,51 @@ fn trigger_data() -> Trigger {
     }
 }
 
+fn package_data() -> Package {
+    Package {
+        namespace: "guest".to_string(),
+        name: "utils".to_string(),
+        publish: false,
+        actions: vec![PackageAction {
+            name: "cars".to_string(),
+            version: "0.0.1".to_string(),
+            annotations: vec![],
+        }],
+        ..Default::default()
+    }
+}
+
+fn binding_data() -> Package {
+    Package {
+        namespace: "guest".to_string(),
+        name: "utils_binding".to_string(),
+        binding: Some(PackageBinding::new(
+            "guest".to_string(),
+            "utils".to_string(),
+        )),
+        ..Default::default()
+    }
+}
+
+fn activation_data() -> Activation {
+    Activation {
+        namespace: "guest".to_string(),
+        name: "cars".to_string(),
+        activation_id: "44794bd6aab74415b4e42a308d880e5b".to_string(),
+        start: 1000,
+        end: 1010,
+        duration: 10,
+        response: ActivationResponse {
+            status: "success".to_string(),
+            success: true,
+            result: json!({"greeting": "hello"}),
+            ..Default::default()
+        },
+        logs: vec!["2022-05-10T10:00:00.000Z stdout: hello".to_string()],
+        ..Default::default()
+    }
+}
+
 async fn create_server() -> MockServer {
     MockServer::start().await
 }
@@ -125,6 +166,94 @@ pub async fn get() -> MockServer {
         .mount(&server)
         .await;
 
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions/my%20utils/cars"))
+        .and(query_param("code", "false"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(action_data()),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/packages"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!([package_data(), binding_data()])),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/packages/utils"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({
+                    "namespace": "guest",
+                    "name": "utils",
+                    "version": "0.0.1",
+                    "publish": false,
+                    "binding": {},
+                    "annotations": [],
+                    "parameters": [],
+                    "actions": [{ "name": "cars", "version": "0.0.1", "annotations": [] }],
+                    "feeds": [],
+                    "updated": 0
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/activations"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(vec![activation_data()]),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v1/namespaces/guest/activations/44794bd6aab74415b4e42a308d880e5b",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(activation_data()),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v1/namespaces/guest/activations/44794bd6aab74415b4e42a308d880e5b/logs",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({ "logs": activation_data().logs })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v1/namespaces/guest/activations/44794bd6aab74415b4e42a308d880e5b/result",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(activation_data().response),
+        )
+        .mount(&server)
+        .await;
+
     server
 }
 
@@ -172,6 +301,26 @@ pub async fn delete() -> MockServer {
         )
         .mount(&server)
         .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/namespaces/guest/packages/utils"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(package_data()),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/packages"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!([])),
+        )
+        .mount(&server)
+        .await;
     server
 }
 
@@ -225,5 +374,1055 @@ pub async fn put(action: Option<Action>) -> MockServer {
         )
         .mount(&server)
         .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/packages/utils"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({
+                    "namespace": "guest",
+                    "name": "utils",
+                    "version": "0.0.1",
+                    "publish": false,
+                    "binding": {},
+                    "annotations": [{ "key": "description", "value": "utilities" }],
+                    "parameters": [],
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/namespaces/guest/packages/utils"))
+        .and(query_param("overwrite", "true"))
+        .and(body_partial_json(json!({
+            "publish": true,
+            "annotations": [{ "key": "description", "value": "utilities" }]
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(Package {
+                    publish: true,
+                    ..package_data()
+                }),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/namespaces/guest/packages/utils"))
+        .and(query_param("overwrite", "false"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(package_data()),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/namespaces/guest/packages/utils_binding"))
+        .and(body_partial_json(
+            json!({ "binding": { "namespace": "guest", "name": "utils" } }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(binding_data()),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+pub async fn errors() -> MockServer {
+    let server = create_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions/missing"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({
+                    "code": "4XwKX5V7mXa0cOJwWkOHcP4PMbMFKa1T",
+                    "error": "The requested resource does not exist."
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/namespaces/guest/triggers/trigger"))
+        .and(query_param("overwrite", "false"))
+        .respond_with(
+            ResponseTemplate::new(409)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({
+                    "code": "bb8d2a9c1d2f4b7e2f2b1c8d9e0a1b2c",
+                    "error": "resource already exists"
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/rules"))
+        .respond_with(ResponseTemplate::new(502).set_body_string("Bad Gateway"))
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn pages() -> MockServer {
+    let server = create_server().await;
+
+    let actions: Vec<Action> = ["cars", "bikes", "trucks"]
+        .iter()
+        .map(|name| Action {
+            name: name.to_string(),
+            ..action_data()
+        })
+        .collect();
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions"))
+        .and(query_param("limit", "2"))
+        .and(query_param("skip", "0"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(&actions[..2]),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions"))
+        .and(query_param("limit", "2"))
+        .and(query_param("skip", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(&actions[2..]),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/rules"))
+        .and(query_param("limit", "1"))
+        .and(query_param("skip", "0"))
+        .and(query_param("docs", "true"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(vec![rule_data()]),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/triggers"))
+        .and(query_param("limit", "200"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(vec![trigger_data()]),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/packages"))
+        .and(query_param("public", "true"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(vec![package_data()]),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn routes() -> MockServer {
+    let server = create_server().await;
+
+    let api = json!({
+        "namespace": "guest",
+        "gwApiUrl": "https://gateway/api/23bc46b1/hello",
+        "gwApiActivated": true,
+        "apidoc": {
+            "swagger": "2.0",
+            "basePath": "/hello",
+            "info": { "title": "/hello", "version": "1.0.0" },
+            "paths": { "/world": { "get": {} } }
+        }
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/web/whisk.system/apimgmt/createApi.http"))
+        .and(body_partial_json(json!({
+            "apidoc": {
+                "action": {
+                    "name": "demo/cars",
+                    "namespace": "guest",
+                    "backendUrl": format!("{}/api/v1/web/guest/demo/cars.json", server.uri())
+                }
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(api.clone()),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/web/whisk.system/apimgmt/createApi.http"))
+        .and(query_param(
+            "spaceguid",
+            "23bc46b1-71f6-4ed5-8c54-816aa4f8c502",
+        ))
+        .and(header("apigw-access-token", "apigw-token"))
+        .and(query_param("responsetype", "json"))
+        .and(body_partial_json(json!({
+            "apidoc": {
+                "gatewayBasePath": "/hello",
+                "gatewayPath": "/world",
+                "gatewayMethod": "GET",
+                "action": { "name": "cars", "backendUrl": format!("{}/api/v1/web/guest/default/cars.json", server.uri()) }
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(api.clone()),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/web/whisk.system/apimgmt/getApi.http"))
+        .and(query_param("basepath", "/hello"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({
+                    "apis": [{ "id": "API:guest:/hello", "key": "API:guest:/hello", "value": api }]
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/web/whisk.system/apimgmt/deleteApi.http"))
+        .and(query_param("basepath", "/hello"))
+        .and(query_param("relpath", "/world"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({})),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn web() -> MockServer {
+    let server = create_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/web/guest/default/hello.http"))
+        .and(query_param("name", "open whisk"))
+        .and(header("Content-Type", "text/plain"))
+        .and(header("X-Require-Whisk-Auth", "secret"))
+        .and(body_string("raw body"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .insert_header("Content-Type", "text/plain")
+                .insert_header("X-Custom", "value")
+                .set_body_string("hello open whisk"),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/web/guest/demo/cars.json"))
+        .and(header_exists("Authorization"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({"cars": ["tesla"]})),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v1/web/guest/default/hello.json"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Authentication is possible but has failed or not yet been provided.",
+            "code": "4ac7d31"
+        })))
+        .mount(&server)
+        .await;
+
+    server
+}
+
+fn sequence_data(namespace: &str, name: &str, exec: Exec) -> serde_json::Value {
+    json!({
+        "namespace": namespace,
+        "name": name,
+        "version": "0.0.1",
+        "exec": exec,
+        "annotations": [],
+        "parameters": []
+    })
+}
+
+pub async fn sequences() -> MockServer {
+    let server = create_server().await;
+
+    let actions = vec![
+        (
+            "/api/v1/namespaces/guest/actions/pipeline",
+            sequence_data(
+                "guest",
+                "pipeline",
+                Exec::sequence(vec![
+                    "/guest/utils/split".to_string(),
+                    "/whisk.system/utils/echo".to_string(),
+                ]),
+            ),
+        ),
+        (
+            "/api/v1/namespaces/guest/actions/utils/split",
+            sequence_data(
+                "guest/utils",
+                "split",
+                Exec::sequence(vec!["/guest/sort".to_string()]),
+            ),
+        ),
+        (
+            "/api/v1/namespaces/guest/actions/sort",
+            sequence_data("guest", "sort", Exec::code(Runtime::NODEJS_20, "")),
+        ),
+        (
+            "/api/v1/namespaces/whisk.system/actions/utils/echo",
+            sequence_data(
+                "whisk.system/utils",
+                "echo",
+                Exec::code(Runtime::NODEJS_20, ""),
+            ),
+        ),
+        (
+            "/api/v1/namespaces/guest/actions/loop",
+            sequence_data(
+                "guest",
+                "loop",
+                Exec::sequence(vec!["/guest/loop".to_string()]),
+            ),
+        ),
+    ];
+
+    for (action_path, action) in actions {
+        Mock::given(method("GET"))
+            .and(path(action_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Type", "application/json")
+                    .set_body_json(action),
+            )
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/namespaces/guest/actions/pipeline"))
+        .and(body_partial_json(json!({
+            "exec": {
+                "kind": "sequence",
+                "components": ["/guest/utils/split", "/whisk.system/utils/echo"]
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(sequence_data(
+                    "guest",
+                    "pipeline",
+                    Exec::sequence(vec![
+                        "/guest/utils/split".to_string(),
+                        "/whisk.system/utils/echo".to_string(),
+                    ]),
+                )),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/api/v1/namespaces/whisk.system/actions/utils/pipeline",
+        ))
+        .and(query_param("overwrite", "true"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(sequence_data(
+                    "whisk.system/utils",
+                    "pipeline",
+                    Exec::sequence(vec!["/guest/sort".to_string()]),
+                )),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v1/namespaces/whisk.system/actions/utils/pipeline",
+        ))
+        .and(query_param("code", "false"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(sequence_data(
+                    "whisk.system/utils",
+                    "pipeline",
+                    Exec::sequence(vec!["/guest/sort".to_string()]),
+                )),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn deployments() -> MockServer {
+    let server = create_server().await;
+
+    Mock::given(wiremock::matchers::any())
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({
+                    "namespace": "guest",
+                    "name": "entity",
+                    "version": "0.0.1",
+                    "exec": { "kind": "nodejs:20", "code": "", "binary": false },
+                    "annotations": [],
+                    "parameters": []
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+fn entity_path(namespace: &str, collection: &str, name: &str) -> String {
+    format!("/api/v1/namespaces/{}/{}/{}", namespace, collection, name)
+}
+
+/// Returns `namespace` and `name` of a `[package/]name` entity as listed by OpenWhisk
+fn listed_name(name: &str) -> (String, String) {
+    match name.split_once('/') {
+        Some((package, name)) => (format!("guest/{}", package), name.to_string()),
+        None => ("guest".to_string(), name.to_string()),
+    }
+}
+
+/// Serves the entities of a deployed project, other entities are missing and writes succeed
+pub async fn reconciler(live: &Project) -> MockServer {
+    reconciler_in("guest", live).await
+}
+
+/// Serves the entities of a project deployed to `guest` for requests to `namespace`,
+/// e.g. the default namespace `_`
+pub async fn reconciler_in(namespace: &str, live: &Project) -> MockServer {
+    let server = create_server().await;
+
+    let mut packages = vec![];
+    for package in &live.packages {
+        let package = Package {
+            namespace: "guest".to_string(),
+            ..package.clone()
+        };
+
+        Mock::given(method("GET"))
+            .and(path(entity_path(namespace, "packages", &package.name)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&package))
+            .mount(&server)
+            .await;
+
+        packages.push(package);
+    }
+
+    let mut actions = vec![];
+    for action in live.actions.iter().chain(live.sequences.iter()) {
+        let (listed_namespace, name) = listed_name(&action.name);
+        let action_path = entity_path(namespace, "actions", &action.name);
+        let action = Action {
+            namespace: listed_namespace,
+            name,
+            ..action.clone()
+        };
+
+        Mock::given(method("GET"))
+            .and(path(action_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&action))
+            .mount(&server)
+            .await;
+
+        actions.push(action);
+    }
+
+    let mut triggers = vec![];
+    for project_trigger in &live.triggers {
+        let trigger = Trigger {
+            namespace: "guest".to_string(),
+            ..project_trigger.trigger.clone()
+        };
+
+        Mock::given(method("GET"))
+            .and(path(entity_path(namespace, "triggers", &trigger.name)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&trigger))
+            .mount(&server)
+            .await;
+
+        triggers.push(trigger);
+    }
+
+    let mut rules = vec![];
+    for rule in &live.rules {
+        let (action_path, action_name) = listed_name(&rule.action);
+        let rule = RuleResponse {
+            namespace: "guest".to_string(),
+            name: rule.name.clone(),
+            status: rule.status.clone(),
+            trigger: json!({ "path": "guest", "name": rule.trigger }),
+            action: json!({ "path": action_path, "name": action_name }),
+            ..Default::default()
+        };
+
+        Mock::given(method("GET"))
+            .and(path(entity_path(namespace, "rules", &rule.name)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&rule))
+            .mount(&server)
+            .await;
+
+        rules.push(rule);
+    }
+
+    for (collection, entities) in [
+        ("packages", json!(packages)),
+        ("actions", json!(actions)),
+        ("triggers", json!(triggers)),
+        ("rules", json!(rules)),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/api/v1/namespaces/{}/{}",
+                namespace, collection
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(entities))
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "code": "4XwKX5V7mXa0cOJwWkOHcP4PMbMFKa1T",
+            "error": "The requested resource does not exist."
+        })))
+        .with_priority(10)
+        .mount(&server)
+        .await;
+
+    Mock::given(wiremock::matchers::any())
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "namespace": "guest",
+            "name": "entity",
+            "exec": { "kind": "nodejs:20", "code": "", "binary": false },
+            "annotations": [],
+            "parameters": []
+        })))
+        .with_priority(10)
+        .mount(&server)
+        .await;
+
+    server
+}
+
+/// Accepts non-blocking invokes of `cars`, whose activation is not found for the first `pending` polls.
+/// The activation of `stuck` never completes
+pub async fn polling(pending: u64) -> MockServer {
+    let server = create_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/actions/cars"))
+        .and(query_param("blocking", "false"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({ "activationId": activation_data().activation_id })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/actions/stuck"))
+        .and(query_param("blocking", "false"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({ "activationId": "stuck" })),
+        )
+        .mount(&server)
+        .await;
+
+    if pending > 0 {
+        Mock::given(method("GET"))
+            .and(path(
+                "/api/v1/namespaces/guest/activations/44794bd6aab74415b4e42a308d880e5b",
+            ))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .insert_header("Content-Type", "application/json")
+                    .set_body_json(json!({
+                        "code": "4XwKX5V7mXa0cOJwWkOHcP4PMbMFKa1T",
+                        "error": "The requested resource does not exist."
+                    })),
+            )
+            .up_to_n_times(pending)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v1/namespaces/guest/activations/44794bd6aab74415b4e42a308d880e5b",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(activation_data()),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/activations/stuck"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({
+                    "code": "4XwKX5V7mXa0cOJwWkOHcP4PMbMFKa1T",
+                    "error": "The requested resource does not exist."
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn typed() -> MockServer {
+    let server = create_server().await;
+
+    let activation = |success: bool, result: serde_json::Value| Activation {
+        response: ActivationResponse {
+            status: if success {
+                "success".to_string()
+            } else {
+                "application error".to_string()
+            },
+            success,
+            result,
+            ..Default::default()
+        },
+        ..activation_data()
+    };
+
+    for (action, status, result_body, activation_body) in [
+        (
+            "add",
+            200,
+            json!({ "sum": 3 }),
+            json!(activation(true, json!({ "sum": 3 }))),
+        ),
+        (
+            "fail",
+            502,
+            json!({ "error": "boom" }),
+            json!(activation(false, json!({ "error": "boom" }))),
+        ),
+    ] {
+        Mock::given(method("POST"))
+            .and(path(format!("/api/v1/namespaces/guest/actions/{}", action)))
+            .and(query_param("blocking", "true"))
+            .and(query_param("result", "true"))
+            .and(body_partial_json(json!({ "a": 1, "b": 2 })))
+            .respond_with(
+                ResponseTemplate::new(status)
+                    .insert_header("Content-Type", "application/json")
+                    .set_body_json(result_body),
+            )
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path(format!("/api/v1/namespaces/guest/actions/{}", action)))
+            .and(query_param("blocking", "true"))
+            .and(query_param("result", "false"))
+            .and(body_partial_json(json!({ "a": 1, "b": 2 })))
+            .respond_with(
+                ResponseTemplate::new(status)
+                    .insert_header("Content-Type", "application/json")
+                    .set_body_json(activation_body),
+            )
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/actions/unreachable"))
+        .respond_with(ResponseTemplate::new(502).set_body_string("Bad Gateway"))
+        .mount(&server)
+        .await;
+
+    server
+}
+
+/// Fails requests on `cars` with 503 `failures` times before answering them.
+/// Requests on `busy` are always throttled, asking to retry after `retry_after` seconds
+pub async fn retries(failures: u64, retry_after: u64) -> MockServer {
+    let server = create_server().await;
+
+    Mock::given(path("/api/v1/namespaces/guest/actions/cars"))
+        .respond_with(
+            ResponseTemplate::new(503)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({
+                    "code": "4XwKX5V7mXa0cOJwWkOHcP4PMbMFKa1T",
+                    "error": "The server is currently unavailable."
+                })),
+        )
+        .up_to_n_times(failures)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions/cars"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(action_data()),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/actions/cars"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({ "greeting": "hello" })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(path("/api/v1/namespaces/guest/actions/busy"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Content-Type", "application/json")
+                .insert_header("Retry-After", retry_after.to_string().as_str())
+                .set_body_json(json!({
+                    "code": "4XwKX5V7mXa0cOJwWkOHcP4PMbMFKa1T",
+                    "error": "Too many requests in the last minute."
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn responses() -> MockServer {
+    let server = create_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/triggers/trigger"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .insert_header("Content-Type", "application/json")
+                .insert_header("x-request-id", "a8f7e3c2b1d04e6f")
+                .set_body_json(json!({ "activationId": "fa1cb5a8c4ae4b6e9cb5a8c4aedb6e3f" })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/triggers/idle"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/actions/cars"))
+        .and(query_param("blocking", "false"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({ "activationId": activation_data().activation_id })),
+        )
+        .mount(&server)
+        .await;
+
+    // The blocking invoke of `slow` outlasts the wait of the controller
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/actions/slow"))
+        .and(query_param("blocking", "true"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .insert_header("Content-Type", "application/json")
+                .set_body_json(json!({ "activationId": activation_data().activation_id })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions/cars"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "text/html")
+                .set_body_string("<html>maintenance</html>"),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions/broken"))
+        .respond_with(
+            ResponseTemplate::new(500)
+                .insert_header("Content-Type", "text/plain")
+                .set_body_string("internal failure"),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/web/guest/default/hello.http"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "x-openwhisk-activation-id",
+                    "44794bd6aab74415b4e42a308d880e5b",
+                )
+                .insert_header("x-request-id", "a8f7e3c2b1d04e6f")
+                .set_body_string("hello"),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn auth() -> MockServer {
+    let server = create_server().await;
+    let basic = base64::encode(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP",
+    );
+
+    for (name, value) in [
+        ("Authorization", format!("Basic {}", basic)),
+        ("Authorization", "Bearer token-1".to_string()),
+        ("Authorization", "Bearer token-2".to_string()),
+        ("X-Api-Key", "gateway-key".to_string()),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/namespaces/guest/actions"))
+            .and(header(name, value.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(
+            json!({ "code": "4f2a", "error": "The supplied authentication is invalid" }),
+        ))
+        .with_priority(10)
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn timeouts(delay: Duration) -> MockServer {
+    let server = create_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([]))
+                .set_delay(delay),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+pub async fn cli() -> MockServer {
+    let server = create_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions"))
+        .and(query_param("limit", "30"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![
+            action_data(),
+            Action {
+                name: "trucks".to_string(),
+                ..action_data()
+            },
+        ]))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions/cars"))
+        .and(query_param("code", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(action_data()))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/namespaces/guest/actions/hello"))
+        .and(query_param("overwrite", "false"))
+        .and(body_partial_json(json!({
+            "exec": { "kind": "nodejs:default", "main": "greet" },
+            "parameters": [{ "key": "name", "value": "world" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "namespace": "guest",
+            "name": "hello",
+            "exec": { "kind": "nodejs:default", "code": "function greet() {}", "main": "greet" },
+            "annotations": [],
+            "parameters": [{ "key": "name", "value": "world" }]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/actions/cars"))
+        .and(query_param("blocking", "true"))
+        .and(query_param("result", "true"))
+        .and(body_partial_json(json!({ "count": 3, "color": "red" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "answer": 42 })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/guest/triggers/trigger"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .set_body_json(json!({ "activationId": "fa1cb5a8c4ae4b6e9cb5a8c4aedb6e3f" })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/rules"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![rule_data()]))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/actions/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(
+            json!({ "code": "a1b2", "error": "The requested resource does not exist." }),
+        ))
+        .mount(&server)
+        .await;
+
+    server
+}
+
+/// Serves a trigger fired by the alarm feed, the feed answers with the given status.
+/// When the trigger does not exist, the first get of the trigger is not found
+pub async fn feeds(exists: bool, feed_status: u16) -> MockServer {
+    let server = create_server().await;
+
+    let feed_trigger = json!({
+        "namespace": "guest",
+        "name": "everyhour",
+        "version": "0.0.1",
+        "annotations": [{ "key": "feed", "value": "/whisk.system/alarms/alarm" }],
+        "parameters": []
+    });
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/namespaces/guest/triggers/everyhour"))
+        .and(body_partial_json(json!({
+            "annotations": [{ "key": "feed", "value": "/whisk.system/alarms/alarm" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(feed_trigger.clone()))
+        .mount(&server)
+        .await;
+
+    if !exists {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/namespaces/guest/triggers/everyhour"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(
+                json!({ "code": "e5f6", "error": "The requested resource does not exist." }),
+            ))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/triggers/everyhour"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(feed_trigger.clone()))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/namespaces/guest/triggers/everyhour"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(feed_trigger))
+        .mount(&server)
+        .await;
+
+    let response = match feed_status {
+        200 => ResponseTemplate::new(200).set_body_json(json!({ "status": "active" })),
+        status => ResponseTemplate::new(status).set_body_json(json!({
+            "code": "c3d4",
+            "error": "The action did not produce a valid response."
+        })),
+    };
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/namespaces/whisk.system/actions/alarms/alarm"))
+        .and(query_param("blocking", "true"))
+        .and(header_exists("authorization"))
+        .and(body_partial_json(json!({
+            "triggerName": "/guest/everyhour",
+            "authKey": "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP"
+        })))
+        .respond_with(response)
+        .mount(&server)
+        .await;
+
     server
 }
@@ -1,7 +1,7 @@
 pub mod helper;
 
 use openwhisk_client_rust::{
-    Action, ActionList, Exec, KeyValue, NativeClient, OpenwhiskClient, WskProperties,
+    Action, ActionList, Exec, KeyValue, NativeClient, OpenwhiskClient, Runtime, WskProperties,
 };
 
 use crate::helper::{delete, get, put};
@@ -16,7 +16,7 @@ async fn test_list_actions_native_client() {
          "guest".to_string(), 
     ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     let result = serde_json::to_value(client.actions().list().unwrap()).unwrap();
 
@@ -39,7 +39,7 @@ async fn test_get_action_property_native_client() {
          "guest".to_string(), 
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
     let actions = serde_json::to_value(client.actions().get("cars", false).unwrap()).unwrap();
 
     let expected: String = serde_json::to_string(&actions).unwrap();
@@ -55,7 +55,7 @@ async fn test_delete_action_native_client() {
          "guest".to_string(),
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     client.actions().delete("cars").unwrap();
 
@@ -73,15 +73,7 @@ async fn test_create_action() {
         name: "cars".to_string(),
         version: "0.0.1".to_string(),
         limits: Default::default(),
-        exec: Exec {
-            kind: "rust:1.34".to_string(),
-            code: bas64_data,
-            image: "openwhisk/action-rust-v1.34".to_string(),
-            init: "".to_string(),
-            main: "".to_string(),
-            components: vec![],
-            binary: true,
-        },
+        exec: Exec::binary(Runtime::RUST_1_34, &bas64_data),
         error: "".to_string(),
         publish: true,
         updated: 0,
@@ -100,7 +92,7 @@ async fn test_create_action() {
          "guest".to_string(),
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     client.actions().insert(&action, true).unwrap();
 
@@ -109,3 +101,19 @@ async fn test_create_action() {
 
     assert!(expected.contains("cars"));
 }
+
+#[async_std::test]
+async fn test_get_package_action_property_native_client() {
+    let server = get().await;
+
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+         server.uri(),
+         "guest".to_string(),
+        ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+    let action = client.actions().get("my utils/cars", false).unwrap();
+
+    assert_eq!(action.name, "cars");
+}
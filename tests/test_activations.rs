@@ -0,0 +1,69 @@
+use openwhisk_client_rust::{NativeClient, OpenwhiskClient, WskProperties};
+use serde_json::json;
+pub mod helper;
+use crate::helper::get;
+
+#[async_std::test]
+async fn test_list_activations_native_client() {
+    let server = get().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activations = client.activations().list().unwrap();
+
+    assert_eq!(activations.len(), 1);
+    assert_eq!(
+        activations[0].activation_id,
+        "44794bd6aab74415b4e42a308d880e5b"
+    );
+}
+
+#[async_std::test]
+async fn test_get_activation_native_client() {
+    let server = get().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activation = client
+        .activations()
+        .get("44794bd6aab74415b4e42a308d880e5b")
+        .unwrap();
+
+    assert_eq!(activation.name, "cars");
+    assert_eq!(activation.duration, 10);
+    assert!(activation.response.success);
+}
+
+#[async_std::test]
+async fn test_get_activation_logs_and_result_native_client() {
+    let server = get().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let logs = client
+        .activations()
+        .logs("44794bd6aab74415b4e42a308d880e5b")
+        .unwrap();
+    assert_eq!(logs.logs.len(), 1);
+
+    let result = client
+        .activations()
+        .result("44794bd6aab74415b4e42a308d880e5b")
+        .unwrap();
+    assert_eq!(result.result, json!({"greeting": "hello"}));
+}
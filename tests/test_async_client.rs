@@ -0,0 +1,62 @@
+use openwhisk_client_rust::{AsyncNativeClient, OpenWhiskError, OpenwhiskClient, WskProperties};
+pub mod helper;
+use crate::helper::{errors, get};
+
+#[tokio::test]
+async fn test_list_actions_async_native_client() {
+    let server = get().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let actions = client.actions().list_async().await.unwrap();
+
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0].name, "cars");
+}
+
+#[tokio::test]
+async fn test_concurrent_requests_async_native_client() {
+    let server = get().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let (rules, triggers, namespaces) = tokio::join!(
+        client.rules().list_async(),
+        client.triggers().list_async(),
+        client.namespaces().list_async()
+    );
+
+    assert_eq!(rules.unwrap()[0].name, "rule1");
+    assert_eq!(triggers.unwrap()[0].name, "trigger");
+    assert_eq!(namespaces.unwrap(), vec!["guest"]);
+}
+
+#[tokio::test]
+async fn test_not_found_error_async_native_client() {
+    let server = errors().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client
+        .actions()
+        .get_async("missing", false)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::NotFound(_)));
+}
@@ -0,0 +1,118 @@
+use openwhisk_client_rust::{
+    AccessToken, AsyncNativeClient, BearerToken, HeaderAuth, NativeClient, OpenWhiskError,
+    OpenwhiskClient, RefreshingToken, WskProperties,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+pub mod helper;
+use crate::helper::auth;
+
+#[async_std::test]
+async fn test_basic_auth_by_default() {
+    let server = auth().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(client.actions().list().unwrap().is_empty());
+}
+
+#[async_std::test]
+async fn test_bearer_token() {
+    let server = auth().await;
+    let wsk_properties = WskProperties::new("".to_string(), server.uri(), "guest".to_string())
+        .set_bypass_cerificate_check(true)
+        .set_auth_provider(BearerToken::new("token-1"));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(client.actions().list().unwrap().is_empty());
+}
+
+#[async_std::test]
+async fn test_invalid_bearer_token() {
+    let server = auth().await;
+    let wsk_properties = WskProperties::new("".to_string(), server.uri(), "guest".to_string())
+        .set_bypass_cerificate_check(true)
+        .set_auth_provider(BearerToken::new("expired"));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    match client.actions().list() {
+        Err(OpenWhiskError::Unauthorized(error)) => assert_eq!(error.status, 401),
+        other => panic!("expected unauthorized, got {:?}", other),
+    }
+}
+
+#[async_std::test]
+async fn test_header_auth() {
+    let server = auth().await;
+    let wsk_properties = WskProperties::new("".to_string(), server.uri(), "guest".to_string())
+        .set_bypass_cerificate_check(true)
+        .set_auth_provider(HeaderAuth::new().add_header("X-Api-Key", "gateway-key"));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(client.actions().list().unwrap().is_empty());
+}
+
+#[async_std::test]
+async fn test_refreshing_token() {
+    let server = auth().await;
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let counter = fetches.clone();
+    let provider = RefreshingToken::new(move || {
+        let fetch = counter.fetch_add(1, Ordering::SeqCst) + 1;
+
+        // The first token expires right away, the second one outlives the test
+        match fetch {
+            1 => Ok(AccessToken::new("token-1", Duration::ZERO)),
+            _ => Ok(AccessToken::new("token-2", Duration::from_secs(3600))),
+        }
+    });
+    let wsk_properties = WskProperties::new("".to_string(), server.uri(), "guest".to_string())
+        .set_bypass_cerificate_check(true)
+        .set_auth_provider(provider);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    for _ in 0..3 {
+        assert!(client.actions().list().unwrap().is_empty());
+    }
+
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
+
+#[async_std::test]
+async fn test_failed_token_refresh() {
+    let server = auth().await;
+    let wsk_properties = WskProperties::new("".to_string(), server.uri(), "guest".to_string())
+        .set_bypass_cerificate_check(true)
+        .set_auth_provider(RefreshingToken::new(|| {
+            Err(OpenWhiskError::Request("IAM unavailable".to_string()))
+        }));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    match client.actions().list() {
+        Err(OpenWhiskError::Request(message)) => assert_eq!(message, "IAM unavailable"),
+        other => panic!("expected request error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_bearer_token_async() {
+    let server = auth().await;
+    let wsk_properties = WskProperties::new("".to_string(), server.uri(), "guest".to_string())
+        .set_bypass_cerificate_check(true)
+        .set_auth_provider(BearerToken::new("token-2"));
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(client.actions().list_async().await.unwrap().is_empty());
+}
@@ -0,0 +1,219 @@
+use openwhisk_client_rust::{
+    AsyncNativeClient, Cassette, KeyValue, MatchMode, NativeClient, OpenWhiskError,
+    OpenwhiskClient, RecordingClient, ReplayClient, Route, Trigger, WskProperties,
+};
+use serde_json::json;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+pub mod helper;
+use crate::helper::{feeds, get, routes};
+
+const AUTH: &str = "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP";
+
+fn properties(host: String) -> WskProperties {
+    WskProperties::new(AUTH.to_string(), host, "guest".to_string())
+        .set_bypass_cerificate_check(true)
+}
+
+fn cassette_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!(
+        "openwhisk-cassette-{}-{}.json",
+        name,
+        std::process::id()
+    ))
+}
+
+#[async_std::test]
+async fn test_record_redacts_basic_auth() {
+    let server = get().await;
+    let feed_server = feeds(false, 200).await;
+    let route_server = routes().await;
+    let path = cassette_path("record");
+
+    let client =
+        OpenwhiskClient::<RecordingClient<NativeClient>>::try_new(Some(&properties(server.uri())))
+            .unwrap();
+
+    client.actions().list().unwrap();
+    client.actions().get("cars", false).unwrap();
+
+    // Clones of the recording client record to the same cassette
+    let feed_client =
+        OpenwhiskClient::from_client(client.client.clone(), Some(&properties(feed_server.uri())));
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+    let parameters = vec![KeyValue {
+        key: "cron".to_string(),
+        value: json!("0 * * * *"),
+    }];
+    feed_client
+        .triggers()
+        .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &parameters, true)
+        .unwrap();
+
+    let route_properties =
+        properties(route_server.uri()).set_apigw_access_token("apigw-token".to_string());
+    let route_client = OpenwhiskClient::from_client(client.client.clone(), Some(&route_properties));
+    route_client.routes().list(Some("/hello")).unwrap();
+
+    client.client.save(&path).unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(!content.contains("23bc46b1-71f6-4ed5-8c54-816aa4f8c502"));
+    assert!(!content.contains("123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP"));
+    assert!(!content.contains("apigw-token"));
+    assert!(!content.contains("Basic "));
+
+    let cassette = Cassette::from_file(&path).unwrap();
+    assert_eq!(cassette.interactions.len(), 6);
+
+    let feed = cassette.interactions[4].request.body.as_ref().unwrap();
+    let feed: serde_json::Value = serde_json::from_str(&feed.data).unwrap();
+    assert_eq!(feed["authKey"], "[REDACTED]");
+    assert_eq!(feed["cron"], "0 * * * *");
+
+    let route = &cassette.interactions[5].request;
+    assert!(route
+        .headers
+        .contains(&("apigw-access-token".to_string(), "[REDACTED]".to_string())));
+    assert!(route.url.contains("spaceguid=[REDACTED]"));
+
+    // Replayed requests are redacted the same way, so they match exactly
+    let replay = OpenwhiskClient::from_client(
+        ReplayClient::new(cassette.clone()),
+        Some(&properties(feed_server.uri())),
+    );
+    replay
+        .triggers()
+        .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &parameters, true)
+        .unwrap();
+
+    let request = &cassette.interactions[0].request;
+    assert_eq!(request.method, "GET");
+    assert!(request.url.contains("/api/v1/namespaces/guest/actions"));
+    assert!(request
+        .headers
+        .contains(&("authorization".to_string(), "[REDACTED]".to_string())));
+    assert_eq!(cassette.interactions[0].response.status, 200);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[async_std::test]
+async fn test_record_redacts_route_auth_key() {
+    let server = routes().await;
+    let path = cassette_path("route");
+
+    let properties = properties(server.uri()).set_apigw_access_token("apigw-token".to_string());
+    let client =
+        OpenwhiskClient::<RecordingClient<NativeClient>>::try_new(Some(&properties)).unwrap();
+
+    let route = Route::new(
+        "/hello".to_string(),
+        "/world".to_string(),
+        "get".to_string(),
+        "cars".to_string(),
+        "json".to_string(),
+        None,
+    );
+    client.routes().insert(&route).unwrap();
+
+    client.client.save(&path).unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(!content.contains("123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP"));
+
+    let cassette = Cassette::from_file(&path).unwrap();
+    let body = cassette.interactions[0].request.body.as_ref().unwrap();
+    let body: serde_json::Value = serde_json::from_str(&body.data).unwrap();
+    assert_eq!(body["apidoc"]["action"]["authkey"], "[REDACTED]");
+    assert_eq!(body["apidoc"]["gatewayPath"], "/world");
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[async_std::test]
+async fn test_replay_recorded_session() {
+    let server = get().await;
+    let path = cassette_path("replay");
+
+    let recording =
+        OpenwhiskClient::<RecordingClient<NativeClient>>::try_new(Some(&properties(server.uri())))
+            .unwrap();
+    let recorded = recording.actions().list().unwrap();
+    recording.actions().get("cars", false).unwrap();
+    recording.client.save(&path).unwrap();
+
+    drop(server);
+
+    let replay = ReplayClient::from_file(&path).unwrap();
+    let client = OpenwhiskClient::from_client(
+        replay.clone(),
+        Some(&properties("https://openwhisk.test".to_string())),
+    );
+
+    let actions = client.actions().list().unwrap();
+    assert_eq!(actions.len(), recorded.len());
+    assert_eq!(actions[0].name, "cars");
+
+    let action = client.actions().get("cars", false).unwrap();
+    assert_eq!(action.name, "cars");
+    assert!(replay.remaining().is_empty());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[async_std::test]
+async fn test_replay_matching_modes() {
+    let server = get().await;
+
+    let recording =
+        OpenwhiskClient::<RecordingClient<NativeClient>>::try_new(Some(&properties(server.uri())))
+            .unwrap();
+    recording.actions().get("cars", false).unwrap();
+    let cassette = recording.client.cassette();
+
+    let exact = OpenwhiskClient::from_client(
+        ReplayClient::new(cassette.clone()),
+        Some(&properties(server.uri())),
+    );
+
+    match exact.actions().get("cars", true) {
+        Err(OpenWhiskError::Transport(message)) => {
+            assert!(message.contains("/api/v1/namespaces/guest/actions/cars"))
+        }
+        other => panic!("expected a transport error, got {:?}", other),
+    }
+
+    let fuzzy = OpenwhiskClient::from_client(
+        ReplayClient::new(cassette).set_match_mode(MatchMode::Fuzzy),
+        Some(&properties(server.uri())),
+    );
+
+    assert_eq!(fuzzy.actions().get("cars", true).unwrap().name, "cars");
+    assert!(fuzzy.actions().get("cars", true).is_err());
+}
+
+#[tokio::test]
+async fn test_record_and_replay_async_client() {
+    let server = get().await;
+
+    let recording = OpenwhiskClient::<RecordingClient<AsyncNativeClient>>::try_new(Some(
+        &properties(server.uri()),
+    ))
+    .unwrap();
+    recording.actions().list_async().await.unwrap();
+
+    let cassette = recording.client.cassette();
+    assert_eq!(cassette.interactions.len(), 1);
+
+    let client =
+        OpenwhiskClient::from_client(ReplayClient::new(cassette), Some(&properties(server.uri())));
+
+    let actions = client.actions().list_async().await.unwrap();
+    assert_eq!(actions[0].name, "cars");
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "cli")]
+
+use serde_json::{json, Value};
+use std::env;
+use std::fs;
+use std::process::{Command, Output};
+pub mod helper;
+use crate::helper::cli;
+
+fn wskprops(name: &str, host: &str) -> String {
+    let path = env::temp_dir().join(name);
+    fs::write(
+        &path,
+        format!(
+            "AUTH=23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP\nAPIHOST={}\nNAMESPACE=guest\n",
+            host
+        ),
+    )
+    .unwrap();
+    path.to_string_lossy().to_string()
+}
+
+fn wsk(wskprops: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wsk"))
+        .args(args)
+        .env("WSK_CONFIG_FILE", wskprops)
+        .env_remove("__OW_API_KEY")
+        .env_remove("__OW_API_HOST")
+        .env_remove("__OW_NAMESPACE")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[async_std::test]
+async fn test_cli_list_actions_table() {
+    let server = cli().await;
+    let wskprops = wskprops("openwhisk_client_rust_cli_list.wskprops", &server.uri());
+
+    let output = wsk(&wskprops, &["action", "list"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        stdout(&output),
+        "actions\nname\n/guest/cars\n/guest/trucks\n"
+    );
+}
+
+#[async_std::test]
+async fn test_cli_list_actions_json() {
+    let server = cli().await;
+    let wskprops = wskprops("openwhisk_client_rust_cli_json.wskprops", &server.uri());
+
+    let output = wsk(&wskprops, &["-o", "json", "action", "list"]);
+    let actions: Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+    assert_eq!(
+        actions,
+        json!([
+            { "name": "cars", "namespace": "guest" },
+            { "name": "trucks", "namespace": "guest" }
+        ])
+    );
+}
+
+#[async_std::test]
+async fn test_cli_get_action() {
+    let server = cli().await;
+    let wskprops = wskprops("openwhisk_client_rust_cli_get.wskprops", &server.uri());
+
+    let output = wsk(&wskprops, &["action", "get", "cars"]);
+    let stdout = stdout(&output);
+    let (message, action) = stdout.split_once('\n').unwrap();
+    let action: Value = serde_json::from_str(action).unwrap();
+
+    assert_eq!(message, "ok: got action cars");
+    assert_eq!(action["name"], "cars");
+}
+
+#[async_std::test]
+async fn test_cli_create_action() {
+    let server = cli().await;
+    let wskprops = wskprops("openwhisk_client_rust_cli_create.wskprops", &server.uri());
+    let source = env::temp_dir().join("openwhisk_client_rust_cli_hello.js");
+    fs::write(&source, "function greet() {}").unwrap();
+
+    let output = wsk(
+        &wskprops,
+        &[
+            "action",
+            "create",
+            "hello",
+            source.to_str().unwrap(),
+            "--main",
+            "greet",
+            "-p",
+            "name",
+            "world",
+        ],
+    );
+
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(stdout(&output), "ok: created action hello\n");
+}
+
+#[async_std::test]
+async fn test_cli_invoke_action_result() {
+    let server = cli().await;
+    let wskprops = wskprops("openwhisk_client_rust_cli_invoke.wskprops", &server.uri());
+
+    let output = wsk(
+        &wskprops,
+        &[
+            "action", "invoke", "cars", "-r", "-p", "count", "3", "-p", "color", "red",
+        ],
+    );
+    let result: Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+    assert_eq!(result, json!({ "answer": 42 }));
+}
+
+#[async_std::test]
+async fn test_cli_fire_trigger() {
+    let server = cli().await;
+    let wskprops = wskprops("openwhisk_client_rust_cli_fire.wskprops", &server.uri());
+
+    let output = wsk(&wskprops, &["trigger", "fire", "trigger"]);
+
+    assert_eq!(
+        stdout(&output),
+        "ok: triggered /guest/trigger with id fa1cb5a8c4ae4b6e9cb5a8c4aedb6e3f\n"
+    );
+}
+
+#[async_std::test]
+async fn test_cli_list_rules() {
+    let server = cli().await;
+    let wskprops = wskprops("openwhisk_client_rust_cli_rules.wskprops", &server.uri());
+
+    let output = wsk(&wskprops, &["rule", "list"]);
+
+    assert_eq!(
+        stdout(&output),
+        "rules\nname          status\n/guest/rule1\n"
+    );
+}
+
+#[async_std::test]
+async fn test_cli_error() {
+    let server = cli().await;
+    let wskprops = wskprops("openwhisk_client_rust_cli_error.wskprops", &server.uri());
+
+    let output = wsk(&wskprops, &["action", "get", "missing"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error: "));
+}
+
+#[test]
+fn test_cli_set_and_get_properties() {
+    let wskprops = wskprops(
+        "openwhisk_client_rust_cli_property.wskprops",
+        "http://localhost:3233",
+    );
+
+    let output = wsk(
+        &wskprops,
+        &[
+            "property",
+            "set",
+            "--namespace",
+            "dev",
+            "--apihost",
+            "openwhisk.example.com",
+        ],
+    );
+    assert!(output.status.success());
+
+    let output = wsk(
+        &wskprops,
+        &["-o", "json", "property", "get", "--apihost", "--namespace"],
+    );
+    let properties: Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+    assert_eq!(
+        properties,
+        json!({ "APIHOST": "https://openwhisk.example.com", "NAMESPACE": "dev" })
+    );
+    assert!(fs::read_to_string(&wskprops)
+        .unwrap()
+        .starts_with("AUTH=23bc46b1"));
+}
+
+#[test]
+fn test_cli_unset_requires_a_property() {
+    let wskprops = wskprops(
+        "openwhisk_client_rust_cli_unset.wskprops",
+        "http://localhost:3233",
+    );
+
+    let output = wsk(&wskprops, &["property", "unset"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(fs::read_to_string(&wskprops)
+        .unwrap()
+        .starts_with("AUTH=23bc46b1"));
+
+    let output = wsk(&wskprops, &["property", "unset", "--namespace"]);
+    assert!(output.status.success());
+
+    let content = fs::read_to_string(&wskprops).unwrap();
+    assert!(content.contains("APIHOST=http://localhost:3233"));
+    assert!(!content.contains("NAMESPACE"));
+}
@@ -0,0 +1,265 @@
+use openwhisk_client_rust::{
+    Deployment, Exec, Manifest, NativeClient, OpenWhiskError, OpenwhiskClient, WskProperties,
+};
+use serde_json::json;
+use std::fs;
+use std::path::PathBuf;
+pub mod helper;
+use crate::helper::deployments;
+
+static MANIFEST: &str = r#"
+project:
+  name: helloworld
+  packages:
+    hello:
+      version: 1.0
+      inputs:
+        greeting: string
+      actions:
+        hello:
+          function: src/hello.js
+          runtime: nodejs:20
+          web: raw
+          inputs:
+            name:
+              type: string
+              default: World
+            place: ${DEPLOY_TEST_PLACE}
+          limits:
+            timeout: 5000
+            memorySize: 256
+        bye:
+          code: "function main() { return {}; }"
+          runtime: nodejs:20
+      sequences:
+        outer:
+          actions: inner, /whisk.system/utils/echo
+        inner:
+          actions: hello, bye
+      triggers:
+        everyhour:
+          feed: /whisk.system/alarms/alarm
+          inputs:
+            cron: "0 * * * *"
+      rules:
+        hourly:
+          trigger: everyhour
+          action: outer
+      apis:
+        hello-api:
+          hello:
+            world:
+              hello:
+                method: GET
+                response: http
+"#;
+
+static DEPLOYMENT: &str = r#"
+project:
+  name: helloworld
+  packages:
+    hello:
+      inputs:
+        greeting: Hi
+      actions:
+        hello:
+          inputs:
+            name: Amy
+      triggers:
+        everyhour:
+          inputs:
+            cron: "*/5 * * * *"
+"#;
+
+fn project_dir(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("openwhisk-deploy-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("src").join("hello.js"),
+        "function main() { return {}; }",
+    )
+    .unwrap();
+    fs::write(dir.join("manifest.yaml"), MANIFEST).unwrap();
+
+    dir
+}
+
+fn parameter(parameters: &[openwhisk_client_rust::KeyValue], key: &str) -> serde_json::Value {
+    parameters
+        .iter()
+        .find(|parameter| parameter.key == key)
+        .map(|parameter| parameter.value.clone())
+        .unwrap()
+}
+
+#[test]
+fn test_manifest_project() {
+    std::env::set_var("DEPLOY_TEST_PLACE", "Earth");
+    let dir = project_dir("project");
+
+    let manifest = Manifest::from_file(dir.join("manifest.yaml")).unwrap();
+    let project = manifest.project("guest").unwrap();
+
+    assert_eq!(project.name, "helloworld");
+    assert_eq!(project.packages[0].name, "hello");
+    assert_eq!(project.packages[0].version, "1.0");
+    assert_eq!(
+        parameter(&project.packages[0].parameters, "greeting"),
+        json!("")
+    );
+
+    let hello = project
+        .actions
+        .iter()
+        .find(|action| action.name == "hello/hello")
+        .unwrap();
+    assert_eq!(
+        hello.exec,
+        Exec::code(
+            "nodejs:20".parse().unwrap(),
+            "function main() { return {}; }"
+        )
+    );
+    assert_eq!(parameter(&hello.parameters, "name"), json!("World"));
+    assert_eq!(parameter(&hello.parameters, "place"), json!("Earth"));
+    assert_eq!(parameter(&hello.annotations, "raw-http"), json!(true));
+    assert_eq!(hello.limits.as_ref().unwrap().memory, Some(256));
+
+    let sequences: Vec<&str> = project
+        .sequences
+        .iter()
+        .map(|sequence| sequence.name.as_str())
+        .collect();
+    assert_eq!(sequences, vec!["hello/inner", "hello/outer"]);
+    assert_eq!(
+        project.sequences[1].exec,
+        Exec::sequence(vec![
+            "/guest/hello/inner".to_string(),
+            "/whisk.system/utils/echo".to_string()
+        ])
+    );
+
+    assert_eq!(
+        project.triggers[0].feed.as_deref(),
+        Some("/whisk.system/alarms/alarm")
+    );
+    assert_eq!(project.rules[0].action, "hello/outer");
+    assert_eq!(project.routes[0].base_path, "/hello");
+    assert_eq!(project.routes[0].action, "hello/hello");
+    assert_eq!(project.routes[0].response_type, "http");
+}
+
+#[test]
+fn test_deployment_overrides_inputs() {
+    let dir = project_dir("overrides");
+
+    let manifest = Manifest::from_file(dir.join("manifest.yaml"))
+        .unwrap()
+        .with_deployment(&Deployment::from_yaml(DEPLOYMENT).unwrap());
+    let project = manifest.project("guest").unwrap();
+
+    assert_eq!(
+        parameter(&project.packages[0].parameters, "greeting"),
+        json!("Hi")
+    );
+
+    let hello = project
+        .actions
+        .iter()
+        .find(|action| action.name == "hello/hello")
+        .unwrap();
+    assert_eq!(parameter(&hello.parameters, "name"), json!("Amy"));
+    assert_eq!(
+        parameter(&project.triggers[0].trigger.parameters, "cron"),
+        json!("*/5 * * * *")
+    );
+}
+
+#[test]
+fn test_manifest_rejects_unknown_runtime() {
+    let manifest = Manifest::from_yaml(
+        r#"
+packages:
+  default:
+    actions:
+      hello:
+        code: "main"
+        runtime: cobol:1
+"#,
+        ".",
+    )
+    .unwrap();
+
+    assert!(matches!(
+        manifest.project("guest"),
+        Err(OpenWhiskError::InvalidInput(_))
+    ));
+}
+
+#[async_std::test]
+async fn test_deploy_and_undeploy_native_client() {
+    let dir = project_dir("deploy");
+    let server = deployments().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+    let manifest = Manifest::from_file(dir.join("manifest.yaml")).unwrap();
+
+    client.deploy(&manifest).unwrap();
+
+    let requests: Vec<String> = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| format!("{} {}", request.method, request.url.path()))
+        .collect();
+
+    assert_eq!(
+        requests,
+        vec![
+            "PUT /api/v1/namespaces/guest/packages/hello",
+            "PUT /api/v1/namespaces/guest/actions/hello/bye",
+            "PUT /api/v1/namespaces/guest/actions/hello/hello",
+            "PUT /api/v1/namespaces/guest/actions/hello/inner",
+            "PUT /api/v1/namespaces/guest/actions/hello/outer",
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm",
+            "PUT /api/v1/namespaces/guest/rules/hourly",
+            "POST /api/v1/web/whisk.system/apimgmt/createApi.http",
+        ]
+    );
+
+    client.undeploy(&manifest).unwrap();
+
+    let requests: Vec<String> = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .skip(requests.len())
+        .map(|request| format!("{} {}", request.method, request.url.path()))
+        .collect();
+
+    assert_eq!(
+        requests,
+        vec![
+            "DELETE /api/v1/web/whisk.system/apimgmt/deleteApi.http",
+            "DELETE /api/v1/namespaces/guest/rules/hourly",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm",
+            "DELETE /api/v1/namespaces/guest/triggers/everyhour",
+            "DELETE /api/v1/namespaces/guest/actions/hello/outer",
+            "DELETE /api/v1/namespaces/guest/actions/hello/inner",
+            "DELETE /api/v1/namespaces/guest/actions/hello/hello",
+            "DELETE /api/v1/namespaces/guest/actions/hello/bye",
+            "DELETE /api/v1/namespaces/guest/packages/hello",
+        ]
+    );
+}
@@ -0,0 +1,543 @@
+use openwhisk_client_rust::{
+    Action, Activation, EmulatorClient, Exec, KeyValue, LocalActivation, Manifest, OpenWhiskError,
+    OpenwhiskClient, Package, Rule, Runtime, Trigger, WskProperties,
+};
+use serde_json::{json, Value};
+
+fn client() -> OpenwhiskClient<EmulatorClient> {
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        "https://openwhisk.test".to_string(),
+        "guest".to_string(),
+    );
+
+    OpenwhiskClient::<EmulatorClient>::try_new(Some(&wsk_properties)).unwrap()
+}
+
+fn action(name: &str) -> Action {
+    Action {
+        name: name.to_string(),
+        exec: Exec::code(Runtime::new("nodejs:20").unwrap(), "function main() {}"),
+        ..Default::default()
+    }
+}
+
+fn trigger(name: &str) -> Trigger {
+    Trigger {
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+fn rule(name: &str, trigger: &str, action: &str) -> Rule {
+    Rule {
+        name: name.to_string(),
+        trigger: trigger.to_string(),
+        action: action.to_string(),
+        status: "".to_string(),
+    }
+}
+
+#[test]
+fn test_emulator_insert_conflicts_without_overwrite() {
+    let client = client();
+
+    let inserted = client.actions().insert(&action("hello"), false).unwrap();
+
+    assert_eq!(inserted.namespace, "guest");
+    assert_eq!(inserted.version, "0.0.1");
+
+    let error = client
+        .actions()
+        .insert(&action("hello"), false)
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Conflict(_)), "{:?}", error);
+
+    let updated = client.actions().insert(&action("hello"), true).unwrap();
+
+    assert_eq!(updated.version, "0.0.2");
+    assert_eq!(client.actions().list().unwrap().len(), 1);
+    assert_eq!(client.client.actions().len(), 1);
+}
+
+#[test]
+fn test_emulator_missing_entities_are_not_found() {
+    let client = client();
+
+    let error = client.actions().get("missing", true).unwrap_err();
+    assert!(matches!(error, OpenWhiskError::NotFound(_)), "{:?}", error);
+
+    let error = client.triggers().delete("missing").unwrap_err();
+    assert!(matches!(error, OpenWhiskError::NotFound(_)), "{:?}", error);
+
+    let error = client.rules().set_state("missing", "active").unwrap_err();
+    assert!(matches!(error, OpenWhiskError::NotFound(_)), "{:?}", error);
+
+    let error = client.activations().get("missing").unwrap_err();
+    assert!(matches!(error, OpenWhiskError::NotFound(_)), "{:?}", error);
+
+    client.actions().insert(&action("hello"), false).unwrap();
+    client.actions().delete("hello").unwrap();
+
+    let error = client.actions().delete("hello").unwrap_err();
+    assert!(matches!(error, OpenWhiskError::NotFound(_)), "{:?}", error);
+}
+
+#[test]
+fn test_emulator_rule_requires_trigger_and_action() {
+    let client = client();
+
+    client.actions().insert(&action("hello"), false).unwrap();
+
+    let error = client
+        .rules()
+        .insert(&rule("hourly", "everyhour", "hello"), false)
+        .unwrap_err();
+
+    match error {
+        OpenWhiskError::NotFound(error) => {
+            assert_eq!(error.message, "Trigger /guest/everyhour does not exist.")
+        }
+        error => panic!("{:?}", error),
+    }
+
+    client
+        .triggers()
+        .insert(&trigger("everyhour"), false)
+        .unwrap();
+
+    let created = client
+        .rules()
+        .insert(&rule("hourly", "everyhour", "hello"), false)
+        .unwrap();
+
+    assert_eq!(created.status, "active");
+    assert_eq!(
+        created.trigger,
+        json!({"path": "guest", "name": "everyhour"})
+    );
+    assert_eq!(created.action, json!({"path": "guest", "name": "hello"}));
+
+    let error = client
+        .rules()
+        .insert(&rule("other", "everyhour", "missing"), false)
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::NotFound(_)), "{:?}", error);
+}
+
+#[test]
+fn test_emulator_fire_invokes_actions_of_active_rules() {
+    let client = client();
+
+    client.actions().insert(&action("hello"), false).unwrap();
+    client
+        .triggers()
+        .insert(
+            &Trigger {
+                parameters: vec![KeyValue {
+                    key: "source".to_string(),
+                    value: json!("emulator"),
+                }],
+                ..trigger("everyhour")
+            },
+            false,
+        )
+        .unwrap();
+
+    assert_eq!(
+        client.triggers().fire("everyhour", json!({})).unwrap(),
+        None
+    );
+
+    client
+        .rules()
+        .insert(&rule("hourly", "everyhour", "hello"), false)
+        .unwrap();
+
+    let activation_id = client
+        .triggers()
+        .fire("everyhour", json!({"name": "Amy"}))
+        .unwrap()
+        .unwrap();
+
+    let trigger_activation = client.activations().get(activation_id.as_str()).unwrap();
+    assert_eq!(trigger_activation.name, "everyhour");
+    assert_eq!(trigger_activation.logs.len(), 1);
+
+    let activations = client.activations().list().unwrap();
+    let action_activation = activations
+        .iter()
+        .find(|activation| activation.name == "hello")
+        .unwrap();
+
+    assert_eq!(
+        action_activation.response.result,
+        json!({"source": "emulator", "name": "Amy"})
+    );
+
+    client.rules().set_state("hourly", "inactive").unwrap();
+
+    assert_eq!(
+        client.triggers().fire("everyhour", json!({})).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_emulator_invoke_records_activations() {
+    let client = client();
+
+    client
+        .actions()
+        .insert(
+            &Action {
+                parameters: vec![KeyValue {
+                    key: "greeting".to_string(),
+                    value: json!("Hello"),
+                }],
+                ..action("hello")
+            },
+            false,
+        )
+        .unwrap();
+
+    let result = client
+        .actions()
+        .invoke("hello", json!({"name": "Amy"}), true, true)
+        .unwrap();
+
+    assert_eq!(result, json!({"greeting": "Hello", "name": "Amy"}));
+
+    let activation_id = client
+        .actions()
+        .invoke_nonblocking("hello", json!({}))
+        .unwrap();
+
+    let result = client.activations().result(activation_id.as_str()).unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.result, json!({"greeting": "Hello"}));
+    assert_eq!(client.client.activations().len(), 2);
+
+    let error = client
+        .actions()
+        .invoke("missing", json!({}), true, true)
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::NotFound(_)), "{:?}", error);
+}
+
+#[test]
+fn test_emulator_sequences_chain_components() {
+    let client = client();
+
+    client.actions().insert(&action("split"), false).unwrap();
+    client.actions().insert(&action("sort"), false).unwrap();
+
+    let error = client
+        .actions()
+        .create_sequence("broken", &["split", "missing"], false)
+        .unwrap_err();
+
+    assert!(
+        matches!(error, OpenWhiskError::BadRequest(_)),
+        "{:?}",
+        error
+    );
+
+    client
+        .actions()
+        .create_sequence("pipeline", &["split", "sort"], false)
+        .unwrap();
+
+    let result = client
+        .actions()
+        .invoke("pipeline", json!({"text": "b a"}), true, true)
+        .unwrap();
+
+    assert_eq!(result, json!({"text": "b a"}));
+    assert_eq!(client.client.activations().len(), 3);
+}
+
+#[test]
+fn test_emulator_packages_hold_actions() {
+    let client = client();
+
+    let error = client
+        .actions()
+        .insert(&action("utils/split"), false)
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::NotFound(_)), "{:?}", error);
+
+    client
+        .packages()
+        .insert(
+            &Package {
+                name: "utils".to_string(),
+                ..Default::default()
+            },
+            false,
+        )
+        .unwrap();
+
+    let inserted = client
+        .actions()
+        .insert(&action("utils/split"), false)
+        .unwrap();
+
+    assert_eq!(inserted.namespace, "guest/utils");
+    assert_eq!(inserted.name, "split");
+
+    let package = client.packages().get("utils").unwrap();
+    assert_eq!(package.actions.len(), 1);
+    assert_eq!(package.actions[0].name, "split");
+
+    let error = client.packages().delete("utils").unwrap_err();
+    assert!(matches!(error, OpenWhiskError::Conflict(_)), "{:?}", error);
+
+    client.actions().delete("utils/split").unwrap();
+    client.packages().delete("utils").unwrap();
+
+    assert!(client.client.packages().is_empty());
+}
+
+#[test]
+fn test_emulator_deploys_manifest() {
+    let client = client();
+
+    let manifest = Manifest::from_yaml(
+        r#"
+project:
+  name: helloworld
+  packages:
+    hello:
+      actions:
+        hello:
+          code: "function main(params) { return params; }"
+          runtime: nodejs:20
+          inputs:
+            name: World
+      triggers:
+        everyhour: {}
+      rules:
+        hourly:
+          trigger: everyhour
+          action: hello
+"#,
+        ".",
+    )
+    .unwrap();
+
+    client.deploy(&manifest).unwrap();
+
+    assert_eq!(client.client.packages().len(), 1);
+    assert_eq!(client.client.actions().len(), 1);
+    assert_eq!(client.client.triggers().len(), 1);
+    assert_eq!(client.client.rules().len(), 1);
+
+    client
+        .triggers()
+        .fire("everyhour", json!({}))
+        .unwrap()
+        .unwrap();
+
+    let activations = client.client.activations();
+
+    assert_eq!(activations.len(), 2);
+    assert_eq!(activations[0].response.result, json!({"name": "World"}));
+
+    client.undeploy(&manifest).unwrap();
+
+    assert!(client.client.actions().is_empty());
+    assert!(client.client.packages().is_empty());
+}
+
+#[tokio::test]
+async fn test_emulator_async_service() {
+    let client = client();
+
+    client.actions().insert(&action("hello"), false).unwrap();
+
+    let actions = client.actions().list_async().await.unwrap();
+
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0].name, "hello");
+}
+
+#[test]
+fn test_emulator_runs_registered_actions() {
+    let client = client();
+
+    client.actions().insert(&action("hello"), false).unwrap();
+    client.client.register_action(
+        "hello",
+        |params: Value, activation: &mut LocalActivation| {
+            activation.log(format!("greeting {}", params["name"]));
+
+            match params["name"].as_str() {
+                Some(name) => Ok(json!({ "greeting": format!("Hello {}", name) })),
+                None => Err(json!("missing name")),
+            }
+        },
+    );
+
+    let activation = client
+        .actions()
+        .invoke("hello", json!({"name": "Amy"}), true, false)
+        .unwrap();
+    let activation: Activation = serde_json::from_value(activation).unwrap();
+
+    assert_eq!(activation.response.result, json!({"greeting": "Hello Amy"}));
+    assert_eq!(activation.logs, vec!["greeting \"Amy\"".to_string()]);
+    assert_eq!(activation.end - activation.start, activation.duration);
+
+    let typed = client
+        .actions()
+        .invoke_typed::<_, Value>("hello", &json!({}), true)
+        .unwrap();
+
+    assert_eq!(typed.result, Err(json!("missing name")));
+
+    let latest = &client.client.activations()[1];
+
+    assert_eq!(latest.response.status, "application error");
+    assert!(!latest.response.success);
+}
+
+#[test]
+fn test_emulator_reports_failing_actions() {
+    let client = client();
+
+    client.actions().insert(&action("crash"), false).unwrap();
+    client.actions().insert(&action("scalar"), false).unwrap();
+    client
+        .client
+        .register_action("crash", |_: Value, _: &mut LocalActivation| {
+            panic!("out of cheese")
+        });
+    client
+        .client
+        .register_action("scalar", |_: Value, _: &mut LocalActivation| Ok(json!(42)));
+
+    let error = client
+        .actions()
+        .invoke("crash", json!({}), true, true)
+        .unwrap_err();
+
+    match error {
+        OpenWhiskError::Server(error) => {
+            assert_eq!(error.status, 502);
+            assert_eq!(error.message, "The action panicked: out of cheese");
+        }
+        error => panic!("{:?}", error),
+    }
+
+    let typed = client
+        .actions()
+        .invoke_typed::<_, Value>("scalar", &json!({}), true)
+        .unwrap();
+
+    assert_eq!(
+        typed.result,
+        Err(json!("The action did not return a dictionary."))
+    );
+}
+
+#[test]
+fn test_emulator_runs_event_flows() {
+    let client = client();
+
+    client
+        .packages()
+        .insert(
+            &Package {
+                name: "orders".to_string(),
+                ..Default::default()
+            },
+            false,
+        )
+        .unwrap();
+
+    for name in ["orders/validate", "orders/price", "orders/notify"] {
+        client.actions().insert(&action(name), false).unwrap();
+    }
+
+    client
+        .actions()
+        .create_sequence(
+            "orders/checkout",
+            &["orders/validate", "orders/price"],
+            false,
+        )
+        .unwrap();
+
+    client.client.register_action(
+        "orders/validate",
+        |params: Value, _: &mut LocalActivation| {
+            Ok(json!({ "items": params["items"], "valid": true }))
+        },
+    );
+    client
+        .client
+        .register_action("orders/price", |params: Value, _: &mut LocalActivation| {
+            let items = params["items"].as_array().cloned().unwrap_or_default();
+            Ok(json!({ "total": items.iter().filter_map(Value::as_i64).sum::<i64>() }))
+        });
+
+    // Actions may use the emulator, like firing the trigger of the next step of the flow
+    let emulator = client.clone();
+    client.client.register_action(
+        "orders/notify",
+        move |params: Value, _: &mut LocalActivation| {
+            emulator
+                .triggers()
+                .fire("notified", params.clone())
+                .unwrap();
+            Ok(params)
+        },
+    );
+
+    for name in ["ordered", "notified"] {
+        client.triggers().insert(&trigger(name), false).unwrap();
+    }
+    client
+        .rules()
+        .insert(&rule("checkout", "ordered", "orders/checkout"), false)
+        .unwrap();
+    client
+        .rules()
+        .insert(&rule("notify", "ordered", "orders/notify"), false)
+        .unwrap();
+
+    let activation_id = client
+        .triggers()
+        .fire("ordered", json!({"items": [3, 4]}))
+        .unwrap()
+        .unwrap();
+
+    let trigger_activation = client.activations().get(activation_id.as_str()).unwrap();
+    assert_eq!(trigger_activation.logs.len(), 2);
+
+    let activations = client.client.activations();
+    let checkout = activations
+        .iter()
+        .find(|activation| activation.name == "checkout")
+        .unwrap();
+
+    assert_eq!(checkout.response.result, json!({"total": 7}));
+    assert_eq!(checkout.logs.len(), 2);
+
+    // The notified trigger has no rule, so firing it created no activation
+    let names: Vec<&str> = activations
+        .iter()
+        .map(|activation| activation.name.as_str())
+        .collect();
+
+    assert_eq!(
+        names,
+        vec!["validate", "price", "checkout", "notify", "ordered"]
+    );
+}
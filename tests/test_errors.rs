@@ -0,0 +1,64 @@
+use openwhisk_client_rust::{
+    NativeClient, OpenWhiskError, OpenwhiskClient, Trigger, WskProperties,
+};
+pub mod helper;
+use crate::helper::errors;
+
+#[async_std::test]
+async fn test_not_found_error_native_client() {
+    let server = errors().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client.actions().get("missing", false).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::NotFound(_)));
+    assert_eq!(error.status(), Some(404));
+    assert_eq!(error.code(), Some("4XwKX5V7mXa0cOJwWkOHcP4PMbMFKa1T"));
+    assert_eq!(error.message(), "The requested resource does not exist.");
+}
+
+#[async_std::test]
+async fn test_conflict_error_native_client() {
+    let server = errors().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "trigger".to_string(),
+        ..Default::default()
+    };
+
+    let error = client.triggers().insert(&trigger, false).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Conflict(_)));
+    assert_eq!(error.status(), Some(409));
+}
+
+#[async_std::test]
+async fn test_non_json_error_body_native_client() {
+    let server = errors().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client.rules().list().unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Server(_)));
+    assert_eq!(error.status(), Some(502));
+    assert_eq!(error.message(), "Bad Gateway");
+}
@@ -0,0 +1,97 @@
+use openwhisk_client_rust::{
+    Action, Exec, NativeClient, OpenWhiskError, OpenwhiskClient, Runtime, WskProperties,
+};
+use serde_json::json;
+
+#[test]
+fn test_code_exec_wire_format() {
+    let exec = Exec::code(Runtime::NODEJS_20, "function main() {}").set_main("handler");
+
+    assert_eq!(
+        serde_json::to_value(&exec).unwrap(),
+        json!({
+            "kind": "nodejs:20",
+            "code": "function main() {}",
+            "main": "handler",
+            "binary": false
+        })
+    );
+}
+
+#[test]
+fn test_blackbox_exec_wire_format() {
+    let exec = Exec::blackbox("openwhisk/dockerskeleton");
+
+    assert_eq!(
+        serde_json::to_value(&exec).unwrap(),
+        json!({
+            "kind": "blackbox",
+            "image": "openwhisk/dockerskeleton",
+            "binary": false
+        })
+    );
+}
+
+#[test]
+fn test_sequence_exec_wire_format() {
+    let exec = Exec::sequence(vec![
+        "/guest/utils/split".to_string(),
+        "/guest/utils/sort".to_string(),
+    ]);
+
+    assert_eq!(
+        serde_json::to_value(&exec).unwrap(),
+        json!({
+            "kind": "sequence",
+            "components": ["/guest/utils/split", "/guest/utils/sort"]
+        })
+    );
+}
+
+#[test]
+fn test_exec_round_trip() {
+    let execs = vec![
+        Exec::binary(Runtime::RUST_1_34, "UEsDBA=="),
+        Exec::blackbox("openwhisk/dockerskeleton").set_main("main"),
+        Exec::sequence(vec!["/guest/cars".to_string()]),
+    ];
+
+    for exec in execs {
+        let value = serde_json::to_value(&exec).unwrap();
+
+        assert_eq!(serde_json::from_value::<Exec>(value).unwrap(), exec);
+    }
+}
+
+#[test]
+fn test_runtime_validation() {
+    assert_eq!(Runtime::new("python:3.11").unwrap(), Runtime::PYTHON_3_11);
+    assert!("python:3.11".parse::<Runtime>().is_ok());
+    assert!(matches!(
+        Runtime::new("cobol:1"),
+        Err(OpenWhiskError::InvalidInput(_))
+    ));
+    assert!(!Runtime::custom("cobol:1").is_known());
+}
+
+#[test]
+fn test_insert_rejects_invalid_exec() {
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        "http://localhost:1".to_string(),
+        "guest".to_string(),
+    );
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let action = Action {
+        name: "chain".to_string(),
+        exec: Exec::sequence(vec![]),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        client.actions().insert(&action, true),
+        Err(OpenWhiskError::InvalidInput(_))
+    ));
+}
@@ -11,7 +11,7 @@ async fn test_list_namespaces_native_client() {
         "guest".to_string(),
     ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     let namespaces = client.namespaces().list().unwrap();
 
@@ -0,0 +1,96 @@
+use openwhisk_client_rust::{
+    NativeClient, OpenWhiskError, OpenwhiskClient, Package, PackageBinding, WskProperties,
+};
+pub mod helper;
+use crate::helper::{delete, get, put};
+
+#[async_std::test]
+async fn test_list_packages_native_client() {
+    let server = get().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let packages = client.packages().list().unwrap();
+
+    assert_eq!(packages.len(), 2);
+    assert!(!packages[0].is_binding());
+    assert!(packages[1].is_binding());
+}
+
+#[async_std::test]
+async fn test_get_package_native_client() {
+    let server = get().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let package = client.packages().get("utils").unwrap();
+
+    assert_eq!(package.name, "utils");
+    assert!(!package.is_binding());
+    assert_eq!(package.actions[0].name, "cars");
+}
+
+#[async_std::test]
+async fn test_create_bind_and_share_package_native_client() {
+    let server = put(None).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let package = Package {
+        name: "utils".to_string(),
+        ..Default::default()
+    };
+    let created = client.packages().insert(&package, false).unwrap();
+    assert_eq!(created.name, "utils");
+
+    let binding = client
+        .packages()
+        .bind(
+            "utils_binding",
+            &PackageBinding::new("guest".to_string(), "utils".to_string()),
+            vec![],
+            false,
+        )
+        .unwrap();
+    assert!(binding.is_binding());
+
+    let shared = client.packages().share("utils", true).unwrap();
+    assert!(shared.publish);
+
+    assert!(matches!(
+        client.packages().share("missing", true),
+        Err(OpenWhiskError::NotFound(_))
+    ));
+}
+
+#[async_std::test]
+async fn test_delete_package_native_client() {
+    let server = delete().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    client.packages().delete("utils").unwrap();
+
+    let packages = client.packages().list().unwrap();
+    assert!(packages.is_empty());
+}
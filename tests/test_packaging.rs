@@ -0,0 +1,121 @@
+use openwhisk_client_rust::{zip_dir, Exec, OpenWhiskError, Runtime};
+use std::fs;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "openwhisk-packaging-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("lib")).unwrap();
+
+    fs::write(dir.join("package.json"), r#"{"main": "index.js"}"#).unwrap();
+    fs::write(
+        dir.join("index.js"),
+        "exports.main = require('./lib/hello');",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("lib").join("hello.js"),
+        "module.exports = () => ({});",
+    )
+    .unwrap();
+
+    dir
+}
+
+#[test]
+fn test_exec_from_source_file() {
+    let dir = fixture("file");
+
+    let exec = Exec::from_path(dir.join("index.js"), None).unwrap();
+
+    assert_eq!(
+        exec,
+        Exec::code(
+            Runtime::NODEJS_DEFAULT,
+            "exports.main = require('./lib/hello');"
+        )
+    );
+}
+
+#[test]
+fn test_exec_from_dir_infers_kind() {
+    let dir = fixture("dir");
+
+    let exec = Exec::from_path(&dir, None).unwrap();
+
+    assert_eq!(exec.kind(), "nodejs:default");
+    assert!(exec.is_binary());
+
+    let code = match exec {
+        Exec::Code(exec) => exec.code,
+        _ => panic!("expected a code exec"),
+    };
+    let archive = zip::ZipArchive::new(Cursor::new(base64::decode(code).unwrap())).unwrap();
+    let mut names: Vec<&str> = archive.file_names().collect();
+    names.sort_unstable();
+
+    assert_eq!(names, vec!["index.js", "lib/hello.js", "package.json"]);
+}
+
+#[test]
+fn test_zip_dir_is_deterministic() {
+    let dir = fixture("deterministic");
+
+    let first = zip_dir(&dir).unwrap();
+    fs::write(
+        dir.join("index.js"),
+        "exports.main = require('./lib/hello');",
+    )
+    .unwrap();
+    let second = zip_dir(&dir).unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_exec_from_zip_requires_kind() {
+    let dir = fixture("zip");
+    let archive = dir.join("action.zip");
+    fs::write(&archive, zip_dir(dir.join("lib")).unwrap()).unwrap();
+
+    assert!(matches!(
+        Exec::from_path(&archive, None),
+        Err(OpenWhiskError::InvalidInput(_))
+    ));
+
+    let exec = Exec::from_path(&archive, Some(Runtime::NODEJS_20)).unwrap();
+    assert_eq!(exec.kind(), "nodejs:20");
+    assert!(exec.is_binary());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_zip_dir_skips_symlinked_dirs() {
+    let dir = fixture("symlinks");
+    std::os::unix::fs::symlink(&dir, dir.join("lib").join("parent")).unwrap();
+    std::os::unix::fs::symlink(dir.join("index.js"), dir.join("main.js")).unwrap();
+
+    let archive = zip::ZipArchive::new(Cursor::new(zip_dir(&dir).unwrap())).unwrap();
+    let mut names: Vec<&str> = archive.file_names().collect();
+    names.sort_unstable();
+
+    assert_eq!(
+        names,
+        vec!["index.js", "lib/hello.js", "main.js", "package.json"]
+    );
+}
+
+#[test]
+fn test_exec_from_missing_file() {
+    let dir = fixture("missing");
+
+    assert!(matches!(
+        Exec::from_path(dir.join("missing.js"), None),
+        Err(OpenWhiskError::Configuration(_))
+    ));
+}
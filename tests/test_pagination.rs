@@ -0,0 +1,100 @@
+use openwhisk_client_rust::{
+    ActionListOptions, AsyncNativeClient, NativeClient, OpenwhiskClient, PackageListOptions,
+    RuleListOptions, TriggerListOptions, WskProperties,
+};
+pub mod helper;
+use crate::helper::pages;
+
+#[async_std::test]
+async fn test_iterate_action_pages_native_client() {
+    let server = pages().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let actions: Vec<String> = client
+        .actions()
+        .iter(ActionListOptions::new(2, 0, false))
+        .map(|action| action.unwrap().name)
+        .collect();
+
+    assert_eq!(actions, vec!["cars", "bikes", "trucks"]);
+}
+
+#[async_std::test]
+async fn test_list_rules_with_options_native_client() {
+    let server = pages().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let rules = client
+        .rules()
+        .list_with_options(&RuleListOptions::new(1, 0, true))
+        .unwrap();
+
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].name, "rule1");
+}
+
+#[async_std::test]
+async fn test_iterate_uses_maximum_page_size_native_client() {
+    let server = pages().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let triggers = client
+        .triggers()
+        .iter(TriggerListOptions::default())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(triggers.len(), 1);
+
+    let packages = client
+        .packages()
+        .list_with_options(&PackageListOptions {
+            public: true,
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(packages[0].name, "utils");
+}
+
+#[tokio::test]
+async fn test_iterate_action_pages_async_native_client() {
+    let server = pages().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+    let actions = client.actions();
+
+    let mut pages = actions.iter_async(ActionListOptions::new(2, 0, false));
+    let first = pages.next_entity().await.unwrap().unwrap();
+    assert_eq!(first.name, "cars");
+
+    let rest: Vec<String> = pages
+        .collect_all()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|action| action.name)
+        .collect();
+    assert_eq!(rest, vec!["bikes", "trucks"]);
+}
@@ -0,0 +1,121 @@
+use openwhisk_client_rust::{
+    AsyncNativeClient, NativeClient, OpenWhiskError, OpenwhiskClient, PollOptions, WskProperties,
+};
+use serde_json::json;
+use std::time::Duration;
+pub mod helper;
+use crate::helper::polling;
+
+fn poll_options() -> PollOptions {
+    PollOptions::new()
+        .set_interval(Duration::from_millis(5))
+        .set_max_interval(Duration::from_millis(20))
+        .set_deadline(Duration::from_secs(5))
+}
+
+#[test]
+fn test_poll_options_backoff() {
+    let options = PollOptions::new()
+        .set_interval(Duration::from_millis(100))
+        .set_max_interval(Duration::from_millis(300))
+        .set_multiplier(2);
+
+    assert_eq!(options.interval, Duration::from_millis(100));
+    assert_eq!(options.max_interval, Duration::from_millis(300));
+    assert_eq!(options.multiplier, 2);
+    assert_eq!(PollOptions::default().deadline, Duration::from_secs(600));
+}
+
+#[async_std::test]
+async fn test_invoke_and_wait() {
+    let server = polling(3).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activation = client
+        .actions()
+        .invoke_and_wait("cars", json!({"name": "whisk"}), &poll_options())
+        .unwrap();
+
+    assert_eq!(activation.activation_id, "44794bd6aab74415b4e42a308d880e5b");
+    assert_eq!(activation.response.status, "success");
+    assert_eq!(activation.response.result, json!({"greeting": "hello"}));
+    assert_eq!(activation.logs.len(), 1);
+
+    let polls = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.method.to_string() == "GET")
+        .count();
+    assert_eq!(polls, 4);
+}
+
+#[async_std::test]
+async fn test_wait_for_activation() {
+    let server = polling(0).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activation = client
+        .activations()
+        .wait("44794bd6aab74415b4e42a308d880e5b", &poll_options())
+        .unwrap();
+
+    assert_eq!(activation.name, "cars");
+}
+
+#[async_std::test]
+async fn test_invoke_and_wait_deadline() {
+    let server = polling(0).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client
+        .actions()
+        .invoke_and_wait(
+            "stuck",
+            json!({}),
+            &poll_options().set_deadline(Duration::from_millis(50)),
+        )
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Timeout(_)));
+    assert_eq!(error.activation_id(), Some("stuck"));
+}
+
+#[tokio::test]
+async fn test_invoke_and_wait_async_client() {
+    let server = polling(2).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activation = client
+        .actions()
+        .invoke_and_wait_async("cars", json!({}), &poll_options())
+        .await
+        .unwrap();
+
+    assert_eq!(activation.response.status, "success");
+}
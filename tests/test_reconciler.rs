@@ -0,0 +1,239 @@
+use openwhisk_client_rust::{
+    ChangeKind, Manifest, NativeClient, OpenwhiskClient, Plan, Project, WskProperties,
+};
+pub mod helper;
+use crate::helper::{reconciler, reconciler_in};
+
+static DEPLOYED: &str = r#"
+project:
+  name: demo
+  packages:
+    hello:
+      inputs:
+        greeting: Hi
+      actions:
+        hello:
+          code: "function main() { return { v: 1 }; }"
+          runtime: nodejs:20
+        bye:
+          code: "function main() { return {}; }"
+          runtime: nodejs:20
+      triggers:
+        tick: {}
+      rules:
+        ticking:
+          trigger: tick
+          action: hello
+    stale:
+      actions:
+        old:
+          code: "function main() { return {}; }"
+          runtime: nodejs:20
+"#;
+
+static DESIRED: &str = r#"
+project:
+  name: demo
+  packages:
+    hello:
+      inputs:
+        greeting: Hi
+      actions:
+        hello:
+          code: "function main() { return { v: 2 }; }"
+          runtime: nodejs:20
+        welcome:
+          code: "function main() { return {}; }"
+          runtime: nodejs:20
+      triggers:
+        tick: {}
+      rules:
+        ticking:
+          trigger: tick
+          action: hello
+"#;
+
+static SEQUENCED: &str = r#"
+project:
+  name: demo
+  packages:
+    hello:
+      actions:
+        hello:
+          code: "function main() { return {}; }"
+          runtime: nodejs:20
+      sequences:
+        greet:
+          actions: hello
+      triggers:
+        tick: {}
+      rules:
+        greeting:
+          trigger: tick
+          action: greet
+"#;
+
+fn project(manifest: &str) -> Project {
+    project_in(manifest, "guest")
+}
+
+fn project_in(manifest: &str, namespace: &str) -> Project {
+    Manifest::from_yaml(manifest, ".")
+        .unwrap()
+        .project(namespace)
+        .unwrap()
+}
+
+fn client(uri: String) -> OpenwhiskClient<NativeClient> {
+    client_in(uri, "guest")
+}
+
+fn client_in(uri: String, namespace: &str) -> OpenwhiskClient<NativeClient> {
+    let config = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        uri,
+        namespace.to_string(),
+    )
+    .set_bypass_cerificate_check(true);
+
+    OpenwhiskClient::<NativeClient>::try_new(Some(&config)).unwrap()
+}
+
+fn names(plan: &Plan, kind: ChangeKind) -> Vec<String> {
+    plan.changes_of(kind)
+        .iter()
+        .map(|change| format!("{} {}", change.entity.kind(), change.entity.name()))
+        .collect()
+}
+
+#[async_std::test]
+async fn plan_creates_entities_of_empty_namespace() {
+    let server = reconciler(&Project::default()).await;
+    let client = client(server.uri());
+
+    let plan = client.plan(&project(DESIRED)).unwrap();
+
+    assert!(plan.has_changes());
+    assert_eq!(
+        names(&plan, ChangeKind::Create),
+        vec![
+            "package hello",
+            "action hello/hello",
+            "action hello/welcome",
+            "trigger tick",
+            "rule ticking"
+        ]
+    );
+    assert!(plan.changes_of(ChangeKind::Delete).is_empty());
+}
+
+#[async_std::test]
+async fn plan_is_noop_for_deployed_project() {
+    let deployed = project(DESIRED);
+    let server = reconciler(&deployed).await;
+    let client = client(server.uri());
+
+    let plan = client.plan(&deployed).unwrap();
+
+    assert!(!plan.has_changes());
+    assert_eq!(plan.changes.len(), 5);
+}
+
+#[async_std::test]
+async fn plan_is_noop_for_project_deployed_to_default_namespace() {
+    let server = reconciler_in("_", &project_in(SEQUENCED, "guest")).await;
+    let client = client_in(server.uri(), "_");
+
+    let plan = client.plan(&project_in(SEQUENCED, "_")).unwrap();
+
+    assert!(!plan.has_changes());
+    assert_eq!(plan.changes.len(), 5);
+}
+
+#[async_std::test]
+async fn plan_updates_and_deletes_managed_entities() {
+    let server = reconciler(&project(DEPLOYED)).await;
+    let client = client(server.uri());
+
+    let plan = client.plan(&project(DESIRED)).unwrap();
+
+    assert_eq!(
+        names(&plan, ChangeKind::Create),
+        vec!["action hello/welcome"]
+    );
+    assert_eq!(names(&plan, ChangeKind::Update), vec!["action hello/hello"]);
+    assert_eq!(
+        names(&plan, ChangeKind::Delete),
+        vec!["action hello/bye", "action stale/old", "package stale"]
+    );
+    assert_eq!(
+        plan.changes_of(ChangeKind::Update)[0].differences,
+        vec!["exec"]
+    );
+
+    let printed = plan.to_string();
+    assert!(printed.contains("~ update action hello/hello (exec)\n"));
+    assert!(printed.contains("+ create action hello/welcome\n"));
+    assert!(printed.contains("- delete package stale\n"));
+    assert!(printed.contains("= unchanged rule ticking\n"));
+}
+
+#[async_std::test]
+async fn apply_writes_only_changes() {
+    let server = reconciler(&project(DEPLOYED)).await;
+    let client = client(server.uri());
+
+    let plan = client.plan(&project(DESIRED)).unwrap();
+    let planned = server.received_requests().await.unwrap().len();
+
+    client.apply(&plan).unwrap();
+
+    let writes: Vec<String> = server.received_requests().await.unwrap()[planned..]
+        .iter()
+        .map(|request| format!("{} {}", request.method, request.url.path()))
+        .collect();
+
+    assert_eq!(
+        writes,
+        vec![
+            "PUT /api/v1/namespaces/guest/actions/hello/hello",
+            "PUT /api/v1/namespaces/guest/actions/hello/welcome",
+            "DELETE /api/v1/namespaces/guest/actions/hello/bye",
+            "DELETE /api/v1/namespaces/guest/actions/stale/old",
+            "DELETE /api/v1/namespaces/guest/packages/stale",
+        ]
+    );
+}
+
+#[async_std::test]
+async fn apply_sets_state_of_updated_rule() {
+    let mut deployed = project(DESIRED);
+    deployed.rules[0].status = "inactive".to_string();
+    let server = reconciler(&deployed).await;
+    let client = client(server.uri());
+
+    let plan = client.plan(&project(DESIRED)).unwrap();
+
+    assert_eq!(names(&plan, ChangeKind::Update), vec!["rule ticking"]);
+    assert_eq!(
+        plan.changes_of(ChangeKind::Update)[0].differences,
+        vec!["status"]
+    );
+
+    let planned = server.received_requests().await.unwrap().len();
+
+    client.apply(&plan).unwrap();
+
+    let writes: Vec<String> = server.received_requests().await.unwrap()[planned..]
+        .iter()
+        .map(|request| format!("{} {}", request.method, request.url.path()))
+        .collect();
+
+    assert_eq!(
+        writes,
+        vec![
+            "PUT /api/v1/namespaces/guest/rules/ticking",
+            "POST /api/v1/namespaces/guest/rules/ticking",
+        ]
+    );
+}
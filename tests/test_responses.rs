@@ -0,0 +1,192 @@
+use openwhisk_client_rust::{
+    ActivationId, AsyncNativeClient, HttpMethods, NativeClient, OpenWhiskError, OpenwhiskClient,
+    WebExtension, WebRequest, WskProperties,
+};
+use serde_json::json;
+pub mod helper;
+use crate::helper::responses;
+
+#[async_std::test]
+async fn test_fire_returns_activation_id() {
+    let server = responses().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activation_id = client.triggers().fire("trigger", json!({})).unwrap();
+
+    assert_eq!(
+        activation_id,
+        Some(ActivationId::from("fa1cb5a8c4ae4b6e9cb5a8c4aedb6e3f"))
+    );
+}
+
+#[async_std::test]
+async fn test_fire_without_active_rules() {
+    let server = responses().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activation_id = client.triggers().fire("idle", json!({})).unwrap();
+
+    assert_eq!(activation_id, None);
+}
+
+#[async_std::test]
+async fn test_invoke_nonblocking() {
+    let server = responses().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activation_id = client
+        .actions()
+        .invoke_nonblocking("cars", json!({}))
+        .unwrap();
+    assert_eq!(activation_id.as_str(), "44794bd6aab74415b4e42a308d880e5b");
+
+    let response = client
+        .actions()
+        .invoke("cars", json!({}), false, false)
+        .unwrap();
+    assert_eq!(
+        response,
+        json!({ "activationId": "44794bd6aab74415b4e42a308d880e5b" })
+    );
+}
+
+#[async_std::test]
+async fn test_blocking_invoke_timeout() {
+    let server = responses().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client
+        .actions()
+        .invoke("slow", json!({}), true, true)
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Timeout(_)));
+    assert_eq!(
+        error.activation_id(),
+        Some("44794bd6aab74415b4e42a308d880e5b")
+    );
+}
+
+#[tokio::test]
+async fn test_blocking_invoke_timeout_async_client() {
+    let server = responses().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client
+        .actions()
+        .invoke_async("slow", json!({}), true, false)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Timeout(_)));
+    assert_eq!(
+        error.activation_id(),
+        Some("44794bd6aab74415b4e42a308d880e5b")
+    );
+}
+
+#[async_std::test]
+async fn test_malformed_bodies_do_not_panic() {
+    let server = responses().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client.actions().get("cars", false).unwrap_err();
+    assert!(matches!(error, OpenWhiskError::Deserialize(_)));
+
+    let error = client.actions().get("broken", false).unwrap_err();
+    assert!(matches!(error, OpenWhiskError::Server(_)));
+    assert_eq!(error.status(), Some(500));
+    assert_eq!(error.message(), "internal failure");
+}
+
+#[async_std::test]
+async fn test_response_metadata() {
+    let server = responses().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let response = client
+        .actions()
+        .web_invoke(
+            "hello",
+            &WebRequest::new(HttpMethods::GET).set_extension(WebExtension::Http),
+        )
+        .unwrap();
+
+    assert_eq!(
+        response.activation_id(),
+        Some(ActivationId::from("44794bd6aab74415b4e42a308d880e5b"))
+    );
+    assert_eq!(response.request_id(), Some("a8f7e3c2b1d04e6f"));
+    assert!(response.error().is_none());
+}
+
+#[tokio::test]
+async fn test_fire_async_client() {
+    let server = responses().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let activation_id = client
+        .triggers()
+        .fire_async("trigger", json!({}))
+        .await
+        .unwrap();
+    assert_eq!(
+        activation_id.unwrap().to_string(),
+        "fa1cb5a8c4ae4b6e9cb5a8c4aedb6e3f"
+    );
+
+    let activation_id = client
+        .actions()
+        .invoke_nonblocking_async("cars", json!({}))
+        .await
+        .unwrap();
+    assert_eq!(activation_id.as_str(), "44794bd6aab74415b4e42a308d880e5b");
+}
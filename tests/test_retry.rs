@@ -0,0 +1,185 @@
+use openwhisk_client_rust::{
+    AsyncNativeClient, HttpMethods, NativeClient, OpenWhiskError, OpenwhiskClient, RetryPolicy,
+    WskProperties,
+};
+use serde_json::json;
+use std::time::{Duration, Instant};
+use wiremock::MockServer;
+pub mod helper;
+use crate::helper::retries;
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy::new().set_backoff(Duration::from_millis(1), Duration::from_millis(10))
+}
+
+async fn attempts(server: &MockServer) -> usize {
+    server.received_requests().await.unwrap().len()
+}
+
+#[async_std::test]
+async fn test_retry_transient_status() {
+    let server = retries(2, 0).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_retry_policy(retry_policy());
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let action = client.actions().get("cars", false).unwrap();
+
+    assert_eq!(action.name, "cars");
+    assert_eq!(attempts(&server).await, 3);
+}
+
+#[async_std::test]
+async fn test_retry_gives_up_after_max_attempts() {
+    let server = retries(1, 0).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_retry_policy(retry_policy().set_max_attempts(4));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client.actions().get("busy", false).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::TooManyRequests(_)));
+    assert_eq!(attempts(&server).await, 4);
+}
+
+#[async_std::test]
+async fn test_retry_protects_invoke() {
+    let server = retries(1, 0).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_retry_policy(retry_policy());
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client
+        .actions()
+        .invoke("cars", json!({}), true, true)
+        .unwrap_err();
+
+    assert_eq!(error.status(), Some(503));
+    assert_eq!(attempts(&server).await, 1);
+}
+
+#[async_std::test]
+async fn test_retry_invoke_when_allowed() {
+    let server = retries(1, 0).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_retry_policy(retry_policy().set_retry_methods(&[HttpMethods::POST]));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let result = client
+        .actions()
+        .invoke("cars", json!({}), true, true)
+        .unwrap();
+
+    assert_eq!(result, json!({ "greeting": "hello" }));
+    assert_eq!(attempts(&server).await, 2);
+}
+
+#[test]
+fn test_retry_protects_invoke_on_connect_error() {
+    // Nothing listens on the port of a dropped listener
+    let address = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        format!("http://{}", address),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_retry_policy(
+        RetryPolicy::new()
+            .set_backoff(Duration::from_secs(2), Duration::from_secs(2))
+            .set_jitter(false),
+    );
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let started = Instant::now();
+    let error = client
+        .actions()
+        .invoke("cars", json!({}), true, true)
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Transport(_)));
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[async_std::test]
+async fn test_retry_disabled() {
+    let server = retries(1, 0).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_retry_policy(RetryPolicy::disabled());
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client.actions().get("cars", false).unwrap_err();
+
+    assert_eq!(error.status(), Some(503));
+    assert_eq!(attempts(&server).await, 1);
+}
+
+#[async_std::test]
+async fn test_retry_honours_retry_after() {
+    let server = retries(1, 1).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_retry_policy(
+        retry_policy()
+            .set_max_attempts(2)
+            .set_backoff(Duration::from_millis(1), Duration::from_secs(5)),
+    );
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let started = Instant::now();
+    let error = client.actions().get("busy", false).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::TooManyRequests(_)));
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(attempts(&server).await, 2);
+}
+
+#[tokio::test]
+async fn test_retry_async_client() {
+    let server = retries(2, 0).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_retry_policy(retry_policy());
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let action = client.actions().get_async("cars", false).await.unwrap();
+
+    assert_eq!(action.name, "cars");
+    assert_eq!(attempts(&server).await, 3);
+}
@@ -0,0 +1,95 @@
+use openwhisk_client_rust::{NativeClient, OpenwhiskClient, Route, WskProperties};
+pub mod helper;
+use crate::helper::routes;
+
+#[async_std::test]
+async fn test_create_route_native_client() {
+    let server = routes().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_apigw_access_token("apigw-token".to_string());
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let route = Route::new(
+        "/hello".to_string(),
+        "/world".to_string(),
+        "get".to_string(),
+        "cars".to_string(),
+        "json".to_string(),
+        None,
+    );
+
+    let api = client.routes().insert(&route).unwrap();
+
+    assert!(api.gw_api_activated);
+    assert_eq!(api.gw_api_url, "https://gateway/api/23bc46b1/hello");
+
+    // The access token is only sent in a header
+    let requests = server.received_requests().await.unwrap();
+    assert!(!requests[0].url.as_str().contains("apigw-token"));
+}
+
+#[async_std::test]
+async fn test_create_route_for_fully_qualified_action_native_client() {
+    let server = routes().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "_".to_string(),
+    ).set_bypass_cerificate_check(true)
+    .set_apigw_access_token("apigw-token".to_string());
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let route = Route::new(
+        "/hello".to_string(),
+        "/world".to_string(),
+        "get".to_string(),
+        "/guest/demo/cars".to_string(),
+        "json".to_string(),
+        None,
+    );
+
+    let api = client.routes().insert(&route).unwrap();
+
+    assert!(api.gw_api_activated);
+}
+
+#[async_std::test]
+async fn test_list_and_export_routes_native_client() {
+    let server = routes().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let apis = client.routes().list(Some("/hello")).unwrap();
+    assert_eq!(apis.apis[0].id, "API:guest:/hello");
+
+    let swagger = client.routes().export("/hello").unwrap();
+    assert_eq!(swagger["basePath"], "/hello");
+}
+
+#[async_std::test]
+async fn test_delete_route_native_client() {
+    let server = routes().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    client
+        .routes()
+        .delete("/hello", Some("/world"), None)
+        .unwrap();
+}
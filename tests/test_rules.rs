@@ -11,7 +11,7 @@ async fn test_list_rules_native_client() {
          "guest".to_string(),
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     let rules = serde_json::to_value(client.rules().list().unwrap()).unwrap();
 
@@ -35,7 +35,7 @@ async fn test_create_rule_native_clients() {
          "guest".to_string(),
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     let rule = Rule {
         name: "rule1".to_string(),
@@ -59,7 +59,7 @@ async fn test_get_rule_property_native_client() {
          "guest".to_string(),
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     let rule = serde_json::to_value(client.rules().get("rule1").unwrap()).unwrap();
 
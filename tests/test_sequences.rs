@@ -0,0 +1,114 @@
+use openwhisk_client_rust::{
+    AsyncNativeClient, Exec, NativeClient, OpenWhiskError, OpenwhiskClient, WskProperties,
+};
+pub mod helper;
+use crate::helper::sequences;
+
+#[async_std::test]
+async fn test_create_sequence_native_client() {
+    let server = sequences().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let sequence = client
+        .actions()
+        .create_sequence(
+            "pipeline",
+            &["utils/split", "/whisk.system/utils/echo"],
+            true,
+        )
+        .unwrap();
+
+    assert_eq!(sequence.exec.kind(), "sequence");
+}
+
+#[async_std::test]
+async fn test_fully_qualified_sequence_native_client() {
+    let server = sequences().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let sequence = client
+        .actions()
+        .create_sequence("/whisk.system/utils/pipeline", &["sort"], true)
+        .unwrap();
+    assert_eq!(sequence.namespace, "whisk.system/utils");
+
+    let deleted = client
+        .actions()
+        .delete("/whisk.system/utils/pipeline")
+        .unwrap();
+    assert_eq!(deleted.name, "pipeline");
+}
+
+#[async_std::test]
+async fn test_resolve_sequence_native_client() {
+    let server = sequences().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let tree = client.actions().resolve_sequence("pipeline").unwrap();
+
+    assert_eq!(tree.name, "/guest/pipeline");
+    assert_eq!(tree.components.len(), 2);
+    assert_eq!(tree.components[0].name, "/guest/utils/split");
+    assert_eq!(tree.components[0].components[0].name, "/guest/sort");
+    assert!(matches!(
+        tree.components[0].components[0].action.exec,
+        Exec::Code(_)
+    ));
+    assert_eq!(tree.components[1].name, "/whisk.system/utils/echo");
+    assert!(tree.components[1].components.is_empty());
+}
+
+#[async_std::test]
+async fn test_resolve_sequence_detects_cycles_native_client() {
+    let server = sequences().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(matches!(
+        client.actions().resolve_sequence("loop"),
+        Err(OpenWhiskError::InvalidInput(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_resolve_sequence_async_client() {
+    let server = sequences().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let tree = client
+        .actions()
+        .resolve_sequence_async("/guest/pipeline")
+        .await
+        .unwrap();
+
+    assert_eq!(tree.components[0].components[0].name, "/guest/sort");
+}
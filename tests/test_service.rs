@@ -0,0 +1,136 @@
+use openwhisk_client_rust::{
+    AsyncService, HttpMethods, HttpResponse, OpenWhisk, OpenWhiskError, OpenwhiskClient, Service,
+    WebRequest, WskProperties,
+};
+use serde_json::{json, Value};
+use std::future::{ready, Future};
+
+/// Client implementing only the required methods of the services and answering in json
+#[derive(Debug, Clone, Default)]
+struct JsonClient;
+
+struct JsonRequest {
+    method: Option<HttpMethods>,
+    url: String,
+    body: Option<Value>,
+}
+
+impl JsonClient {
+    fn respond(&self, request: JsonRequest) -> Result<HttpResponse, OpenWhiskError> {
+        let body = if request.url.trim_end_matches('/').ends_with("/namespaces") {
+            json!(["guest"])
+        } else {
+            json!({
+                "method": request.method.map(|method| method.as_str()),
+                "url": request.url,
+                "body": request.body,
+            })
+        };
+
+        Ok(HttpResponse {
+            status: 200,
+            headers: http::HeaderMap::new(),
+            body: body.to_string().into_bytes(),
+        })
+    }
+}
+
+impl OpenWhisk for JsonClient {
+    type Output = JsonClient;
+
+    fn new_whisk_client(_insecure: Option<bool>) -> Self::Output {
+        JsonClient
+    }
+}
+
+impl Service for JsonClient {
+    type Output = JsonRequest;
+
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        _user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Ok(JsonRequest {
+            method,
+            url: url.to_string(),
+            body,
+        })
+    }
+
+    fn invoke_raw_request(&self, request: Self::Output) -> Result<HttpResponse, OpenWhiskError> {
+        self.respond(request)
+    }
+}
+
+impl AsyncService for JsonClient {
+    type Output = JsonRequest;
+
+    fn new_request(
+        &self,
+        method: Option<HttpMethods>,
+        url: &str,
+        user_auth: Option<(&str, &str)>,
+        body: Option<Value>,
+    ) -> Result<Self::Output, OpenWhiskError> {
+        Service::new_request(self, method, url, user_auth, body)
+    }
+
+    fn invoke_raw_request(
+        &self,
+        request: Self::Output,
+    ) -> impl Future<Output = Result<HttpResponse, OpenWhiskError>> + Send {
+        ready(self.respond(request))
+    }
+}
+
+fn client() -> OpenwhiskClient<JsonClient> {
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        "https://openwhisk.test".to_string(),
+        "guest".to_string(),
+    );
+
+    OpenwhiskClient::<JsonClient>::try_new(Some(&wsk_properties)).unwrap()
+}
+
+#[test]
+fn test_minimal_client_serves_services() {
+    let client = client();
+
+    assert_eq!(client.namespaces().list().unwrap(), vec!["guest"]);
+
+    let request = WebRequest::new(HttpMethods::POST).set_json_body(&json!({ "key": "value" }));
+    let response = client.actions().web_invoke("hello", &request).unwrap();
+
+    assert_eq!(response.status, 200);
+    let echo: Value = response.json().unwrap();
+    assert_eq!(echo["method"], "POST");
+    assert_eq!(echo["body"], json!({ "key": "value" }));
+
+    let request = WebRequest::new(HttpMethods::POST).set_body(b"raw body".to_vec());
+    assert!(matches!(
+        client.actions().web_invoke("hello", &request),
+        Err(OpenWhiskError::Request(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_minimal_async_client_serves_services() {
+    let client = client();
+
+    assert_eq!(
+        client.namespaces().list_async().await.unwrap(),
+        vec!["guest"]
+    );
+
+    let request = WebRequest::new(HttpMethods::GET);
+    let response = client
+        .actions()
+        .web_invoke_async("hello", &request)
+        .await
+        .unwrap();
+    assert_eq!(response.json::<Value>().unwrap()["method"], "GET");
+}
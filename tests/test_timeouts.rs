@@ -0,0 +1,132 @@
+use openwhisk_client_rust::{
+    AsyncNativeClient, NativeClient, OpenWhisk, OpenWhiskError, OpenwhiskClient, ProxySettings,
+    RetryPolicy, WskProperties,
+};
+use std::time::Duration;
+pub mod helper;
+use crate::helper::timeouts;
+
+fn properties(host: String) -> WskProperties {
+    WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        host,
+        "guest".to_string(),
+    )
+    .set_bypass_cerificate_check(true)
+    .set_retry_policy(RetryPolicy::disabled())
+}
+
+#[async_std::test]
+async fn test_request_timeout() {
+    let server = timeouts(Duration::from_millis(500)).await;
+    let wsk_properties = properties(server.uri()).set_timeout(Duration::from_millis(100));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    match client.actions().list() {
+        Err(OpenWhiskError::Transport(_)) => {}
+        other => panic!("expected transport error, got {:?}", other),
+    }
+}
+
+#[async_std::test]
+async fn test_client_without_connection_type() {
+    let server = timeouts(Duration::ZERO).await;
+
+    let client = OpenwhiskClient::from_client(
+        NativeClient::new_whisk_client(None),
+        Some(&properties(server.uri())),
+    );
+
+    assert!(client.actions().list().is_ok());
+}
+
+#[async_std::test]
+async fn test_per_call_timeout_extends_client_timeout() {
+    let server = timeouts(Duration::from_millis(300)).await;
+    let wsk_properties = properties(server.uri()).set_timeout(Duration::from_millis(100));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let actions = client
+        .with_timeout(Duration::from_secs(5))
+        .actions()
+        .list()
+        .unwrap();
+    assert!(actions.is_empty());
+
+    assert!(client.actions().list().is_err());
+}
+
+#[async_std::test]
+async fn test_per_call_timeout_shortens_client_timeout() {
+    let server = timeouts(Duration::from_millis(500)).await;
+    let wsk_properties = properties(server.uri());
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    match client
+        .with_timeout(Duration::from_millis(100))
+        .actions()
+        .list()
+    {
+        Err(OpenWhiskError::Transport(_)) => {}
+        other => panic!("expected transport error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_per_call_timeout_async() {
+    let server = timeouts(Duration::from_millis(300)).await;
+    let wsk_properties = properties(server.uri()).set_timeout(Duration::from_millis(100));
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(client.actions().list_async().await.is_err());
+
+    let actions = client
+        .with_timeout(Duration::from_secs(5))
+        .actions()
+        .list_async()
+        .await
+        .unwrap();
+    assert!(actions.is_empty());
+}
+
+#[async_std::test]
+async fn test_requests_sent_through_proxy() {
+    let proxy = timeouts(Duration::ZERO).await;
+    let wsk_properties = properties("http://openwhisk.invalid".to_string())
+        .set_proxy(ProxySettings::new().set_http(&proxy.uri()));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(client.actions().list().unwrap().is_empty());
+}
+
+#[async_std::test]
+async fn test_no_proxy_hosts_reached_directly() {
+    let proxy = timeouts(Duration::ZERO).await;
+    let wsk_properties = properties("http://openwhisk.invalid".to_string()).set_proxy(
+        ProxySettings::new()
+            .set_all(&proxy.uri())
+            .add_no_proxy("openwhisk.invalid"),
+    );
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    match client.actions().list() {
+        Err(OpenWhiskError::Transport(_)) => {}
+        other => panic!("expected transport error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_invalid_proxy() {
+    let wsk_properties = properties("http://localhost".to_string())
+        .set_proxy(ProxySettings::new().set_https("not a url"));
+
+    let error = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Configuration(_)));
+}
@@ -0,0 +1,112 @@
+use openwhisk_client_rust::{
+    AsyncNativeClient, NativeClient, OpenWhiskError, OpenwhiskClient, TlsVersion, WskProperties,
+};
+pub mod helper;
+use crate::helper::auth;
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/tls/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+#[async_std::test]
+async fn test_client_with_certificates() {
+    let server = auth().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    )
+    .set_client_certificate(fixture("client.pem"), fixture("client.key"))
+    .add_ca_certificate(fixture("ca.pem"))
+    .set_min_tls_version(TlsVersion::Tls1_2);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(client.actions().list().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_async_client_with_certificates() {
+    let server = auth().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    )
+    .set_client_certificate(fixture("client.pem"), fixture("client.key"))
+    .add_ca_certificate(fixture("ca.pem"));
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    assert!(client.actions().list_async().await.unwrap().is_empty());
+}
+
+#[test]
+fn test_missing_ca_bundle() {
+    let wsk_properties = WskProperties::new(
+        "user:pass".to_string(),
+        "https://localhost".to_string(),
+        "guest".to_string(),
+    )
+    .add_ca_certificate(fixture("missing.pem"));
+
+    let error = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Configuration(_)));
+}
+
+#[test]
+fn test_invalid_ca_bundle() {
+    let wsk_properties = WskProperties::new(
+        "user:pass".to_string(),
+        "https://localhost".to_string(),
+        "guest".to_string(),
+    )
+    .add_ca_certificate(fixture("client.key"));
+
+    let error = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Configuration(_)));
+}
+
+#[test]
+fn test_client_certificate_without_key() {
+    let mut wsk_properties = WskProperties::new(
+        "user:pass".to_string(),
+        "https://localhost".to_string(),
+        "guest".to_string(),
+    );
+    wsk_properties.cert = Some(fixture("client.pem"));
+
+    let error = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Configuration(_)));
+}
+
+#[test]
+fn test_mismatched_client_key() {
+    let wsk_properties = WskProperties::new(
+        "user:pass".to_string(),
+        "https://localhost".to_string(),
+        "guest".to_string(),
+    )
+    .set_client_certificate(fixture("client.pem"), fixture("ca.pem"));
+
+    let error = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Configuration(_)));
+}
+
+#[test]
+fn test_pkcs1_client_key() {
+    let wsk_properties = WskProperties::new(
+        "user:pass".to_string(),
+        "https://localhost".to_string(),
+        "guest".to_string(),
+    )
+    .set_client_certificate(fixture("client.pem"), fixture("client-rsa.key"));
+
+    let error = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Configuration(message) if message.contains("PKCS#1")));
+}
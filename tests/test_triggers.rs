@@ -1,7 +1,13 @@
-use openwhisk_client_rust::{KeyValue, NativeClient, OpenwhiskClient, Trigger, WskProperties};
+use openwhisk_client_rust::{
+    AsyncNativeClient, BearerToken, FeedLifecycleEvent, KeyValue, NativeClient, OpenWhiskError,
+    OpenwhiskClient, Trigger, WskProperties,
+};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, ResponseTemplate};
 
 pub mod helper;
-use crate::helper::{delete, get, put};
+use crate::helper::{delete, feeds, get, put};
 
 #[async_std::test]
 async fn test_list_triggers_native_client() {
@@ -12,7 +18,7 @@ async fn test_list_triggers_native_client() {
          "guest".to_string(), 
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     let triggers = serde_json::to_value(client.triggers().list().unwrap()).unwrap();
     let expected: Vec<Trigger> = serde_json::from_value(triggers).unwrap();
@@ -28,7 +34,7 @@ async fn test_create_trigger_native_client() {
          "guest".to_string(),
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     let trigger = Trigger {
         name: "trigger".to_string(),
@@ -50,7 +56,7 @@ async fn test_create_trigger_native_client() {
 
     let triggers = serde_json::to_value(result).unwrap();
     let expected: String = serde_json::to_string(&triggers).unwrap();
-    
+
     assert!(expected.contains("trigger"));
 }
 
@@ -63,7 +69,7 @@ async fn test_delete_trigger_native_client() {
          "guest".to_string(),
         ).set_bypass_cerificate_check(true);
 
-    let client = OpenwhiskClient::<NativeClient>::new(Some(&wsk_properties));
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
 
     client.triggers().delete("trigger").unwrap();
     let triggers = serde_json::to_value(client.triggers().list().unwrap()).unwrap();
@@ -71,3 +77,411 @@ async fn test_delete_trigger_native_client() {
 
     assert!(expected.is_empty())
 }
+
+async fn feed_requests(server: &wiremock::MockServer) -> Vec<String> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            let body: Value = serde_json::from_slice(&request.body).unwrap_or(Value::Null);
+            match body["lifecycleEvent"].as_str() {
+                Some(event) => format!("{} {} {}", request.method, request.url.path(), event),
+                None => format!("{} {}", request.method, request.url.path()),
+            }
+        })
+        .collect()
+}
+
+#[async_std::test]
+async fn test_create_trigger_with_feed_native_client() {
+    let server = feeds(false, 200).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+    let parameters = vec![KeyValue {
+        key: "cron".to_string(),
+        value: json!("0 * * * *"),
+    }];
+
+    let created = client
+        .triggers()
+        .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &parameters, true)
+        .unwrap();
+    assert_eq!(created.name, "everyhour");
+
+    let requests = server.received_requests().await.unwrap();
+    let body: Value = serde_json::from_slice(&requests[2].body).unwrap();
+    assert_eq!(body["lifecycleEvent"], "CREATE");
+    assert_eq!(body["cron"], "0 * * * *");
+
+    assert_eq!(
+        client.triggers().read_feed("everyhour").unwrap(),
+        json!({ "status": "active" })
+    );
+    client.triggers().pause_feed("everyhour").unwrap();
+    client.triggers().delete_with_feed("everyhour").unwrap();
+
+    assert_eq!(
+        feed_requests(&server).await,
+        vec![
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm CREATE",
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm READ",
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm PAUSE",
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm DELETE",
+            "DELETE /api/v1/namespaces/guest/triggers/everyhour",
+        ]
+    );
+}
+
+#[async_std::test]
+async fn test_create_trigger_with_failing_feed_native_client() {
+    let server = feeds(false, 502).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+
+    let result =
+        client
+            .triggers()
+            .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &[], true);
+    assert!(matches!(result, Err(OpenWhiskError::Server(_))));
+
+    assert_eq!(
+        feed_requests(&server).await,
+        vec![
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm CREATE",
+            "DELETE /api/v1/namespaces/guest/triggers/everyhour",
+        ]
+    );
+}
+
+#[async_std::test]
+async fn test_create_trigger_with_running_feed_native_client() {
+    let server = feeds(false, 202).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+
+    // A feed which is still running has not registered the trigger
+    let result =
+        client
+            .triggers()
+            .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &[], true);
+    assert!(matches!(result, Err(OpenWhiskError::Timeout(_))));
+
+    assert_eq!(
+        feed_requests(&server).await,
+        vec![
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm CREATE",
+            "DELETE /api/v1/namespaces/guest/triggers/everyhour",
+        ]
+    );
+}
+
+#[async_std::test]
+async fn test_overwrite_trigger_with_failing_feed_native_client() {
+    let server = feeds(true, 502).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+
+    let result =
+        client
+            .triggers()
+            .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &[], true);
+    assert!(matches!(result, Err(OpenWhiskError::Server(_))));
+
+    // The live trigger is updated in its feed and restored when the feed fails
+    assert_eq!(
+        feed_requests(&server).await,
+        vec![
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm UPDATE",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+        ]
+    );
+}
+
+#[async_std::test]
+async fn test_move_trigger_to_other_feed_native_client() {
+    let server = feeds(false, 200).await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/triggers/everyhour"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "namespace": "guest",
+            "name": "everyhour",
+            "version": "0.0.1",
+            "annotations": [{ "key": "feed", "value": "/whisk.system/messaging/kafkaFeed" }],
+            "parameters": []
+        })))
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v1/namespaces/whisk.system/actions/messaging/kafkaFeed",
+        ))
+        .and(body_partial_json(json!({
+            "lifecycleEvent": "DELETE",
+            "triggerName": "/guest/everyhour"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&server)
+        .await;
+
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+
+    client
+        .triggers()
+        .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &[], true)
+        .unwrap();
+
+    // The trigger leaves its previous feed once it is registered with the new one
+    assert_eq!(
+        feed_requests(&server).await,
+        vec![
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm CREATE",
+            "POST /api/v1/namespaces/whisk.system/actions/messaging/kafkaFeed DELETE",
+        ]
+    );
+}
+
+#[async_std::test]
+async fn test_move_trigger_to_failing_feed_native_client() {
+    let server = feeds(false, 502).await;
+    let previous = json!({
+        "namespace": "guest",
+        "name": "everyhour",
+        "version": "0.0.1",
+        "annotations": [{ "key": "feed", "value": "/whisk.system/messaging/kafkaFeed" }],
+        "parameters": []
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/namespaces/guest/triggers/everyhour"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&previous))
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/namespaces/guest/triggers/everyhour"))
+        .and(body_partial_json(json!({
+            "annotations": [{ "key": "feed", "value": "/whisk.system/messaging/kafkaFeed" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&previous))
+        .mount(&server)
+        .await;
+
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+
+    let result =
+        client
+            .triggers()
+            .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &[], true);
+    assert!(matches!(result, Err(OpenWhiskError::Server(_))));
+
+    // The previous feed is never told to stop firing the restored trigger
+    let requests = server.received_requests().await.unwrap();
+    let restored: Value = serde_json::from_slice(&requests[3].body).unwrap();
+    assert_eq!(restored["annotations"], previous["annotations"]);
+
+    assert_eq!(
+        feed_requests(&server).await,
+        vec![
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm CREATE",
+            "PUT /api/v1/namespaces/guest/triggers/everyhour",
+        ]
+    );
+}
+
+#[async_std::test]
+async fn test_create_trigger_with_reserved_feed_parameter_native_client() {
+    let server = feeds(false, 200).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+    let parameters = vec![KeyValue {
+        key: "triggerName".to_string(),
+        value: json!("/guest/other"),
+    }];
+
+    let result = client.triggers().create_with_feed(
+        &trigger,
+        "/whisk.system/alarms/alarm",
+        &parameters,
+        true,
+    );
+    assert!(matches!(result, Err(OpenWhiskError::InvalidInput(_))));
+
+    assert_eq!(
+        feed_requests(&server).await,
+        vec!["GET /api/v1/namespaces/guest/triggers/everyhour"]
+    );
+}
+
+#[async_std::test]
+async fn test_create_trigger_with_feed_auth_provider_native_client() {
+    let server = feeds(false, 200).await;
+    let wsk_properties = WskProperties::new("".to_string(), server.uri(), "guest".to_string())
+        .set_bypass_cerificate_check(true)
+        .set_auth_provider(BearerToken::new("token"));
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let trigger = Trigger {
+        name: "everyhour".to_string(),
+        version: "0.0.1".to_string(),
+        ..Default::default()
+    };
+
+    let result =
+        client
+            .triggers()
+            .create_with_feed(&trigger, "/whisk.system/alarms/alarm", &[], true);
+    assert!(matches!(result, Err(OpenWhiskError::InvalidInput(_))));
+
+    assert_eq!(
+        feed_requests(&server).await,
+        vec!["GET /api/v1/namespaces/guest/triggers/everyhour"]
+    );
+}
+
+#[tokio::test]
+async fn test_update_feed_async_native_client() {
+    let server = feeds(true, 200).await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let parameters = vec![KeyValue {
+        key: "cron".to_string(),
+        value: json!("*/5 * * * *"),
+    }];
+
+    client
+        .triggers()
+        .update_feed_async("everyhour", &parameters)
+        .await
+        .unwrap();
+    client
+        .triggers()
+        .feed_lifecycle_async(
+            "/whisk.system/alarms/alarm",
+            "everyhour",
+            FeedLifecycleEvent::Unpause,
+            &[],
+        )
+        .await
+        .unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let body: Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(body["lifecycleEvent"], "UPDATE");
+    assert_eq!(body["cron"], "*/5 * * * *");
+
+    assert_eq!(
+        feed_requests(&server).await,
+        vec![
+            "GET /api/v1/namespaces/guest/triggers/everyhour",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm UPDATE",
+            "POST /api/v1/namespaces/whisk.system/actions/alarms/alarm UNPAUSE",
+        ]
+    );
+}
@@ -0,0 +1,135 @@
+use openwhisk_client_rust::{
+    AsyncNativeClient, NativeClient, OpenWhiskError, OpenwhiskClient, TypedActivation,
+    WskProperties,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+pub mod helper;
+use crate::helper::typed;
+
+#[derive(Serialize)]
+struct Operands {
+    a: i64,
+    b: i64,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Sum {
+    sum: i64,
+}
+
+#[async_std::test]
+async fn test_invoke_typed_result() {
+    let server = typed().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let typed: TypedActivation<Sum> = client
+        .actions()
+        .invoke_typed("add", &Operands { a: 1, b: 2 }, true)
+        .unwrap();
+
+    assert!(typed.is_success());
+    assert_eq!(typed.result, Ok(Sum { sum: 3 }));
+    assert_eq!(typed.activation, None);
+}
+
+#[async_std::test]
+async fn test_invoke_typed_activation() {
+    let server = typed().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let typed: TypedActivation<Sum> = client
+        .actions()
+        .invoke_typed("add", &Operands { a: 1, b: 2 }, false)
+        .unwrap();
+
+    assert_eq!(typed.result, Ok(Sum { sum: 3 }));
+    assert_eq!(
+        typed.activation_id(),
+        Some("44794bd6aab74415b4e42a308d880e5b")
+    );
+    assert_eq!(typed.activation.unwrap().duration, 10);
+}
+
+#[async_std::test]
+async fn test_invoke_typed_application_error() {
+    let server = typed().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let typed: TypedActivation<Sum> = client
+        .actions()
+        .invoke_typed("fail", &Operands { a: 1, b: 2 }, true)
+        .unwrap();
+
+    assert!(!typed.is_success());
+    assert_eq!(typed.result, Err(json!("boom")));
+
+    let typed: TypedActivation<Sum> = client
+        .actions()
+        .invoke_typed("fail", &Operands { a: 1, b: 2 }, false)
+        .unwrap();
+
+    assert_eq!(typed.result, Err(json!("boom")));
+    assert_eq!(
+        typed.activation.unwrap().response.status,
+        "application error"
+    );
+}
+
+#[async_std::test]
+async fn test_invoke_typed_gateway_error() {
+    let server = typed().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let error = client
+        .actions()
+        .invoke_typed::<_, Sum>("unreachable", &Operands { a: 1, b: 2 }, true)
+        .unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Server(_)));
+    assert_eq!(error.status(), Some(502));
+}
+
+#[tokio::test]
+async fn test_invoke_typed_async_client() {
+    let server = typed().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let typed: TypedActivation<Sum> = client
+        .actions()
+        .invoke_typed_async("add", &Operands { a: 1, b: 2 }, false)
+        .await
+        .unwrap();
+
+    assert_eq!(typed.result, Ok(Sum { sum: 3 }));
+}
@@ -0,0 +1,121 @@
+use openwhisk_client_rust::{
+    AsyncNativeClient, HttpMethods, NativeClient, OpenwhiskClient, WebExtension, WebRequest,
+    WskProperties,
+};
+use serde_json::{json, Value};
+pub mod helper;
+use crate::helper::web;
+
+#[async_std::test]
+async fn test_web_invoke_http_native_client() {
+    let server = web().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let request = WebRequest::new(HttpMethods::POST)
+        .set_extension(WebExtension::Http)
+        .add_query("name", "open whisk")
+        .add_header("Content-Type", "text/plain")
+        .set_whisk_auth("secret")
+        .set_body(b"raw body".to_vec());
+
+    let response = client.actions().web_invoke("hello", &request).unwrap();
+
+    assert_eq!(response.status, 201);
+    assert!(response.is_success());
+    assert_eq!(response.header("x-custom"), Some("value"));
+    assert_eq!(response.text(), "hello open whisk");
+}
+
+#[async_std::test]
+async fn test_web_invoke_json_with_basic_auth_native_client() {
+    let server = web().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let request = WebRequest::new(HttpMethods::GET).set_basic_auth(true);
+
+    let response = client.actions().web_invoke("demo/cars", &request).unwrap();
+    let body: Value = response.json().unwrap();
+
+    assert_eq!(body, json!({"cars": ["tesla"]}));
+}
+
+#[async_std::test]
+async fn test_web_invoke_fully_qualified_action_native_client() {
+    let server = web().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "_".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let request = WebRequest::new(HttpMethods::GET).set_basic_auth(true);
+
+    let response = client
+        .actions()
+        .web_invoke("/guest/demo/cars", &request)
+        .unwrap();
+    let body: Value = response.json().unwrap();
+
+    assert_eq!(body, json!({"cars": ["tesla"]}));
+}
+
+#[async_std::test]
+async fn test_web_invoke_returns_unsuccessful_response_native_client() {
+    let server = web().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<NativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let request = WebRequest::new(HttpMethods::PATCH).set_json_body(&json!({"key": "value"}));
+
+    let response = client.actions().web_invoke("hello", &request).unwrap();
+
+    assert_eq!(response.status, 401);
+    assert!(!response.is_success());
+}
+
+#[tokio::test]
+async fn test_web_invoke_async_client() {
+    let server = web().await;
+    let wsk_properties = WskProperties::new(
+        "23bc46b1-71f6-4ed5-8c54-816aa4f8c502:123zO3xZCLrMN6v2BKK1dXYFpXlPkccOFqm12CdAsMgRU4VrNZ9lyGVCGuMDGIwP".to_string(),
+        server.uri(),
+        "guest".to_string(),
+    ).set_bypass_cerificate_check(true);
+
+    let client = OpenwhiskClient::<AsyncNativeClient>::try_new(Some(&wsk_properties)).unwrap();
+
+    let request = WebRequest::new(HttpMethods::POST)
+        .set_extension(WebExtension::Http)
+        .add_query("name", "open whisk")
+        .add_header("Content-Type", "text/plain")
+        .set_whisk_auth("secret")
+        .set_body(b"raw body".to_vec());
+
+    let response = client
+        .actions()
+        .web_invoke_async("hello", &request)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, 201);
+    assert_eq!(response.text(), "hello open whisk");
+}
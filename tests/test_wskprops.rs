@@ -0,0 +1,77 @@
+use openwhisk_client_rust::{OpenWhiskError, WskProperties};
+use std::env;
+use std::fs;
+
+fn write_wskprops(name: &str, content: &str) -> String {
+    let path = env::temp_dir().join(name);
+    fs::write(&path, content).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+#[test]
+fn test_read_wskprops_file() {
+    let path = write_wskprops(
+        "openwhisk_client_rust_test.wskprops",
+        "# wsk properties\nAUTH=user:pass\nAPIHOST=localhost:3233\nNAMESPACE=guest\n\nAPIVERSION=v1\nCERT=/tmp/cert.pem\nKEY=/tmp/key.pem\nAPIGW_ACCESS_TOKEN=token\n",
+    );
+
+    let properties = WskProperties::from_wskprops_file(&path).unwrap();
+
+    assert_eq!(properties.auth_token, "user:pass");
+    assert_eq!(properties.host, "https://localhost:3233");
+    assert_eq!(properties.namespace, "guest");
+    assert_eq!(properties.version, "v1");
+    assert_eq!(properties.cert.as_deref(), Some("/tmp/cert.pem"));
+    assert_eq!(properties.key.as_deref(), Some("/tmp/key.pem"));
+    assert_eq!(properties.apigw_access_token.as_deref(), Some("token"));
+}
+
+#[test]
+fn test_read_missing_wskprops_file() {
+    let error = WskProperties::from_wskprops_file("/nonexistent/.wskprops").unwrap_err();
+
+    assert!(matches!(error, OpenWhiskError::Configuration(_)));
+}
+
+#[test]
+fn test_environment_takes_precedence_over_wsk_config_file() {
+    let path = write_wskprops(
+        "openwhisk_client_rust_config_file.wskprops",
+        "AUTH=user:pass\nAPIHOST=http://localhost:3233\nNAMESPACE=guest\n",
+    );
+
+    env::set_var("WSK_CONFIG_FILE", &path);
+    env::set_var("__OW_NAMESPACE", "other");
+
+    let properties = WskProperties::from_default_locations().unwrap();
+
+    env::remove_var("WSK_CONFIG_FILE");
+    env::remove_var("__OW_NAMESPACE");
+
+    assert_eq!(properties.auth_token, "user:pass");
+    assert_eq!(properties.host, "http://localhost:3233");
+    assert_eq!(properties.namespace, "other");
+}
+
+#[test]
+fn test_update_wskprops_file() {
+    let path = write_wskprops(
+        "openwhisk_client_rust_update.wskprops",
+        "# wsk properties\nAUTH=user:pass\nAPIHOST=localhost:3233\nCERT=/tmp/cert.pem\n",
+    );
+
+    WskProperties::update_wskprops_file(
+        &path,
+        &[
+            ("APIHOST", Some("openwhisk.example.com")),
+            ("CERT", None),
+            ("NAMESPACE", Some("dev")),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# wsk properties\nAUTH=user:pass\nAPIHOST=openwhisk.example.com\nNAMESPACE=dev\n"
+    );
+}